@@ -0,0 +1,352 @@
+//! Request authentication with JWT access tokens
+//!
+//! Handlers take [`AuthUser`] (or `Option<AuthUser>`) as an extractor;
+//! [`require_role`] guards whole routes. Failures are [`AuthError`]s,
+//! answered with 401 or 403 problem details.
+
+use crate::problem::{ErrorCode, Problem};
+use axum::{
+    extract::{FromRef, FromRequestParts, OptionalFromRequestParts, Request},
+    http::{HeaderMap, HeaderValue, header, request::Parts},
+    response::{IntoResponse, Response},
+};
+use starscalendars_app::JwtService;
+use starscalendars_domain::{DomainError, JwtClaims, UserId};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+
+/// Role that passes every role check
+pub const ADMIN_ROLE: &str = "admin";
+
+/// Role of paying users, also granted by the Telegram channel subscription
+pub const PREMIUM_ROLE: &str = "premium";
+
+/// Authentication or authorization failure
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    /// No `Authorization: Bearer` header
+    #[error("Missing bearer token")]
+    MissingToken,
+
+    /// Token rejected by the `JwtService`
+    #[error("Invalid or expired access token")]
+    InvalidToken(#[source] DomainError),
+
+    /// Valid token without the role the route requires
+    #[error("Requires the {0} role")]
+    MissingRole(&'static str),
+}
+
+impl AuthError {
+    /// Error code: 401 until a valid token is presented, 403 afterwards
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::MissingToken => ErrorCode::MissingToken,
+            Self::InvalidToken(DomainError::JwtTokenExpired(_)) => ErrorCode::TokenExpired,
+            Self::InvalidToken(_) => ErrorCode::InvalidToken,
+            Self::MissingRole(_) => ErrorCode::InsufficientRole,
+        }
+    }
+}
+
+impl IntoResponse for AuthError {
+    fn into_response(self) -> Response {
+        if let Self::InvalidToken(e) = &self {
+            tracing::debug!("Access token rejected: {}", e);
+        }
+
+        let mut problem = Problem::new(self.code()).with_detail(self.to_string());
+        if let Self::MissingRole(role) = &self {
+            problem = problem.with_required_role(*role);
+        }
+        let mut response = problem.into_response();
+
+        // RFC 6750 challenge
+        let challenge = match &self {
+            Self::MissingToken => Some("Bearer"),
+            Self::InvalidToken(_) => Some("Bearer error=\"invalid_token\""),
+            Self::MissingRole(_) => None,
+        };
+        if let Some(challenge) = challenge {
+            response.headers_mut().insert(
+                header::WWW_AUTHENTICATE,
+                HeaderValue::from_static(challenge),
+            );
+        }
+        response
+    }
+}
+
+/// Signed-in user: claims of a valid `Authorization: Bearer` access token
+///
+/// Routes taking it answer 401 without a valid token; `Option<AuthUser>`
+/// is `None` when no token is sent. The state must provide the
+/// `JwtService`.
+#[derive(Debug, Clone)]
+pub struct AuthUser(pub JwtClaims);
+
+impl AuthUser {
+    /// Account of the token's user
+    pub fn user_id(&self) -> UserId {
+        UserId::from_uuid(self.0.user_id.as_uuid())
+    }
+
+    /// Whether the user holds `role`
+    ///
+    /// Admins hold every role, and premium access also comes with the
+    /// Telegram channel subscription.
+    pub fn has_role(&self, role: &str) -> bool {
+        match role {
+            PREMIUM_ROLE => self.0.has_premium_access(),
+            _ => self.0.has_role(role) || self.0.has_role(ADMIN_ROLE),
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for AuthUser
+where
+    S: Send + Sync,
+    Arc<dyn JwtService>: FromRef<S>,
+{
+    type Rejection = AuthError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        // Already validated by `require_role`
+        if let Some(user) = parts.extensions.get::<Self>() {
+            return Ok(user.clone());
+        }
+
+        let jwt_service = Arc::<dyn JwtService>::from_ref(state);
+        authenticate(jwt_service.as_ref(), &parts.headers).await
+    }
+}
+
+impl<S> OptionalFromRequestParts<S> for AuthUser
+where
+    S: Send + Sync,
+    Arc<dyn JwtService>: FromRef<S>,
+{
+    type Rejection = AuthError;
+
+    /// A token that is sent but invalid is still rejected, so clients
+    /// learn to refresh it
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        if !parts.headers.contains_key(header::AUTHORIZATION) {
+            return Ok(None);
+        }
+        <Self as FromRequestParts<S>>::from_request_parts(parts, state)
+            .await
+            .map(Some)
+    }
+}
+
+/// Validate the `Authorization: Bearer` access token
+async fn authenticate(
+    jwt_service: &dyn JwtService,
+    headers: &HeaderMap,
+) -> Result<AuthUser, AuthError> {
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or(AuthError::MissingToken)?;
+    jwt_service
+        .validate_access_token(token)
+        .await
+        .map(AuthUser)
+        .map_err(AuthError::InvalidToken)
+}
+
+/// Route guard admitting only users with `role`
+///
+/// Apply with `Router::route_layer`. The authenticated user is stored in
+/// the request extensions, so an `AuthUser` extractor behind the guard
+/// does not validate the token again.
+pub fn require_role(jwt_service: Arc<dyn JwtService>, role: &'static str) -> RequireRole {
+    RequireRole { jwt_service, role }
+}
+
+/// Layer built by [`require_role`]
+#[derive(Clone)]
+pub struct RequireRole {
+    jwt_service: Arc<dyn JwtService>,
+    role: &'static str,
+}
+
+impl<S> Layer<S> for RequireRole {
+    type Service = RequireRoleService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequireRoleService {
+            inner,
+            guard: self.clone(),
+        }
+    }
+}
+
+/// Service wrapped by [`RequireRole`]
+#[derive(Clone)]
+pub struct RequireRoleService<S> {
+    inner: S,
+    guard: RequireRole,
+}
+
+impl<S> Service<Request> for RequireRoleService<S>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        // Keep the service that was polled ready
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let guard = self.guard.clone();
+
+        Box::pin(async move {
+            let (mut parts, body) = request.into_parts();
+            let user = match authenticate(guard.jwt_service.as_ref(), &parts.headers).await {
+                Ok(user) if user.has_role(guard.role) => user,
+                Ok(_) => return Ok(AuthError::MissingRole(guard.role).into_response()),
+                Err(e) => return Ok(e.into_response()),
+            };
+            parts.extensions.insert(user);
+            inner.call(Request::from_parts(parts, body)).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, http::StatusCode, routing::get};
+    use axum_test::TestServer;
+    use serde_json::Value;
+    use starscalendars_infra::MockJwtService;
+
+    fn jwt_service(roles: &[&str], subscribed: bool) -> Arc<dyn JwtService> {
+        let roles: Vec<String> = roles.iter().map(|role| role.to_string()).collect();
+        let user_id = starscalendars_domain::UserId::new();
+        let mut service = MockJwtService::new();
+        service.set_mock_claims(JwtClaims::new(&user_id, None, subscribed, &roles));
+        Arc::new(service)
+    }
+
+    async fn whoami(user: Option<AuthUser>) -> String {
+        user.map_or_else(|| "anonymous".to_string(), |user| user.0.sub)
+    }
+
+    async fn premium(user: AuthUser) -> String {
+        user.0.sub
+    }
+
+    fn server(jwt_service: Arc<dyn JwtService>) -> Option<TestServer> {
+        let app = Router::new()
+            .route(
+                "/premium",
+                get(premium).route_layer(require_role(jwt_service.clone(), PREMIUM_ROLE)),
+            )
+            .route("/whoami", get(whoami))
+            .with_state(jwt_service);
+        TestServer::new(app).ok()
+    }
+
+    #[test]
+    fn test_role_checks() {
+        let claims = |roles: &[&str], subscribed| {
+            let roles: Vec<String> = roles.iter().map(|role| role.to_string()).collect();
+            let user_id = starscalendars_domain::UserId::new();
+            AuthUser(JwtClaims::new(&user_id, None, subscribed, &roles))
+        };
+
+        assert!(!claims(&[], false).has_role(PREMIUM_ROLE));
+        assert!(claims(&[], true).has_role(PREMIUM_ROLE));
+        assert!(claims(&["premium"], false).has_role(PREMIUM_ROLE));
+        assert!(!claims(&["premium"], false).has_role(ADMIN_ROLE));
+        assert!(claims(&["admin"], false).has_role(PREMIUM_ROLE));
+        assert!(claims(&["admin"], false).has_role("moderator"));
+    }
+
+    #[tokio::test]
+    async fn test_optional_auth() {
+        let Some(app) = server(jwt_service(&[], false)) else {
+            panic!("Failed to start the test server");
+        };
+
+        let response = app.get("/whoami").await;
+        response.assert_status_ok();
+        response.assert_text("anonymous");
+
+        let response = app
+            .get("/whoami")
+            .add_header(header::AUTHORIZATION, "Bearer token")
+            .await;
+        response.assert_status_ok();
+        assert_ne!(response.text(), "anonymous");
+
+        let response = app
+            .get("/whoami")
+            .add_header(header::AUTHORIZATION, "Basic token")
+            .await;
+        response.assert_status(StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_require_role() {
+        let Some(app) = server(jwt_service(&[], false)) else {
+            panic!("Failed to start the test server");
+        };
+        let response = app.get("/premium").await;
+        response.assert_status(StatusCode::UNAUTHORIZED);
+        response.assert_header(header::WWW_AUTHENTICATE, "Bearer");
+        let body: Value = response.json();
+        assert_eq!(body["code"], "missing_token");
+        assert_eq!(body["detail"], "Missing bearer token");
+
+        let response = app
+            .get("/premium")
+            .add_header(header::AUTHORIZATION, "Bearer token")
+            .await;
+        response.assert_status(StatusCode::FORBIDDEN);
+        assert_eq!(response.json::<Value>()["code"], "insufficient_role");
+
+        let Some(app) = server(jwt_service(&["premium"], false)) else {
+            panic!("Failed to start the test server");
+        };
+        let response = app
+            .get("/premium")
+            .add_header(header::AUTHORIZATION, "Bearer token")
+            .await;
+        response.assert_status_ok();
+    }
+
+    #[tokio::test]
+    async fn test_invalid_token() {
+        let mut service = MockJwtService::new();
+        service.set_tokens_valid(false);
+        let Some(app) = server(Arc::new(service)) else {
+            panic!("Failed to start the test server");
+        };
+
+        let response = app
+            .get("/premium")
+            .add_header(header::AUTHORIZATION, "Bearer token")
+            .await;
+        response.assert_status(StatusCode::UNAUTHORIZED);
+        response.assert_header(header::WWW_AUTHENTICATE, "Bearer error=\"invalid_token\"");
+        assert_eq!(response.json::<Value>()["code"], "invalid_token");
+    }
+}
@@ -0,0 +1,414 @@
+//! Bot reply catalog
+//!
+//! Covers the languages of the dioxus-app i18n layer, with the same Moon
+//! phase names. Templates use `{name}` placeholders and Telegram legacy
+//! Markdown, so literal `_`, `*`, `` ` `` and `[` are escaped.
+
+use starscalendars_domain::notifications;
+use starscalendars_domain::spiritual::SpiritualEvent;
+
+pub use crate::i18n::{DEFAULT_LANGUAGE, LANGUAGES, native_name, supported_language};
+
+/// Replies of the bot in one language
+pub struct BotTexts {
+    pub welcome: &'static str,
+    pub help: &'static str,
+    pub link_instructions: &'static str,
+    /// `{username}`
+    pub already_linked: &'static str,
+    pub link_success: &'static str,
+    pub link_taken: &'static str,
+    pub link_expired: &'static str,
+    /// `{phase}`, `{next}`, `{date}`
+    pub moon: &'static str,
+    pub today_header: &'static str,
+    pub no_events_today: &'static str,
+    /// `{title}`, `{date}`
+    pub next_event: &'static str,
+    pub no_events: &'static str,
+    /// `{language}`, `{languages}`
+    pub lang_current: &'static str,
+    /// `{language}`
+    pub lang_changed: &'static str,
+    /// `{languages}`
+    pub lang_unsupported: &'static str,
+    /// `/lang` before linking: the language is kept on the profile
+    pub lang_requires_link: &'static str,
+    /// `{language}`, `{account}`, `{subscription}`
+    pub settings: &'static str,
+    pub not_linked: &'static str,
+    pub subscribed: &'static str,
+    pub not_subscribed: &'static str,
+    pub error: &'static str,
+    /// `{title}`, `{date}`: scheduled event notification
+    pub notification: &'static str,
+    /// 8-way phases, 0 New Moon … 7 Waning Crescent
+    pub moon_phase_names: [&'static str; 8],
+    /// 0 March equinox … 3 December solstice
+    pub season_names: [&'static str; 4],
+}
+
+const EN: BotTexts = BotTexts {
+    welcome: "🌌 Welcome to StarsCalendars!",
+    help: "Commands: /moon, /today, /next\\_event, /link, /lang, /settings",
+    link_instructions: "To link your account, open Settings → Telegram on the website and press Link: Telegram will bring you back here.",
+    already_linked: "✅ This Telegram account is linked to {username}.",
+    link_success: "✅ Your Telegram account is now linked to StarsCalendars.",
+    link_taken: "This Telegram account is already linked to another StarsCalendars user.",
+    link_expired: "This link has expired or was already used. Please request a new one on the website.",
+    moon: "🌙 {phase}\nNext: {next}, {date}",
+    today_header: "✨ Today:",
+    no_events_today: "No events today.",
+    next_event: "✨ Next event: {title}, {date}",
+    no_events: "No upcoming events yet.",
+    lang_current: "Language: {language}\nAvailable: {languages}\nChange with /lang <code>",
+    lang_changed: "Language set to {language}.",
+    lang_unsupported: "Unsupported language. Available: {languages}",
+    lang_requires_link: "Link your StarsCalendars account with /link to save a language.",
+    settings: "⚙️ Settings\nLanguage: {language}\nAccount: {account}\nSubscription: {subscription}",
+    not_linked: "not linked, see /link",
+    subscribed: "active",
+    not_subscribed: "inactive",
+    error: "Something went wrong, please try again later.",
+    notification: "🔔 Reminder: {title}\n{date}",
+    moon_phase_names: [
+        "New Moon",
+        "Waxing Crescent",
+        "First Quarter",
+        "Waxing Gibbous",
+        "Full Moon",
+        "Waning Gibbous",
+        "Last Quarter",
+        "Waning Crescent",
+    ],
+    season_names: [
+        "March Equinox",
+        "June Solstice",
+        "September Equinox",
+        "December Solstice",
+    ],
+};
+
+const RU: BotTexts = BotTexts {
+    welcome: "🌌 Добро пожаловать в StarsCalendars!",
+    help: "Команды: /moon, /today, /next\\_event, /link, /lang, /settings",
+    link_instructions: "Чтобы привязать аккаунт, откройте на сайте Настройки → Telegram и нажмите «Привязать»: Telegram вернёт вас сюда.",
+    already_linked: "✅ Этот аккаунт Telegram привязан к {username}.",
+    link_success: "✅ Ваш аккаунт Telegram привязан к StarsCalendars.",
+    link_taken: "Этот аккаунт Telegram уже привязан к другому пользователю StarsCalendars.",
+    link_expired: "Ссылка устарела или уже использована. Запросите новую на сайте.",
+    moon: "🌙 {phase}\nДалее: {next}, {date}",
+    today_header: "✨ Сегодня:",
+    no_events_today: "Сегодня событий нет.",
+    next_event: "✨ Ближайшее событие: {title}, {date}",
+    no_events: "Предстоящих событий пока нет.",
+    lang_current: "Язык: {language}\nДоступны: {languages}\nСменить: /lang <код>",
+    lang_changed: "Язык изменён: {language}.",
+    lang_unsupported: "Язык не поддерживается. Доступны: {languages}",
+    lang_requires_link: "Чтобы сохранить язык, привяжите аккаунт StarsCalendars: /link",
+    settings: "⚙️ Настройки\nЯзык: {language}\nАккаунт: {account}\nПодписка: {subscription}",
+    not_linked: "не привязан, см. /link",
+    subscribed: "активна",
+    not_subscribed: "неактивна",
+    error: "Что-то пошло не так, попробуйте позже.",
+    notification: "🔔 Напоминание: {title}\n{date}",
+    moon_phase_names: [
+        "Новолуние",
+        "Растущий серп",
+        "Первая четверть",
+        "Растущая луна",
+        "Полнолуние",
+        "Убывающая луна",
+        "Последняя четверть",
+        "Убывающий серп",
+    ],
+    season_names: [
+        "Мартовское равноденствие",
+        "Июньское солнцестояние",
+        "Сентябрьское равноденствие",
+        "Декабрьское солнцестояние",
+    ],
+};
+
+const ES: BotTexts = BotTexts {
+    welcome: "🌌 ¡Bienvenido a StarsCalendars!",
+    help: "Comandos: /moon, /today, /next\\_event, /link, /lang, /settings",
+    link_instructions: "Para vincular tu cuenta, abre Ajustes → Telegram en el sitio web y pulsa Vincular: Telegram te traerá de vuelta aquí.",
+    already_linked: "✅ Esta cuenta de Telegram está vinculada a {username}.",
+    link_success: "✅ Tu cuenta de Telegram ya está vinculada a StarsCalendars.",
+    link_taken: "Esta cuenta de Telegram ya está vinculada a otro usuario de StarsCalendars.",
+    link_expired: "El enlace ha caducado o ya se usó. Solicita uno nuevo en el sitio web.",
+    moon: "🌙 {phase}\nSiguiente: {next}, {date}",
+    today_header: "✨ Hoy:",
+    no_events_today: "Hoy no hay eventos.",
+    next_event: "✨ Próximo evento: {title}, {date}",
+    no_events: "Todavía no hay eventos próximos.",
+    lang_current: "Idioma: {language}\nDisponibles: {languages}\nCambiar con /lang <código>",
+    lang_changed: "Idioma cambiado a {language}.",
+    lang_unsupported: "Idioma no disponible. Disponibles: {languages}",
+    lang_requires_link: "Vincula tu cuenta de StarsCalendars con /link para guardar el idioma.",
+    settings: "⚙️ Ajustes\nIdioma: {language}\nCuenta: {account}\nSuscripción: {subscription}",
+    not_linked: "no vinculada, ver /link",
+    subscribed: "activa",
+    not_subscribed: "inactiva",
+    error: "Algo salió mal, inténtalo más tarde.",
+    notification: "🔔 Recordatorio: {title}\n{date}",
+    moon_phase_names: [
+        "Luna Nueva",
+        "Luna Creciente",
+        "Cuarto Creciente",
+        "Gibosa Creciente",
+        "Luna Llena",
+        "Gibosa Menguante",
+        "Cuarto Menguante",
+        "Luna Menguante",
+    ],
+    season_names: [
+        "Equinoccio de marzo",
+        "Solsticio de junio",
+        "Equinoccio de septiembre",
+        "Solsticio de diciembre",
+    ],
+};
+
+const ZH: BotTexts = BotTexts {
+    welcome: "🌌 欢迎来到 StarsCalendars！",
+    help: "命令：/moon, /today, /next\\_event, /link, /lang, /settings",
+    link_instructions: "要绑定账户，请在网站上打开 设置 → Telegram 并点击绑定：Telegram 会将您带回这里。",
+    already_linked: "✅ 此 Telegram 账户已绑定到 {username}。",
+    link_success: "✅ 您的 Telegram 账户已绑定到 StarsCalendars。",
+    link_taken: "此 Telegram 账户已绑定到另一个 StarsCalendars 用户。",
+    link_expired: "链接已过期或已被使用。请在网站上重新获取。",
+    moon: "🌙 {phase}\n下一个：{next}，{date}",
+    today_header: "✨ 今天：",
+    no_events_today: "今天没有事件。",
+    next_event: "✨ 下一个事件：{title}，{date}",
+    no_events: "暂无即将发生的事件。",
+    lang_current: "语言：{language}\n可选：{languages}\n使用 /lang <代码> 更改",
+    lang_changed: "语言已设置为 {language}。",
+    lang_unsupported: "不支持该语言。可选：{languages}",
+    lang_requires_link: "请先使用 /link 关联 StarsCalendars 账户以保存语言。",
+    settings: "⚙️ 设置\n语言：{language}\n账户：{account}\n订阅：{subscription}",
+    not_linked: "未绑定，见 /link",
+    subscribed: "有效",
+    not_subscribed: "无效",
+    error: "出了点问题，请稍后再试。",
+    notification: "🔔 提醒：{title}\n{date}",
+    moon_phase_names: [
+        "新月",
+        "蛾眉月",
+        "上弦月",
+        "盈凸月",
+        "满月",
+        "亏凸月",
+        "下弦月",
+        "残月",
+    ],
+    season_names: ["春分", "夏至", "秋分", "冬至"],
+};
+
+const HI: BotTexts = BotTexts {
+    welcome: "🌌 StarsCalendars में आपका स्वागत है!",
+    help: "कमांड: /moon, /today, /next\\_event, /link, /lang, /settings",
+    link_instructions: "अपना खाता जोड़ने के लिए वेबसाइट पर सेटिंग्स → Telegram खोलें और जोड़ें दबाएँ: Telegram आपको यहीं वापस लाएगा।",
+    already_linked: "✅ यह Telegram खाता {username} से जुड़ा है।",
+    link_success: "✅ आपका Telegram खाता अब StarsCalendars से जुड़ गया है।",
+    link_taken: "यह Telegram खाता पहले से किसी अन्य StarsCalendars उपयोगकर्ता से जुड़ा है।",
+    link_expired: "यह लिंक समाप्त हो गया है या पहले ही उपयोग हो चुका है। कृपया वेबसाइट पर नया लिंक लें।",
+    moon: "🌙 {phase}\nअगला: {next}, {date}",
+    today_header: "✨ आज:",
+    no_events_today: "आज कोई घटना नहीं है।",
+    next_event: "✨ अगली घटना: {title}, {date}",
+    no_events: "अभी कोई आगामी घटना नहीं है।",
+    lang_current: "भाषा: {language}\nउपलब्ध: {languages}\nबदलने के लिए /lang <कोड>",
+    lang_changed: "भाषा {language} पर सेट की गई।",
+    lang_unsupported: "यह भाषा समर्थित नहीं है। उपलब्ध: {languages}",
+    lang_requires_link: "भाषा सहेजने के लिए /link से अपना StarsCalendars खाता जोड़ें।",
+    settings: "⚙️ सेटिंग्स\nभाषा: {language}\nखाता: {account}\nसदस्यता: {subscription}",
+    not_linked: "नहीं जुड़ा, देखें /link",
+    subscribed: "सक्रिय",
+    not_subscribed: "निष्क्रिय",
+    error: "कुछ गलत हो गया, कृपया बाद में पुनः प्रयास करें।",
+    notification: "🔔 अनुस्मारक: {title}\n{date}",
+    moon_phase_names: [
+        "अमावस्या",
+        "शुक्ल पक्ष",
+        "प्रथम चतुर्थी",
+        "शुक्ल पक्ष",
+        "पूर्णिमा",
+        "कृष्ण पक्ष",
+        "तृतीय चतुर्थी",
+        "कृष्ण पक्ष",
+    ],
+    season_names: ["मार्च विषुव", "जून संक्रांति", "सितंबर विषुव", "दिसंबर संक्रांति"],
+};
+
+const HY: BotTexts = BotTexts {
+    welcome: "🌌 Բարի գալուստ StarsCalendars!",
+    help: "Հրամաններ՝ /moon, /today, /next\\_event, /link, /lang, /settings",
+    link_instructions: "Հաշիվը կապելու համար կայքում բացեք Կարգավորումներ → Telegram և սեղմեք Կապել․ Telegram-ը ձեզ կվերադարձնի այստեղ։",
+    already_linked: "✅ Այս Telegram հաշիվը կապված է {username}-ին։",
+    link_success: "✅ Ձեր Telegram հաշիվը կապված է StarsCalendars-ին։",
+    link_taken: "Այս Telegram հաշիվն արդեն կապված է StarsCalendars-ի այլ օգտատիրոջ։",
+    link_expired: "Հղումը ժամկետանց է կամ արդեն օգտագործված։ Խնդրում ենք կայքում նորը ստանալ։",
+    moon: "🌙 {phase}\nՀաջորդը՝ {next}, {date}",
+    today_header: "✨ Այսօր՝",
+    no_events_today: "Այսօր իրադարձություններ չկան։",
+    next_event: "✨ Հաջորդ իրադարձությունը՝ {title}, {date}",
+    no_events: "Առաջիկա իրադարձություններ դեռ չկան։",
+    lang_current: "Լեզու՝ {language}\nՀասանելի են՝ {languages}\nՓոխել՝ /lang <կոդ>",
+    lang_changed: "Լեզուն փոխվեց՝ {language}։",
+    lang_unsupported: "Լեզուն չի աջակցվում։ Հասանելի են՝ {languages}",
+    lang_requires_link: "Լեզուն պահպանելու համար կապեք ձեր StarsCalendars հաշիվը՝ /link",
+    settings: "⚙️ Կարգավորումներ\nԼեզու՝ {language}\nՀաշիվ՝ {account}\nԲաժանորդագրություն՝ {subscription}",
+    not_linked: "կապված չէ, տես /link",
+    subscribed: "ակտիվ",
+    not_subscribed: "ոչ ակտիվ",
+    error: "Ինչ-որ բան սխալ գնաց, փորձեք ավելի ուշ։",
+    notification: "🔔 Հիշեցում՝ {title}\n{date}",
+    moon_phase_names: [
+        "Նորալուսին",
+        "Աճող մահիկ",
+        "Առաջին քառորդ",
+        "Աճող լուսին",
+        "Լիալուսին",
+        "Նվազող լուսին",
+        "Վերջին քառորդ",
+        "Նվազող մահիկ",
+    ],
+    season_names: [
+        "Մարտյան գիշերահավասար",
+        "Հունիսյան արևադարձ",
+        "Սեպտեմբերյան գիշերահավասար",
+        "Դեկտեմբերյան արևադարձ",
+    ],
+};
+
+/// Date and time in replies and notifications
+const TIME_FORMAT: &[time::format_description::BorrowedFormatItem<'static>] =
+    time::macros::format_description!("[year]-[month]-[day] [hour]:[minute]");
+
+/// Texts of a supported language, English otherwise
+pub fn texts(language: &str) -> &'static BotTexts {
+    match language {
+        "ru" => &RU,
+        "es" => &ES,
+        "zh" => &ZH,
+        "hi" => &HI,
+        "hy" => &HY,
+        _ => &EN,
+    }
+}
+
+/// "en (English), ru (Русский), ..." for language lists
+pub fn language_list() -> String {
+    LANGUAGES
+        .iter()
+        .map(|(code, name)| format!("{} ({})", code, name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Name of an 8-way Moon phase
+pub fn phase_name(t: &BotTexts, index: u8) -> &'static str {
+    t.moon_phase_names
+        .get(usize::from(index))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Localized name of Moon phase and season events, escaped stored title otherwise
+pub fn event_title(t: &BotTexts, event: &SpiritualEvent) -> String {
+    if let Some(index) = event.moon_phase_index() {
+        return phase_name(t, index).to_string();
+    }
+    event
+        .season_index()
+        .and_then(|index| t.season_names.get(usize::from(index)))
+        .map_or_else(|| escape_markdown(&event.title), |name| name.to_string())
+}
+
+/// "2025-07-10 06:14 UTC"
+pub fn format_utc_time(at: time::OffsetDateTime) -> String {
+    at.to_offset(time::UtcOffset::UTC)
+        .format(TIME_FORMAT)
+        .map_or_else(|_| at.to_string(), |date| format!("{} UTC", date))
+}
+
+/// "2025-07-10 09:14 (Europe/Moscow)" in the user's time zone
+pub fn format_local_time(at: time::OffsetDateTime, timezone: &str) -> String {
+    notifications::local_time(at, timezone)
+        .format(TIME_FORMAT)
+        .map_or_else(
+            |_| at.to_string(),
+            |date| format!("{} ({})", date, escape_markdown(timezone)),
+        )
+}
+
+/// Substitute `{name}` placeholders
+pub fn format(template: &str, args: &[(&str, &str)]) -> String {
+    args.iter()
+        .fold(template.to_string(), |text, (key, value)| {
+            text.replace(&format!("{{{}}}", key), value)
+        })
+}
+
+/// Escape text inserted into a legacy Markdown reply
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '_' | '*' | '`' | '[') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_resolution() {
+        assert_eq!(texts("pt").welcome, EN.welcome);
+        // An unescaped `_` breaks legacy Markdown parsing of the whole reply
+        for (code, _) in LANGUAGES {
+            assert!(texts(code).help.contains("/next\\_event"), "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_format_and_escape() {
+        let text = format(
+            EN.already_linked,
+            &[("username", &escape_markdown("ada_l"))],
+        );
+        assert_eq!(text, "✅ This Telegram account is linked to ada\\_l.");
+        assert_eq!(format("{a} {a} {b}", &[("a", "1")]), "1 1 {b}");
+    }
+
+    #[test]
+    fn test_event_titles_and_times() {
+        let at = time::macros::datetime!(2025-03-20 09:01 UTC);
+        let equinox = SpiritualEvent::new(
+            SpiritualEvent::MARCH_EQUINOX.to_string(),
+            "March Equinox".to_string(),
+            at,
+        );
+        assert_eq!(event_title(&RU, &equinox), "Мартовское равноденствие");
+        let custom =
+            SpiritualEvent::new("retreat".to_string(), "Full_Moon retreat".to_string(), at);
+        assert_eq!(event_title(&ES, &custom), "Full\\_Moon retreat");
+
+        assert_eq!(format_utc_time(at), "2025-03-20 09:01 UTC");
+        assert_eq!(
+            format_local_time(at, "Asia/Yerevan"),
+            "2025-03-20 13:01 (Asia/Yerevan)"
+        );
+        assert_eq!(
+            format_local_time(at, "America/Argentina/Buenos_Aires"),
+            "2025-03-20 06:01 (America/Argentina/Buenos\\_Aires)"
+        );
+    }
+}
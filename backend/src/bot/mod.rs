@@ -0,0 +1,580 @@
+//! Telegram bot: commands with localized replies
+//!
+//! Updates arrive through the webhook route or `run_long_polling`, commands
+//! are parsed with teloxide's `BotCommands`, and replies go out through the
+//! `TelegramService` port. Pointing that port at a local fake Bot API server
+//! (`TelegramApiService::with_base_url`) runs the bot end to end in tests.
+//!
+//! Astronomical data comes from the precomputed `spiritual_events`; the bot
+//! calculates nothing itself.
+
+pub mod i18n;
+
+use crate::telegram_link::TelegramLinker;
+use i18n::BotTexts;
+use starscalendars_app::{
+    AppError, AppResult, AuthUseCase, SpiritualEventRepository, TelegramService, UserRepository,
+    UserUseCase,
+};
+use starscalendars_domain::notifications;
+use starscalendars_domain::spiritual::{MoonPhase, SpiritualEvent};
+use starscalendars_domain::telegram::LinkingToken;
+use starscalendars_domain::{DomainError, ProfilePatch, User};
+use starscalendars_infra::{BotUpdate, BotUser, TelegramApiService};
+use std::sync::Arc;
+use std::time::Duration;
+use teloxide::utils::command::BotCommands;
+use tracing::{error, info, warn};
+
+/// `getUpdates` long-poll timeout
+const POLL_TIMEOUT_SECS: u64 = 25;
+
+/// Pause after a failed `getUpdates` call
+const POLL_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Commands understood by the bot
+#[derive(BotCommands, Clone, Debug, PartialEq)]
+#[command(rename_rule = "snake_case")]
+pub enum Command {
+    /// Welcome, or `/start <token>` from a linking deep link
+    Start(String),
+    /// How to link the website account
+    Link,
+    /// Current Moon phase and the next principal phase
+    Moon,
+    /// Today's events
+    Today,
+    /// Next upcoming event
+    NextEvent,
+    /// Show or change the reply language
+    Lang(String),
+    /// Language, linked account and subscription
+    Settings,
+}
+
+/// Telegram bot command handler
+pub struct TelegramBot {
+    username: String,
+    auth: Arc<dyn AuthUseCase>,
+    linker: Arc<TelegramLinker>,
+    users: Arc<dyn UserRepository>,
+    profiles: Arc<dyn UserUseCase>,
+    events: Arc<dyn SpiritualEventRepository>,
+    telegram: Arc<dyn TelegramService>,
+}
+
+impl TelegramBot {
+    pub fn new(
+        username: &str,
+        auth: Arc<dyn AuthUseCase>,
+        linker: Arc<TelegramLinker>,
+        users: Arc<dyn UserRepository>,
+        profiles: Arc<dyn UserUseCase>,
+        events: Arc<dyn SpiritualEventRepository>,
+        telegram: Arc<dyn TelegramService>,
+    ) -> Self {
+        Self {
+            username: username.trim_start_matches('@').to_string(),
+            auth,
+            linker,
+            users,
+            profiles,
+            events,
+            telegram,
+        }
+    }
+
+    /// Answer a command message; other updates and plain text are ignored
+    pub async fn handle_update(&self, update: &BotUpdate) {
+        let Some(message) = &update.message else {
+            return;
+        };
+        let (Some(from), Some(text)) = (&message.from, &message.text) else {
+            return;
+        };
+        if from.is_bot || !text.starts_with('/') {
+            return;
+        }
+
+        let profile = match self.users.get_user_by_telegram_id(from.id).await {
+            Ok(profile) => profile,
+            Err(e) => {
+                warn!("Profile lookup for Telegram user {} failed: {}", from.id, e);
+                None
+            }
+        };
+        let language = reply_language(profile.as_ref(), from);
+        let reply = match Command::parse(text, &self.username) {
+            Ok(command) => match self
+                .reply(command, text, from, profile.as_ref(), language)
+                .await
+            {
+                Ok(reply) => reply,
+                Err(e) => {
+                    error!("Bot command {:?} failed: {}", text, e);
+                    i18n::texts(language).error.to_string()
+                }
+            },
+            // Commands addressed to other bots in group chats
+            Err(teloxide::utils::command::ParseError::WrongBotName(_)) => return,
+            Err(_) => i18n::texts(language).help.to_string(),
+        };
+
+        if let Err(e) = self.telegram.send_message(message.chat.id, &reply).await {
+            warn!("Failed to send bot reply to {}: {}", message.chat.id, e);
+        }
+    }
+
+    /// `profile` is the linked account, if any
+    async fn reply(
+        &self,
+        command: Command,
+        text: &str,
+        from: &BotUser,
+        profile: Option<&User>,
+        language: &'static str,
+    ) -> AppResult<String> {
+        let t = i18n::texts(language);
+        match command {
+            Command::Start(_) => Ok(match LinkingToken::from_start_command(text) {
+                Some(token) => self.link(t, token, from.id).await,
+                None => format!("{}\n{}", t.welcome, t.help),
+            }),
+            Command::Link => Ok(match profile {
+                Some(user) => i18n::format(
+                    t.already_linked,
+                    &[("username", &i18n::escape_markdown(&user.username))],
+                ),
+                None => t.link_instructions.to_string(),
+            }),
+            Command::Moon => self.moon(t).await,
+            Command::Today => {
+                let timezone = profile.map_or("UTC", |user| user.timezone.as_str());
+                self.today(t, timezone).await
+            }
+            Command::NextEvent => {
+                let now = time::OffsetDateTime::now_utc();
+                Ok(match self.events.next_event(now, &[]).await? {
+                    Some(event) => i18n::format(
+                        t.next_event,
+                        &[
+                            ("title", &i18n::event_title(t, &event)),
+                            ("date", &i18n::format_utc_time(event.occurs_at)),
+                        ],
+                    ),
+                    None => t.no_events.to_string(),
+                })
+            }
+            Command::Lang(code) => {
+                self.change_language(t, profile, language, code.trim())
+                    .await
+            }
+            Command::Settings => self.settings(t, from.id, profile, language).await,
+        }
+    }
+
+    /// Complete a deep-link account link
+    async fn link(&self, t: &BotTexts, token: LinkingToken, telegram_user_id: i64) -> String {
+        match self.linker.confirm(&token.uuid(), telegram_user_id).await {
+            Ok(_) => t.link_success.to_string(),
+            Err(AppError::Domain(DomainError::TelegramAlreadyLinked(_))) => {
+                t.link_taken.to_string()
+            }
+            Err(AppError::Domain(DomainError::InvalidLinkingToken)) => t.link_expired.to_string(),
+            Err(e) => {
+                error!("Telegram account linking failed: {}", e);
+                t.error.to_string()
+            }
+        }
+    }
+
+    /// Phase between the surrounding principal phases
+    async fn moon(&self, t: &BotTexts) -> AppResult<String> {
+        let now = time::OffsetDateTime::now_utc();
+        let previous = self
+            .events
+            .previous_event(now, &SpiritualEvent::MOON_PHASES)
+            .await?;
+        let next = self
+            .events
+            .next_event(now, &SpiritualEvent::MOON_PHASES)
+            .await?;
+
+        let (Some(previous), Some(next)) = (previous, next) else {
+            return Ok(t.no_events.to_string());
+        };
+        let (Some(previous_index), Some(next_index)) =
+            (previous.moon_phase_index(), next.moon_phase_index())
+        else {
+            return Ok(t.no_events.to_string());
+        };
+
+        let progress = (now - previous.occurs_at) / (next.occurs_at - previous.occurs_at);
+        let phase = MoonPhase::index_between_principal(previous_index, progress);
+        Ok(i18n::format(
+            t.moon,
+            &[
+                ("phase", i18n::phase_name(t, phase)),
+                ("next", i18n::phase_name(t, next_index)),
+                ("date", &i18n::format_utc_time(next.occurs_at)),
+            ],
+        ))
+    }
+
+    /// Events of the current day in the user's time zone
+    async fn today(&self, t: &BotTexts, timezone: &str) -> AppResult<String> {
+        let start = notifications::delivery_time(time::OffsetDateTime::now_utc(), timezone, 0);
+        // Local days last 23 to 25 hours, so 26 hours on is the next day
+        let end = notifications::delivery_time(start + time::Duration::hours(26), timezone, 0);
+        let events = self.events.events_between(start, end).await?;
+        if events.is_empty() {
+            return Ok(t.no_events_today.to_string());
+        }
+
+        let mut reply = t.today_header.to_string();
+        for event in &events {
+            reply.push_str(&format!(
+                "\n• {}, {}",
+                i18n::event_title(t, event),
+                i18n::format_local_time(event.occurs_at, timezone)
+            ));
+        }
+        Ok(reply)
+    }
+
+    /// Show the reply language, or save a new one on the linked profile
+    async fn change_language(
+        &self,
+        t: &BotTexts,
+        profile: Option<&User>,
+        current: &'static str,
+        code: &str,
+    ) -> AppResult<String> {
+        if code.is_empty() {
+            return Ok(i18n::format(
+                t.lang_current,
+                &[
+                    ("language", i18n::native_name(current)),
+                    ("languages", &i18n::language_list()),
+                ],
+            ));
+        }
+
+        let Some(language) = i18n::supported_language(code) else {
+            return Ok(i18n::format(
+                t.lang_unsupported,
+                &[("languages", &i18n::language_list())],
+            ));
+        };
+        let Some(user) = profile else {
+            return Ok(t.lang_requires_link.to_string());
+        };
+        let patch = ProfilePatch {
+            language: Some(Some(language.to_string())),
+            ..ProfilePatch::default()
+        };
+        self.profiles.patch_user_profile(&user.id, &patch).await?;
+        // Confirmed in the new language
+        Ok(i18n::format(
+            i18n::texts(language).lang_changed,
+            &[("language", i18n::native_name(language))],
+        ))
+    }
+
+    async fn settings(
+        &self,
+        t: &BotTexts,
+        telegram_user_id: i64,
+        profile: Option<&User>,
+        language: &'static str,
+    ) -> AppResult<String> {
+        let (account, subscription) = match profile {
+            Some(user) => {
+                let subscribed = self
+                    .auth
+                    .check_subscription_status(telegram_user_id)
+                    .await?;
+                (
+                    i18n::escape_markdown(&user.username),
+                    if subscribed {
+                        t.subscribed
+                    } else {
+                        t.not_subscribed
+                    },
+                )
+            }
+            None => (t.not_linked.to_string(), t.not_subscribed),
+        };
+
+        Ok(i18n::format(
+            t.settings,
+            &[
+                ("language", i18n::native_name(language)),
+                ("account", &account),
+                ("subscription", subscription),
+            ],
+        ))
+    }
+}
+
+/// Reply language: the linked profile's, then the Telegram client language
+fn reply_language(profile: Option<&User>, from: &BotUser) -> &'static str {
+    profile
+        .and_then(|user| i18n::supported_language(&user.language))
+        .or_else(|| {
+            from.language_code
+                .as_deref()
+                .and_then(i18n::supported_language)
+        })
+        .unwrap_or(i18n::DEFAULT_LANGUAGE)
+}
+
+/// Feed `getUpdates` to the bot; used when no webhook is configured
+pub async fn run_long_polling(api: TelegramApiService, bot: Arc<TelegramBot>) {
+    info!("📨 Receiving Telegram updates by long polling");
+
+    let mut offset = 0;
+    loop {
+        match api.get_updates(offset, POLL_TIMEOUT_SECS).await {
+            Ok(updates) => {
+                for update in updates {
+                    offset = offset.max(update.update_id + 1);
+                    bot.handle_update(&update).await;
+                }
+            }
+            Err(e) => {
+                warn!("getUpdates failed: {}", e);
+                tokio::time::sleep(POLL_RETRY_DELAY).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::websocket::WebSocketManager;
+    use axum::{Json, Router, extract::State, routing::post};
+    use serde_json::{Value, json};
+    use starscalendars_app::{AppServices, AuthUseCaseImpl, UserUseCaseImpl};
+    use starscalendars_domain::{TelegramUserId, UserId};
+    use starscalendars_infra::{
+        MockSpiritualEventRepository, MockUserRepository, TelegramAuthVerifierImpl,
+    };
+    use tokio::sync::Mutex;
+
+    type Sent = Arc<Mutex<Vec<Value>>>;
+
+    /// Fake Bot API server recording `sendMessage` calls
+    async fn fake_bot_api() -> Option<(String, Sent)> {
+        async fn send_message(State(sent): State<Sent>, Json(body): Json<Value>) -> Json<Value> {
+            sent.lock().await.push(body);
+            Json(json!({ "ok": true, "result": {} }))
+        }
+
+        let sent = Sent::default();
+        let app = Router::new()
+            .route("/botTEST/sendMessage", post(send_message))
+            .with_state(sent.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.ok()?;
+        let url = format!("http://{}", listener.local_addr().ok()?);
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        Some((url, sent))
+    }
+
+    fn event(event_type: &str, at: time::OffsetDateTime) -> SpiritualEvent {
+        SpiritualEvent::new(event_type.to_string(), event_type.to_string(), at)
+    }
+
+    fn moon_event(event_type: &str, offset: time::Duration) -> SpiritualEvent {
+        event(event_type, time::OffsetDateTime::now_utc() + offset)
+    }
+
+    /// Account of Telegram user 42
+    fn linked_user(timezone: &str) -> User {
+        let now = time::OffsetDateTime::now_utc();
+        User {
+            id: UserId::new(),
+            username: "ada".to_string(),
+            email: None,
+            telegram_user_id: TelegramUserId::new(42).ok(),
+            created_at: now,
+            updated_at: now,
+            is_active: true,
+            language: "en".to_string(),
+            timezone: timezone.to_string(),
+            spiritual_preferences: json!({}),
+        }
+    }
+
+    fn update(text: &str, language_code: &str) -> Option<BotUpdate> {
+        serde_json::from_value(json!({
+            "update_id": 1,
+            "message": {
+                "chat": { "id": 42 },
+                "from": { "id": 42, "is_bot": false, "language_code": language_code },
+                "text": text,
+            }
+        }))
+        .ok()
+    }
+
+    /// Bot over `users` and `events`, replying through the fake Bot API
+    fn bot(
+        base_url: String,
+        users: Arc<MockUserRepository>,
+        events: MockSpiritualEventRepository,
+    ) -> Option<TelegramBot> {
+        let mut services = AppServices::new_test();
+        services.user_repository = users.clone();
+        let verifier = TelegramAuthVerifierImpl::new("TEST", time::Duration::hours(1)).ok()?;
+        let auth: Arc<dyn AuthUseCase> = Arc::new(AuthUseCaseImpl::new(
+            services.clone(),
+            "@test_channel".to_string(),
+            Arc::new(verifier),
+            time::Duration::days(7),
+        ));
+        let telegram: Arc<dyn TelegramService> = Arc::new(TelegramApiService::with_base_url(
+            "TEST".to_string(),
+            base_url,
+        ));
+        let websocket_manager = Arc::new(WebSocketManager::new(services.jwt_service.clone()));
+        let linker = Arc::new(TelegramLinker::new(auth.clone(), websocket_manager));
+
+        Some(TelegramBot::new(
+            "@stars_bot",
+            auth,
+            linker,
+            users,
+            Arc::new(UserUseCaseImpl::new(services)),
+            Arc::new(events),
+            telegram,
+        ))
+    }
+
+    /// Full moon two days ago, last quarter in five days
+    fn moon_events() -> MockSpiritualEventRepository {
+        let events = MockSpiritualEventRepository::new();
+        events.add_event(moon_event(
+            SpiritualEvent::FULL_MOON,
+            -time::Duration::days(2),
+        ));
+        events.add_event(moon_event(
+            SpiritualEvent::LAST_QUARTER,
+            time::Duration::days(5),
+        ));
+        events
+    }
+
+    /// Send each text as user 42 and collect the replies
+    async fn replies(
+        bot: &TelegramBot,
+        sent: &Sent,
+        texts: &[&str],
+        language_code: &str,
+    ) -> Vec<String> {
+        for text in texts {
+            let Some(update) = update(text, language_code) else {
+                panic!("invalid update for {:?}", text);
+            };
+            bot.handle_update(&update).await;
+        }
+        sent.lock()
+            .await
+            .drain(..)
+            .map(|body| body["text"].as_str().unwrap_or_default().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_moon_reply_in_user_language() {
+        let Some((url, sent)) = fake_bot_api().await else {
+            panic!("Failed to start fake Bot API");
+        };
+        let Some(bot) = bot(url, Arc::new(MockUserRepository::new()), moon_events()) else {
+            panic!("Failed to set up bot");
+        };
+        let Some(moon) = update("/moon", "ru-RU") else {
+            panic!("invalid update");
+        };
+
+        bot.handle_update(&moon).await;
+        let sent = sent.lock().await;
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0]["chat_id"], 42);
+        let text = sent[0]["text"].as_str().unwrap_or_default();
+        assert!(text.contains("Убывающая луна"), "{}", text);
+        assert!(text.contains("Последняя четверть"), "{}", text);
+    }
+
+    #[tokio::test]
+    async fn test_lang_command_saves_profile_language() {
+        let Some((url, sent)) = fake_bot_api().await else {
+            panic!("Failed to start fake Bot API");
+        };
+        let users = Arc::new(MockUserRepository::new());
+        let Some(bot) = bot(url, users.clone(), moon_events()) else {
+            panic!("Failed to set up bot");
+        };
+
+        // Without a linked account the client language is used and not saved
+        let texts = replies(&bot, &sent, &["/lang es", "/moon"], "ru").await;
+        assert!(texts[0].contains("/link"), "{:?}", texts);
+        assert!(texts[1].contains("Убывающая луна"), "{:?}", texts);
+
+        users.add_user(linked_user("UTC"));
+        let texts = replies(
+            &bot,
+            &sent,
+            &[
+                "/lang es",
+                "/moon@stars_bot",
+                "/moon@other_bot",
+                "hello",
+                "/unknown",
+            ],
+            "en",
+        )
+        .await;
+        // Plain text and commands for other bots get no reply
+        assert_eq!(texts.len(), 3, "{:?}", texts);
+        assert!(texts[0].contains("Español"));
+        assert!(texts[1].contains("Gibosa Menguante"));
+        assert!(texts[2].starts_with("Comandos"));
+
+        let Ok(Some(user)) = users.get_user_by_telegram_id(42).await else {
+            panic!("linked user missing");
+        };
+        assert_eq!(user.language, "es");
+    }
+
+    #[tokio::test]
+    async fn test_today_uses_profile_time_zone() {
+        let Some((url, sent)) = fake_bot_api().await else {
+            panic!("Failed to start fake Bot API");
+        };
+        // UTC+14: the local day differs from the UTC day for ten hours a day
+        let timezone = "Pacific/Kiritimati";
+        let local_noon =
+            notifications::delivery_time(time::OffsetDateTime::now_utc(), timezone, 12);
+        let events = MockSpiritualEventRepository::new();
+        events.add_event(event("Today", local_noon));
+        events.add_event(event("Yesterday", local_noon - time::Duration::DAY));
+        events.add_event(event("Tomorrow", local_noon + time::Duration::DAY));
+        let users = Arc::new(MockUserRepository::new());
+        users.add_user(linked_user(timezone));
+        let Some(bot) = bot(url, users, events) else {
+            panic!("Failed to set up bot");
+        };
+
+        let texts = replies(&bot, &sent, &["/today"], "en").await;
+        assert_eq!(texts.len(), 1);
+        assert!(texts[0].contains("Today"), "{}", texts[0]);
+        assert!(
+            texts[0].contains("12:00 (Pacific/Kiritimati)"),
+            "{}",
+            texts[0]
+        );
+        assert!(!texts[0].contains("Yesterday") && !texts[0].contains("Tomorrow"));
+    }
+}
@@ -0,0 +1,395 @@
+//! Server-side ephemeris snapshots for `/api/ephemeris`
+//!
+//! Telegram previews, bots and other clients without WASM (link unfurls,
+//! og:image generation) get the same numbers as the web client: snapshots
+//! are computed with `starscalendars-astro-core` at 00:00 UTC of each date.
+//! Rendered JSON bodies are kept in the `CacheService`; a date range that
+//! lies entirely in the past never changes, so it is cached for long and
+//! served as immutable.
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use starscalendars_app::{AppError, AppResult, CacheService};
+use starscalendars_astro_core::moon_phase::moon_phase;
+use starscalendars_astro_core::timescales::utc_to_tt_jd;
+use starscalendars_astro_core::{Body, apparent_position, solar_zenith_position_rad};
+use starscalendars_domain::spiritual::MoonPhase;
+use std::sync::Arc;
+use std::time::Duration;
+use time::Date;
+use time::format_description::BorrowedFormatItem;
+use time::macros::{date, format_description};
+use tracing::warn;
+
+/// Longest date range of one request, in days (inclusive)
+pub const MAX_RANGE_DAYS: i64 = 31;
+
+/// Earliest supported date: the start of the VSOP87 validity span
+pub const MIN_DATE: Date = date!(-4000 - 01 - 01);
+
+/// Latest supported date: the end of the VSOP87 validity span
+pub const MAX_DATE: Date = date!(8000 - 12 - 31);
+
+/// `Cache-Control` of ranges that ended before today (UTC)
+pub const PAST_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// `Cache-Control` of ranges reaching today or later
+pub const RECENT_CACHE_CONTROL: &str = "public, max-age=3600";
+
+/// Server-side cache lifetime of past ranges
+const PAST_CACHE_TTL: Duration = Duration::from_secs(30 * 24 * 3600);
+
+/// Server-side cache lifetime of other ranges
+const RECENT_CACHE_TTL: Duration = Duration::from_secs(3600);
+
+/// Bump when the snapshot layout or the ephemeris changes
+const CACHE_KEY_VERSION: u32 = 1;
+
+/// `YYYY-MM-DD`
+const DATE_FORMAT: &[BorrowedFormatItem<'static>] = format_description!("[year]-[month]-[day]");
+
+/// Dates and bodies of one ephemeris request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EphemerisRequest {
+    from: Date,
+    to: Date,
+    bodies: Vec<Body>,
+}
+
+impl EphemerisRequest {
+    /// Inclusive range of at most `MAX_RANGE_DAYS` days between `MIN_DATE`
+    /// and `MAX_DATE`; bodies are reported in body code order, without
+    /// duplicates
+    pub fn new(from: Date, to: Date, mut bodies: Vec<Body>) -> AppResult<Self> {
+        if to < from {
+            return Err(AppError::Validation(
+                "`to` must not be before `from`".to_string(),
+            ));
+        }
+        if from < MIN_DATE || to > MAX_DATE {
+            return Err(AppError::Validation(format!(
+                "Dates must lie between {} and {}",
+                MIN_DATE, MAX_DATE
+            )));
+        }
+        if (to - from).whole_days() >= MAX_RANGE_DAYS {
+            return Err(AppError::Validation(format!(
+                "Date range is limited to {} days",
+                MAX_RANGE_DAYS
+            )));
+        }
+        if bodies.is_empty() {
+            return Err(AppError::Validation("No bodies requested".to_string()));
+        }
+        bodies.sort_unstable();
+        bodies.dedup();
+        Ok(Self { from, to, bodies })
+    }
+
+    /// The range ended before `today`, so its snapshots are final
+    pub fn is_past(&self, today: Date) -> bool {
+        self.to < today
+    }
+
+    fn cache_key(&self) -> String {
+        let codes: Vec<String> = self
+            .bodies
+            .iter()
+            .map(|body| body.code().to_string())
+            .collect();
+        format!(
+            "ephemeris:v{}:{}:{}:{}",
+            CACHE_KEY_VERSION,
+            self.from,
+            self.to,
+            codes.join(",")
+        )
+    }
+}
+
+/// Response body: one snapshot per date of the range
+#[derive(Debug, Serialize)]
+pub struct EphemerisResponse {
+    pub from: String,
+    pub to: String,
+    pub snapshots: Vec<EphemerisSnapshot>,
+}
+
+/// Ephemeris at 00:00 UTC of a date
+#[derive(Debug, Serialize)]
+pub struct EphemerisSnapshot {
+    pub date: String,
+    pub jd_utc: f64,
+    pub jd_tt: f64,
+    /// Apparent geocentric ecliptic positions of date
+    pub bodies: Vec<BodyPosition>,
+    pub moon_phase: MoonPhase,
+    /// Point where the Sun is at the zenith
+    pub solar_zenith: ZenithPosition,
+}
+
+/// Apparent geocentric ecliptic position of a body
+#[derive(Debug, Serialize)]
+pub struct BodyPosition {
+    pub body: &'static str,
+    /// 0..360
+    pub longitude_deg: f64,
+    pub latitude_deg: f64,
+    pub distance_au: f64,
+}
+
+/// Geographic position, longitude east positive
+#[derive(Debug, Serialize)]
+pub struct ZenithPosition {
+    pub longitude_deg: f64,
+    pub latitude_deg: f64,
+}
+
+/// Rendered snapshots of a request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedEphemeris {
+    pub json: String,
+    /// Past range: cache with `PAST_CACHE_CONTROL`
+    pub immutable: bool,
+}
+
+/// Computes ephemeris snapshots and caches the rendered JSON
+pub struct EphemerisService {
+    cache: Arc<dyn CacheService>,
+}
+
+impl EphemerisService {
+    pub fn new(cache: Arc<dyn CacheService>) -> Self {
+        Self { cache }
+    }
+
+    /// JSON body of a request, from the cache when possible; `today` is the
+    /// current UTC date
+    pub async fn render(
+        &self,
+        request: &EphemerisRequest,
+        today: Date,
+    ) -> AppResult<RenderedEphemeris> {
+        let immutable = request.is_past(today);
+        let key = request.cache_key();
+        // The cache only saves work: its failures fall back to computing
+        match self.cache.get(&key).await {
+            Ok(Some(json)) => return Ok(RenderedEphemeris { json, immutable }),
+            Ok(None) => {}
+            Err(e) => warn!("Ephemeris cache read failed: {}", e),
+        }
+
+        let json = serde_json::to_string(&snapshots(request)?)
+            .map_err(|e| AppError::Internal(format!("Ephemeris serialization failed: {}", e)))?;
+        let ttl = if immutable {
+            PAST_CACHE_TTL
+        } else {
+            RECENT_CACHE_TTL
+        };
+        if let Err(e) = self.cache.set(&key, &json, ttl).await {
+            warn!("Ephemeris cache write failed: {}", e);
+        }
+        Ok(RenderedEphemeris { json, immutable })
+    }
+}
+
+/// Snapshots of every date of a request
+pub fn snapshots(request: &EphemerisRequest) -> AppResult<EphemerisResponse> {
+    let mut snapshots = Vec::new();
+    let mut date = Some(request.from);
+    while let Some(day) = date.filter(|day| *day <= request.to) {
+        snapshots.push(snapshot(day, &request.bodies)?);
+        date = day.next_day();
+    }
+    Ok(EphemerisResponse {
+        from: format_date(request.from)?,
+        to: format_date(request.to)?,
+        snapshots,
+    })
+}
+
+/// Ephemeris of `bodies` at 00:00 UTC of `date`
+pub fn snapshot(date: Date, bodies: &[Body]) -> AppResult<EphemerisSnapshot> {
+    // Julian Day Numbers count from noon
+    let jd_utc = f64::from(date.to_julian_day()) - 0.5;
+    let jd_tt = utc_to_tt_jd(jd_utc);
+    let failed = || AppError::Internal(format!("Ephemeris computation failed for {}", date));
+
+    let bodies = bodies
+        .iter()
+        .map(|&body| {
+            let position = apparent_position(body, jd_tt).ok_or_else(failed)?;
+            Ok(BodyPosition {
+                body: body.name(),
+                longitude_deg: position.longitude_rad.to_degrees(),
+                latitude_deg: position.latitude_rad.to_degrees(),
+                distance_au: position.distance_au,
+            })
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+    let (zenith_lon_rad, zenith_lat_rad) = solar_zenith_position_rad(jd_utc);
+
+    Ok(EphemerisSnapshot {
+        date: format_date(date)?,
+        jd_utc,
+        jd_tt,
+        bodies,
+        moon_phase: moon_phase(jd_utc).ok_or_else(failed)?,
+        solar_zenith: ZenithPosition {
+            longitude_deg: zenith_lon_rad.to_degrees(),
+            latitude_deg: zenith_lat_rad.to_degrees(),
+        },
+    })
+}
+
+/// Parse a `YYYY-MM-DD` date
+pub fn parse_date(value: &str) -> AppResult<Date> {
+    Date::parse(value, DATE_FORMAT)
+        .map_err(|_| AppError::Validation(format!("Invalid date `{}`, expected YYYY-MM-DD", value)))
+}
+
+/// Parse a comma-separated body list (`sun,moon,mars`); all bodies when absent
+pub fn parse_bodies(value: Option<&str>) -> AppResult<Vec<Body>> {
+    let Some(value) = value else {
+        return Ok(Body::ALL.to_vec());
+    };
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            Body::from_name(name)
+                .ok_or_else(|| AppError::Validation(format!("Unknown body `{}`", name)))
+        })
+        .collect()
+}
+
+/// Strong ETag of a response body
+pub fn etag(body: &[u8]) -> String {
+    let digest = Sha256::digest(body);
+    format!("\"{}\"", hex::encode(digest.get(..16).unwrap_or_default()))
+}
+
+/// Re-encode a rendered JSON body as CBOR
+pub fn json_to_cbor(json: &str) -> AppResult<Vec<u8>> {
+    let value: serde_json::Value = serde_json::from_str(json)
+        .map_err(|e| AppError::Internal(format!("Cached ephemeris is invalid: {}", e)))?;
+    let mut cbor = Vec::with_capacity(json.len());
+    ciborium::into_writer(&value, &mut cbor)
+        .map_err(|e| AppError::Internal(format!("CBOR encoding failed: {}", e)))?;
+    Ok(cbor)
+}
+
+fn format_date(date: Date) -> AppResult<String> {
+    date.format(DATE_FORMAT)
+        .map_err(|e| AppError::Internal(format!("Date formatting failed: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starscalendars_infra::InMemoryCacheService;
+
+    fn request(from: Date, to: Date, bodies: &[Body]) -> Option<EphemerisRequest> {
+        EphemerisRequest::new(from, to, bodies.to_vec()).ok()
+    }
+
+    #[test]
+    fn test_request_validation() {
+        assert!(parse_date("2025-03-20").is_ok_and(|d| d == date!(2025 - 03 - 20)));
+        assert!(matches!(
+            parse_date("20.03.2025"),
+            Err(AppError::Validation(_))
+        ));
+
+        assert!(matches!(parse_bodies(None), Ok(bodies) if bodies.len() == Body::ALL.len()));
+        assert!(matches!(
+            parse_bodies(Some("Moon, sun,moon")).ok().and_then(|b| request(date!(2025 - 01 - 01), date!(2025 - 01 - 01), &b)),
+            Some(r) if r.bodies == [Body::Sun, Body::Moon]
+        ));
+        assert!(matches!(
+            parse_bodies(Some("sun,earth")),
+            Err(AppError::Validation(_))
+        ));
+
+        let day = date!(2025 - 01 - 01);
+        assert!(request(day, date!(2025 - 01 - 31), &[Body::Sun]).is_some());
+        assert!(request(day, date!(2025 - 02 - 01), &[Body::Sun]).is_none());
+        assert!(request(day, date!(2024 - 12 - 31), &[Body::Sun]).is_none());
+        assert!(request(day, day, &[]).is_none());
+
+        // Both ends of the supported range compute; before JD 0 the
+        // ephemeris fails, so such dates are rejected up front
+        assert!(request(MIN_DATE, MIN_DATE, &[Body::Sun]).is_some());
+        assert!(request(MAX_DATE, MAX_DATE, &[Body::Sun]).is_some());
+        assert!(snapshot(MIN_DATE, &Body::ALL).is_ok());
+        assert!(snapshot(MAX_DATE, &Body::ALL).is_ok());
+        assert!(matches!(
+            EphemerisRequest::new(
+                date!(-4713 - 06 - 01),
+                date!(-4713 - 06 - 01),
+                vec![Body::Moon]
+            ),
+            Err(AppError::Validation(_))
+        ));
+        assert!(matches!(
+            EphemerisRequest::new(
+                date!(9000 - 01 - 01),
+                date!(9000 - 01 - 01),
+                vec![Body::Moon]
+            ),
+            Err(AppError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_snapshot_matches_core() {
+        let day = date!(2025 - 01 - 01);
+        let snapshot = snapshot(day, &[Body::Sun, Body::Venus]);
+        assert!(snapshot.is_ok());
+        let Ok(snapshot) = snapshot else { return };
+        assert_eq!(snapshot.date, "2025-01-01");
+        assert_eq!(snapshot.jd_utc, 2460676.5);
+        assert_eq!(snapshot.jd_tt, utc_to_tt_jd(2460676.5));
+
+        let venus = apparent_position(Body::Venus, snapshot.jd_tt);
+        assert!(matches!(
+            (snapshot.bodies.get(1), venus),
+            (Some(p), Some(v)) if p.body == "venus" && p.longitude_deg == v.longitude_rad.to_degrees()
+        ));
+        // The Sun is in Capricorn, about 280.8°, and at the zenith near 23° S
+        assert!(
+            matches!(snapshot.bodies.first(), Some(p) if (p.longitude_deg - 280.8).abs() < 0.1
+            && p.latitude_deg.abs() < 1.0e-3)
+        );
+        assert!((snapshot.solar_zenith.latitude_deg + 23.0).abs() < 0.2);
+        // A day after the New Moon of 2024-12-30
+        assert!(snapshot.moon_phase.waxing);
+        assert!((snapshot.moon_phase.age_days - 1.06).abs() < 0.05);
+    }
+
+    #[tokio::test]
+    async fn test_render_uses_cache() {
+        let cache = Arc::new(InMemoryCacheService::new());
+        let service = EphemerisService::new(cache.clone());
+        let request = request(date!(2025 - 01 - 01), date!(2025 - 01 - 03), &[Body::Moon]);
+        assert!(request.is_some());
+        let Some(request) = request else { return };
+
+        let first = service.render(&request, date!(2025 - 06 - 01)).await;
+        assert!(first.is_ok());
+        let Ok(first) = first else { return };
+        assert!(first.immutable);
+        assert!(first.json.contains("\"date\":\"2025-01-03\""));
+        assert_eq!(
+            cache.get(&request.cache_key()).await.ok().flatten(),
+            Some(first.json.clone())
+        );
+
+        // Served from the cache; the range is still open on its last day
+        let cached = service.render(&request, date!(2025 - 01 - 03)).await;
+        assert!(matches!(cached, Ok(ref r) if !r.immutable && r.json == first.json));
+        assert_eq!(etag(first.json.as_bytes()), etag(first.json.as_bytes()));
+        assert!(matches!(json_to_cbor(&first.json), Ok(cbor) if !cbor.is_empty()));
+    }
+}
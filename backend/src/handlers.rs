@@ -1,5 +1,34 @@
 //! HTTP request handlers
 
+use crate::auth::AuthUser;
+use crate::bot::TelegramBot;
+use crate::ephemeris::{self, EphemerisRequest, EphemerisService};
+use crate::notifications::{from_julian_day, julian_day};
+use crate::problem::Problem;
+use crate::telegram_link::TelegramLinker;
+use axum::{
+    extract::{FromRef, Path, Query, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Json, Response},
+};
+use serde::{Deserialize, Serialize};
+use starscalendars_app::{AppError, AuthUseCase, JwtService, UserUseCase};
+use starscalendars_astro_core::returns::{self, ReturnBody};
+use starscalendars_astro_core::zodiac::Zodiac;
+use starscalendars_domain::auth::JwtTokenPair;
+use starscalendars_domain::{DomainError, ProfilePatch, TelegramAuthPayload, User};
+use starscalendars_infra::BotUpdate;
+use std::sync::Arc;
+
+/// Name of the refresh token cookie
+pub const REFRESH_TOKEN_COOKIE: &str = "refresh_token";
+
+/// Media type of CBOR responses
+pub const CBOR_CONTENT_TYPE: &str = "application/cbor";
+
+/// Header carrying the `secret_token` given to `setWebhook`
+pub const TELEGRAM_SECRET_HEADER: &str = "x-telegram-bot-api-secret-token";
+
 #[allow(dead_code)]
 pub struct AuthHandlers;
 
@@ -8,3 +37,501 @@ pub struct ApiHandlers;
 
 #[allow(dead_code)]
 pub struct UserHandlers;
+
+/// Error returned by API handlers, rendered as problem details
+pub struct ApiError(AppError);
+
+impl From<AppError> for ApiError {
+    fn from(err: AppError) -> Self {
+        Self(err)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        Problem::from(self.0).into_response()
+    }
+}
+
+/// State of the authentication routes
+#[derive(Clone, FromRef)]
+pub struct AuthState {
+    pub auth: Arc<dyn AuthUseCase>,
+    /// Deliver refresh tokens in a `Secure; HttpOnly` cookie instead of the body
+    #[from_ref(skip)]
+    pub refresh_cookie: bool,
+    /// `Max-Age` of the refresh token cookie, in seconds
+    #[from_ref(skip)]
+    pub refresh_token_ttl: u64,
+    pub jwt_service: Arc<dyn JwtService>,
+    /// Bot username for linking deep links
+    #[from_ref(skip)]
+    pub bot_username: String,
+}
+
+/// State of the Telegram bot routes
+#[derive(Clone)]
+pub struct TelegramState {
+    pub linker: Arc<TelegramLinker>,
+    pub bot: Arc<TelegramBot>,
+    /// Expected `X-Telegram-Bot-Api-Secret-Token`; `None` disables the routes
+    pub webhook_secret: Option<String>,
+}
+
+/// State of the profile routes
+#[derive(Clone, FromRef)]
+pub struct ProfileState {
+    pub users: Arc<dyn UserUseCase>,
+    pub jwt_service: Arc<dyn JwtService>,
+}
+
+/// Body of `POST /auth/refresh`; may be omitted in cookie mode
+#[derive(Debug, Default, Deserialize)]
+pub struct RefreshRequest {
+    pub refresh_token: Option<String>,
+}
+
+/// `POST /auth/telegram`: exchange a Login Widget or WebApp payload for tokens
+pub async fn telegram_auth(
+    State(state): State<AuthState>,
+    Json(payload): Json<TelegramAuthPayload>,
+) -> Result<Response, ApiError> {
+    let tokens = state.auth.authenticate_with_telegram(&payload).await?;
+    Ok(token_response(&state, tokens))
+}
+
+/// `POST /auth/refresh`: rotate a refresh token taken from the body or cookie
+pub async fn refresh_tokens(
+    State(state): State<AuthState>,
+    headers: HeaderMap,
+    body: Option<Json<RefreshRequest>>,
+) -> Result<Response, ApiError> {
+    let refresh_token = body
+        .and_then(|Json(request)| request.refresh_token)
+        .or_else(|| refresh_token_cookie(&headers))
+        .ok_or(AppError::Domain(DomainError::InvalidRefreshToken))?;
+
+    let tokens = state.auth.refresh_tokens(&refresh_token).await?;
+    Ok(token_response(&state, tokens))
+}
+
+/// Response of `POST /auth/link`
+#[derive(Debug, Serialize)]
+pub struct LinkResponse {
+    pub token: uuid::Uuid,
+    /// `https://t.me/<bot>?start=<token>`, to open in Telegram
+    pub deep_link: String,
+    /// Seconds until the token expires
+    pub expires_in: u64,
+}
+
+/// Body of `POST /api/telegram/confirm-link`
+#[derive(Debug, Deserialize)]
+pub struct ConfirmLinkRequest {
+    pub token: uuid::Uuid,
+    pub telegram_user_id: i64,
+}
+
+/// `POST /auth/link`: issue a linking token for the signed-in user
+pub async fn create_link(
+    State(state): State<AuthState>,
+    AuthUser(claims): AuthUser,
+) -> Result<Json<LinkResponse>, ApiError> {
+    let token = state.auth.create_linking_token(&claims.user_id).await?;
+
+    let remaining = token.expires_at - time::OffsetDateTime::now_utc();
+    Ok(Json(LinkResponse {
+        token: token.token,
+        deep_link: token.deep_link(&state.bot_username),
+        expires_in: u64::try_from(remaining.whole_seconds()).unwrap_or(0),
+    }))
+}
+
+/// `POST /api/telegram/confirm-link`: link reported by an external bot process
+pub async fn confirm_link(
+    State(state): State<TelegramState>,
+    headers: HeaderMap,
+    Json(request): Json<ConfirmLinkRequest>,
+) -> Result<StatusCode, ApiError> {
+    check_bot_secret(&state, &headers)?;
+    state
+        .linker
+        .confirm(&request.token, request.telegram_user_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `POST /api/telegram/webhook`: updates pushed by Telegram
+pub async fn telegram_webhook(
+    State(state): State<TelegramState>,
+    headers: HeaderMap,
+    Json(update): Json<BotUpdate>,
+) -> Result<StatusCode, ApiError> {
+    check_bot_secret(&state, &headers)?;
+    state.bot.handle_update(&update).await;
+    // Telegram redelivers anything but 2xx, so failures are answered in the chat
+    Ok(StatusCode::OK)
+}
+
+/// Profile of the signed-in user
+#[derive(Debug, Serialize)]
+pub struct ProfileResponse {
+    pub id: uuid::Uuid,
+    pub username: String,
+    pub email: Option<String>,
+    pub telegram_user_id: Option<i64>,
+    pub language: String,
+    pub timezone: String,
+    pub spiritual_preferences: serde_json::Value,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: time::OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: time::OffsetDateTime,
+}
+
+impl From<User> for ProfileResponse {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id.as_uuid(),
+            username: user.username,
+            email: user.email,
+            telegram_user_id: user.telegram_user_id.map(|id| id.value()),
+            language: user.language,
+            timezone: user.timezone,
+            spiritual_preferences: user.spiritual_preferences,
+            created_at: user.created_at,
+            updated_at: user.updated_at,
+        }
+    }
+}
+
+/// `GET /api/user/profile`
+pub async fn get_profile(
+    State(state): State<ProfileState>,
+    user: AuthUser,
+) -> Result<Json<ProfileResponse>, ApiError> {
+    let profile = state
+        .users
+        .get_user_profile(&user.user_id())
+        .await?
+        .ok_or_else(|| AppError::Domain(DomainError::UserNotFound(user.0.sub.clone())))?;
+    Ok(Json(profile.into()))
+}
+
+/// `PATCH /api/user/profile`: JSON merge patch (RFC 7396) of the editable fields
+pub async fn patch_profile(
+    State(state): State<ProfileState>,
+    user: AuthUser,
+    Json(document): Json<serde_json::Value>,
+) -> Result<Json<ProfileResponse>, ApiError> {
+    let patch = ProfilePatch::from_json(&document).map_err(AppError::Domain)?;
+    let profile = state
+        .users
+        .patch_user_profile(&user.user_id(), &patch)
+        .await?;
+    Ok(Json(profile.into()))
+}
+
+/// `DELETE /api/user/profile`: delete the account and everything linked to it
+pub async fn delete_profile(
+    State(state): State<ProfileState>,
+    user: AuthUser,
+) -> Result<StatusCode, ApiError> {
+    state.users.delete_user_account(&user.user_id()).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Query of the ephemeris routes
+#[derive(Debug, Default, Deserialize)]
+pub struct EphemerisQuery {
+    /// Single date, `YYYY-MM-DD`; today (UTC) when no date is given
+    pub date: Option<String>,
+    /// First date of a range
+    pub from: Option<String>,
+    /// Last date of a range, inclusive; defaults to `from`
+    pub to: Option<String>,
+    /// Comma-separated body names; all bodies when absent
+    pub bodies: Option<String>,
+    /// `json` or `cbor`; otherwise chosen by `Accept`
+    pub format: Option<String>,
+}
+
+/// `GET /api/ephemeris`: snapshots for a date or a date range
+pub async fn ephemeris(
+    State(service): State<Arc<EphemerisService>>,
+    headers: HeaderMap,
+    Query(query): Query<EphemerisQuery>,
+) -> Result<Response, ApiError> {
+    let today = time::OffsetDateTime::now_utc().date();
+    let from = match query.from.as_deref().or(query.date.as_deref()) {
+        Some(value) => ephemeris::parse_date(value)?,
+        None => today,
+    };
+    let to = match query.to.as_deref().or(query.date.as_deref()) {
+        Some(value) => ephemeris::parse_date(value)?,
+        None => from,
+    };
+    let request =
+        EphemerisRequest::new(from, to, ephemeris::parse_bodies(query.bodies.as_deref())?)?;
+    ephemeris_response(&service, &request, today, &query, &headers).await
+}
+
+/// `GET /api/ephemeris/{date}`: snapshot of one date
+pub async fn ephemeris_for_date(
+    State(service): State<Arc<EphemerisService>>,
+    Path(date): Path<String>,
+    headers: HeaderMap,
+    Query(query): Query<EphemerisQuery>,
+) -> Result<Response, ApiError> {
+    let date = ephemeris::parse_date(&date)?;
+    let request = EphemerisRequest::new(
+        date,
+        date,
+        ephemeris::parse_bodies(query.bodies.as_deref())?,
+    )?;
+    let today = time::OffsetDateTime::now_utc().date();
+    ephemeris_response(&service, &request, today, &query, &headers).await
+}
+
+/// Render as JSON or CBOR with validators; `304` when `If-None-Match` matches
+async fn ephemeris_response(
+    service: &EphemerisService,
+    request: &EphemerisRequest,
+    today: time::Date,
+    query: &EphemerisQuery,
+    headers: &HeaderMap,
+) -> Result<Response, ApiError> {
+    let cbor = match query.format.as_deref() {
+        Some("cbor") => true,
+        Some("json") => false,
+        Some(other) => {
+            return Err(AppError::Validation(format!("Unknown format `{}`", other)).into());
+        }
+        None => headers
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|accept| accept.contains(CBOR_CONTENT_TYPE)),
+    };
+
+    let rendered = service.render(request, today).await?;
+    let (content_type, body) = if cbor {
+        (CBOR_CONTENT_TYPE, ephemeris::json_to_cbor(&rendered.json)?)
+    } else {
+        ("application/json", rendered.json.into_bytes())
+    };
+
+    let etag = ephemeris::etag(&body);
+    let cache_control = if rendered.immutable {
+        ephemeris::PAST_CACHE_CONTROL
+    } else {
+        ephemeris::RECENT_CACHE_CONTROL
+    };
+    let validators = [
+        (header::ETAG, etag.clone()),
+        (header::CACHE_CONTROL, cache_control.to_string()),
+        (header::VARY, header::ACCEPT.to_string()),
+    ];
+
+    if if_none_match(headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, validators).into_response());
+    }
+    Ok((validators, [(header::CONTENT_TYPE, content_type)], body).into_response())
+}
+
+/// `If-None-Match` lists `etag` or `*` (weak comparison)
+fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim())
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+/// Query of `GET /api/returns/{body}`
+#[derive(Debug, Deserialize)]
+pub struct ReturnQuery {
+    /// Birth instant, RFC 3339
+    #[serde(with = "time::serde::rfc3339")]
+    pub natal: time::OffsetDateTime,
+    /// First date searched, `YYYY-MM-DD`; today (UTC) when absent
+    pub after: Option<String>,
+}
+
+/// Response of `GET /api/returns/{body}`
+#[derive(Debug, Serialize)]
+pub struct ReturnResponse {
+    /// `sun` or `moon`
+    pub body: &'static str,
+    #[serde(with = "time::serde::rfc3339")]
+    pub occurs_at: time::OffsetDateTime,
+    pub jd_utc: f64,
+}
+
+/// `GET /api/returns/{body}`: next tropical solar or lunar return, for
+/// premium users
+pub async fn next_return(
+    Path(body): Path<String>,
+    Query(query): Query<ReturnQuery>,
+) -> Result<Json<ReturnResponse>, ApiError> {
+    let (body, name) = match body.as_str() {
+        "sun" => (ReturnBody::Sun, "sun"),
+        "moon" => (ReturnBody::Moon, "moon"),
+        other => {
+            return Err(AppError::Validation(format!("Unknown return body `{}`", other)).into());
+        }
+    };
+    let after = match query.after.as_deref() {
+        Some(value) => ephemeris::parse_date(value)?,
+        None => time::OffsetDateTime::now_utc().date(),
+    };
+    let natal = query.natal.date();
+    let supported = ephemeris::MIN_DATE..=ephemeris::MAX_DATE;
+    if !supported.contains(&natal) || !supported.contains(&after) {
+        return Err(AppError::Validation(format!(
+            "Dates must lie between {} and {}",
+            ephemeris::MIN_DATE,
+            ephemeris::MAX_DATE
+        ))
+        .into());
+    }
+
+    let start = after.midnight().assume_utc();
+    let failed = || AppError::Internal(format!("Return computation failed after {}", after));
+    let jd_utc = returns::next_return(
+        body,
+        julian_day(query.natal),
+        julian_day(start),
+        Zodiac::Tropical,
+    )
+    .ok_or_else(failed)?;
+    Ok(Json(ReturnResponse {
+        body: name,
+        occurs_at: from_julian_day(jd_utc).ok_or_else(failed)?,
+        jd_utc,
+    }))
+}
+
+/// Constant-time check of the Bot API secret token header
+fn check_bot_secret(state: &TelegramState, headers: &HeaderMap) -> Result<(), ApiError> {
+    let expected = state
+        .webhook_secret
+        .as_deref()
+        .ok_or_else(|| AppError::Authorization("Telegram webhook disabled".to_string()))?;
+    let received = headers
+        .get(TELEGRAM_SECRET_HEADER)
+        .map(|value| value.as_bytes())
+        .unwrap_or_default();
+
+    let matches = received.len() == expected.len()
+        && received
+            .iter()
+            .zip(expected.as_bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0;
+    if matches {
+        Ok(())
+    } else {
+        Err(AppError::Authorization("Invalid Telegram secret token".to_string()).into())
+    }
+}
+
+/// Token pair response; in cookie mode the refresh token moves to `Set-Cookie`
+fn token_response(state: &AuthState, mut tokens: JwtTokenPair) -> Response {
+    if !state.refresh_cookie {
+        return Json(tokens).into_response();
+    }
+
+    let cookie = format!(
+        "{}={}; HttpOnly; Secure; SameSite=Strict; Path=/auth; Max-Age={}",
+        REFRESH_TOKEN_COOKIE, tokens.refresh_token, state.refresh_token_ttl
+    );
+    tokens.refresh_token = String::new();
+    ([(header::SET_COOKIE, cookie)], Json(tokens)).into_response()
+}
+
+/// Value of the refresh token cookie, if sent
+fn refresh_token_cookie(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == REFRESH_TOKEN_COOKIE)
+        .map(|(_, value)| value.to_string())
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes;
+    use axum_test::TestServer;
+    use serde_json::Value;
+    use starscalendars_domain::JwtClaims;
+    use starscalendars_domain::UserId;
+    use starscalendars_infra::MockJwtService;
+
+    fn premium_server(roles: &[&str]) -> Option<TestServer> {
+        let roles: Vec<String> = roles.iter().map(|role| role.to_string()).collect();
+        let mut jwt_service = MockJwtService::new();
+        jwt_service.set_mock_claims(JwtClaims::new(&UserId::new(), None, false, &roles));
+        TestServer::new(routes::premium_routes(Arc::new(jwt_service))).ok()
+    }
+
+    #[tokio::test]
+    async fn test_next_return_requires_premium() {
+        let Some(app) = premium_server(&[]) else {
+            panic!("Failed to build test server");
+        };
+        let response = app
+            .get("/api/returns/sun")
+            .add_query_param("natal", "1990-06-15T12:00:00Z")
+            .add_header(header::AUTHORIZATION, "Bearer token")
+            .await;
+        response.assert_status(StatusCode::FORBIDDEN);
+        assert_eq!(response.json::<Value>()["required_role"], "premium");
+    }
+
+    #[tokio::test]
+    async fn test_next_return() {
+        let Some(app) = premium_server(&["premium"]) else {
+            panic!("Failed to build test server");
+        };
+        let response = app
+            .get("/api/returns/sun")
+            .add_query_param("natal", "1990-06-15T12:00:00Z")
+            .add_query_param("after", "2025-01-01")
+            .add_header(header::AUTHORIZATION, "Bearer token")
+            .await;
+        response.assert_status_ok();
+        let body: Value = response.json();
+        assert_eq!(body["body"], "sun");
+        // Birthday 2025-06-15, within the quarter-day drift of the calendar
+        assert!(
+            body["jd_utc"]
+                .as_f64()
+                .is_some_and(|jd| (jd - 2460842.0).abs() < 1.0),
+            "{}",
+            body
+        );
+
+        let response = app
+            .get("/api/returns/mars")
+            .add_query_param("natal", "1990-06-15T12:00:00Z")
+            .add_header(header::AUTHORIZATION, "Bearer token")
+            .await;
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.json::<Value>()["code"], "validation_failed");
+
+        let response = app
+            .get("/api/returns/moon")
+            .add_query_param("natal", "1990-06-15T12:00:00Z")
+            .add_query_param("after", "9000-01-01")
+            .add_header(header::AUTHORIZATION, "Bearer token")
+            .await;
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
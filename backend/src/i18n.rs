@@ -0,0 +1,257 @@
+//! Languages shared by the HTTP API and the bot
+//!
+//! The language list matches the dioxus-app i18n layer. API problem
+//! responses carry their title in the language negotiated from
+//! `Accept-Language`; the bot keeps its reply catalog in `bot::i18n`.
+
+/// Language used when the user's one is not supported
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// Supported languages: code and native name
+pub const LANGUAGES: [(&str, &str); 6] = [
+    ("en", "English"),
+    ("ru", "Русский"),
+    ("es", "Español"),
+    ("zh", "中文"),
+    ("hi", "हिन्दी"),
+    ("hy", "Հայերեն"),
+];
+
+/// Titles of API problem responses in one language, one per error code
+pub struct ErrorTitles {
+    pub bad_request: &'static str,
+    pub validation_failed: &'static str,
+    pub unauthorized: &'static str,
+    pub missing_token: &'static str,
+    pub invalid_token: &'static str,
+    pub token_expired: &'static str,
+    pub invalid_refresh_token: &'static str,
+    pub invalid_telegram_auth: &'static str,
+    pub invalid_linking_token: &'static str,
+    pub forbidden: &'static str,
+    pub insufficient_role: &'static str,
+    pub subscription_required: &'static str,
+    pub not_found: &'static str,
+    pub user_not_found: &'static str,
+    pub method_not_allowed: &'static str,
+    pub conflict: &'static str,
+    pub username_taken: &'static str,
+    pub email_taken: &'static str,
+    pub telegram_already_linked: &'static str,
+    pub payload_too_large: &'static str,
+    pub unsupported_media_type: &'static str,
+    pub too_many_requests: &'static str,
+    pub internal_error: &'static str,
+    pub service_unavailable: &'static str,
+}
+
+const EN_ERRORS: ErrorTitles = ErrorTitles {
+    bad_request: "Bad request",
+    validation_failed: "Invalid input",
+    unauthorized: "Authentication required",
+    missing_token: "Sign-in required",
+    invalid_token: "Invalid access token",
+    token_expired: "Access token expired",
+    invalid_refresh_token: "Invalid refresh token",
+    invalid_telegram_auth: "Telegram sign-in failed",
+    invalid_linking_token: "Invalid or expired link",
+    forbidden: "Access denied",
+    insufficient_role: "Insufficient permissions",
+    subscription_required: "Subscription required",
+    not_found: "Not found",
+    user_not_found: "User not found",
+    method_not_allowed: "Method not allowed",
+    conflict: "Conflict",
+    username_taken: "Username already taken",
+    email_taken: "Email already in use",
+    telegram_already_linked: "Telegram account already linked",
+    payload_too_large: "Request too large",
+    unsupported_media_type: "Unsupported media type",
+    too_many_requests: "Too many requests",
+    internal_error: "Internal server error",
+    service_unavailable: "Service unavailable",
+};
+
+const RU_ERRORS: ErrorTitles = ErrorTitles {
+    bad_request: "Некорректный запрос",
+    validation_failed: "Некорректные данные",
+    unauthorized: "Требуется аутентификация",
+    missing_token: "Требуется вход",
+    invalid_token: "Недействительный токен доступа",
+    token_expired: "Срок действия токена доступа истёк",
+    invalid_refresh_token: "Недействительный токен обновления",
+    invalid_telegram_auth: "Не удалось войти через Telegram",
+    invalid_linking_token: "Ссылка недействительна или устарела",
+    forbidden: "Доступ запрещён",
+    insufficient_role: "Недостаточно прав",
+    subscription_required: "Требуется подписка",
+    not_found: "Не найдено",
+    user_not_found: "Пользователь не найден",
+    method_not_allowed: "Метод не поддерживается",
+    conflict: "Конфликт",
+    username_taken: "Имя пользователя уже занято",
+    email_taken: "Email уже используется",
+    telegram_already_linked: "Аккаунт Telegram уже привязан",
+    payload_too_large: "Слишком большой запрос",
+    unsupported_media_type: "Неподдерживаемый тип данных",
+    too_many_requests: "Слишком много запросов",
+    internal_error: "Внутренняя ошибка сервера",
+    service_unavailable: "Сервис недоступен",
+};
+
+const ES_ERRORS: ErrorTitles = ErrorTitles {
+    bad_request: "Solicitud incorrecta",
+    validation_failed: "Datos no válidos",
+    unauthorized: "Autenticación requerida",
+    missing_token: "Inicio de sesión requerido",
+    invalid_token: "Token de acceso no válido",
+    token_expired: "Token de acceso caducado",
+    invalid_refresh_token: "Token de actualización no válido",
+    invalid_telegram_auth: "Error al iniciar sesión con Telegram",
+    invalid_linking_token: "Enlace no válido o caducado",
+    forbidden: "Acceso denegado",
+    insufficient_role: "Permisos insuficientes",
+    subscription_required: "Suscripción requerida",
+    not_found: "No encontrado",
+    user_not_found: "Usuario no encontrado",
+    method_not_allowed: "Método no permitido",
+    conflict: "Conflicto",
+    username_taken: "Nombre de usuario ya en uso",
+    email_taken: "Correo electrónico ya en uso",
+    telegram_already_linked: "Cuenta de Telegram ya vinculada",
+    payload_too_large: "Solicitud demasiado grande",
+    unsupported_media_type: "Tipo de contenido no admitido",
+    too_many_requests: "Demasiadas solicitudes",
+    internal_error: "Error interno del servidor",
+    service_unavailable: "Servicio no disponible",
+};
+
+const ZH_ERRORS: ErrorTitles = ErrorTitles {
+    bad_request: "请求无效",
+    validation_failed: "输入无效",
+    unauthorized: "需要身份验证",
+    missing_token: "需要登录",
+    invalid_token: "访问令牌无效",
+    token_expired: "访问令牌已过期",
+    invalid_refresh_token: "刷新令牌无效",
+    invalid_telegram_auth: "Telegram 登录失败",
+    invalid_linking_token: "链接无效或已过期",
+    forbidden: "拒绝访问",
+    insufficient_role: "权限不足",
+    subscription_required: "需要订阅",
+    not_found: "未找到",
+    user_not_found: "未找到用户",
+    method_not_allowed: "不支持的请求方法",
+    conflict: "冲突",
+    username_taken: "用户名已被占用",
+    email_taken: "邮箱已被使用",
+    telegram_already_linked: "Telegram 账户已绑定",
+    payload_too_large: "请求过大",
+    unsupported_media_type: "不支持的媒体类型",
+    too_many_requests: "请求过多",
+    internal_error: "服务器内部错误",
+    service_unavailable: "服务不可用",
+};
+
+const HI_ERRORS: ErrorTitles = ErrorTitles {
+    bad_request: "अमान्य अनुरोध",
+    validation_failed: "अमान्य इनपुट",
+    unauthorized: "प्रमाणीकरण आवश्यक है",
+    missing_token: "साइन इन आवश्यक है",
+    invalid_token: "अमान्य एक्सेस टोकन",
+    token_expired: "एक्सेस टोकन की अवधि समाप्त",
+    invalid_refresh_token: "अमान्य रीफ़्रेश टोकन",
+    invalid_telegram_auth: "Telegram साइन-इन विफल",
+    invalid_linking_token: "लिंक अमान्य या समाप्त",
+    forbidden: "पहुँच अस्वीकृत",
+    insufficient_role: "अपर्याप्त अनुमतियाँ",
+    subscription_required: "सदस्यता आवश्यक है",
+    not_found: "नहीं मिला",
+    user_not_found: "उपयोगकर्ता नहीं मिला",
+    method_not_allowed: "विधि की अनुमति नहीं है",
+    conflict: "टकराव",
+    username_taken: "उपयोगकर्ता नाम पहले से लिया गया है",
+    email_taken: "ईमेल पहले से उपयोग में है",
+    telegram_already_linked: "Telegram खाता पहले से जुड़ा है",
+    payload_too_large: "अनुरोध बहुत बड़ा है",
+    unsupported_media_type: "असमर्थित मीडिया प्रकार",
+    too_many_requests: "बहुत अधिक अनुरोध",
+    internal_error: "आंतरिक सर्वर त्रुटि",
+    service_unavailable: "सेवा उपलब्ध नहीं है",
+};
+
+const HY_ERRORS: ErrorTitles = ErrorTitles {
+    bad_request: "Սխալ հարցում",
+    validation_failed: "Անվավեր տվյալներ",
+    unauthorized: "Պահանջվում է նույնականացում",
+    missing_token: "Պահանջվում է մուտք",
+    invalid_token: "Անվավեր մուտքի թոքեն",
+    token_expired: "Մուտքի թոքենի ժամկետն անցել է",
+    invalid_refresh_token: "Անվավեր թարմացման թոքեն",
+    invalid_telegram_auth: "Telegram-ով մուտքը ձախողվեց",
+    invalid_linking_token: "Հղումն անվավեր է կամ ժամկետանց",
+    forbidden: "Մուտքն արգելված է",
+    insufficient_role: "Անբավարար իրավունքներ",
+    subscription_required: "Պահանջվում է բաժանորդագրություն",
+    not_found: "Չի գտնվել",
+    user_not_found: "Օգտատերը չի գտնվել",
+    method_not_allowed: "Մեթոդը թույլատրված չէ",
+    conflict: "Հակասություն",
+    username_taken: "Օգտանունն արդեն զբաղված է",
+    email_taken: "Էլ. փոստն արդեն օգտագործվում է",
+    telegram_already_linked: "Telegram հաշիվն արդեն կապված է",
+    payload_too_large: "Հարցումը չափազանց մեծ է",
+    unsupported_media_type: "Չաջակցվող տվյալների տեսակ",
+    too_many_requests: "Չափազանց շատ հարցումներ",
+    internal_error: "Սերվերի ներքին սխալ",
+    service_unavailable: "Ծառայությունն անհասանելի է",
+};
+
+/// Supported language for a Telegram `language_code` ("ru", "pt-BR", ...)
+pub fn supported_language(code: &str) -> Option<&'static str> {
+    let primary = code.split(['-', '_']).next()?.to_ascii_lowercase();
+    LANGUAGES
+        .iter()
+        .map(|(code, _)| *code)
+        .find(|code| *code == primary)
+}
+
+/// Native name of a supported language
+pub fn native_name(language: &str) -> &'static str {
+    LANGUAGES
+        .iter()
+        .find(|(code, _)| *code == language)
+        .map_or("English", |(_, name)| *name)
+}
+
+/// API error titles of a supported language, English otherwise
+pub fn error_titles(language: &str) -> &'static ErrorTitles {
+    match language {
+        "ru" => &RU_ERRORS,
+        "es" => &ES_ERRORS,
+        "zh" => &ZH_ERRORS,
+        "hi" => &HI_ERRORS,
+        "hy" => &HY_ERRORS,
+        _ => &EN_ERRORS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_resolution() {
+        assert_eq!(supported_language("ru"), Some("ru"));
+        assert_eq!(supported_language("es-MX"), Some("es"));
+        assert_eq!(supported_language("ZH_hans"), Some("zh"));
+        assert_eq!(supported_language("pt-BR"), None);
+        assert_eq!(native_name("hy"), "Հայերեն");
+    }
+
+    #[test]
+    fn test_error_titles() {
+        assert_eq!(error_titles("ru").not_found, "Не найдено");
+        assert_eq!(error_titles("pt").not_found, EN_ERRORS.not_found);
+    }
+}
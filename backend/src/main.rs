@@ -11,14 +11,26 @@ use tower_http::cors::CorsLayer;
 use tracing::{error, info};
 use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
 
+mod auth;
+mod bot;
+mod ephemeris;
 mod handlers;
+mod i18n;
 mod middleware;
+mod notifications;
+mod problem;
 mod routes;
+mod telegram_link;
 mod websocket;
 
-use crate::websocket::{WebSocketManager, websocket_handler};
-use starscalendars_app::{AppServices, ServiceHealthReport};
-use starscalendars_infra::AppConfig;
+use crate::bot::TelegramBot;
+use crate::ephemeris::EphemerisService;
+use crate::handlers::{AuthState, ProfileState, TelegramState};
+use crate::notifications::NotificationScheduler;
+use crate::telegram_link::TelegramLinker;
+use crate::websocket::WebSocketManager;
+use starscalendars_app::{AppServices, AuthUseCaseImpl, UserUseCaseImpl};
+use starscalendars_infra::{AppConfig, TelegramApiService, TelegramAuthVerifierImpl};
 use std::sync::Arc;
 
 #[tokio::main]
@@ -66,16 +78,28 @@ async fn main() -> Result<()> {
     // Initialize WebSocket manager
     let websocket_manager = Arc::new(WebSocketManager::new(services.jwt_service.clone()));
 
+    // Telegram sign-in is verified against the bot token
+    let auth_state = initialize_auth(&config, &services)?;
+
+    // Bot commands, including deep-link account linking
+    let telegram_state =
+        initialize_telegram_bot(&config, &services, &auth_state, &websocket_manager);
+
+    // Precomputed events and scheduled Telegram notifications
+    if config.notifications.enabled {
+        start_notification_scheduler(&config, &services);
+    }
+
     // Build application routes
-    let app = build_app(services, websocket_manager).await?;
+    let app = build_app(services, websocket_manager, auth_state, telegram_state).await?;
 
     // Start server
     let addr = SocketAddr::from(([0, 0, 0, 0], config.server.port));
     let listener = TcpListener::bind(addr).await?;
 
     info!("🚀 Server starting on {}", addr);
-    info!("📡 WebSocket endpoint: ws://{}/ws");
-    info!("🔗 Health check: http://{}/health");
+    info!("📡 WebSocket endpoint: ws://{}/ws", addr);
+    info!("🔗 Health check: http://{}/health", addr);
 
     serve(listener, app)
         .await
@@ -87,15 +111,26 @@ async fn main() -> Result<()> {
 async fn build_app(
     services: AppServices,
     websocket_manager: Arc<WebSocketManager>,
+    auth_state: AuthState,
+    telegram_state: TelegramState,
 ) -> Result<Router> {
+    let ephemeris = Arc::new(EphemerisService::new(services.cache_service.clone()));
+    let profile_state = ProfileState {
+        users: Arc::new(UserUseCaseImpl::new(services.clone())),
+        jwt_service: services.jwt_service.clone(),
+    };
+
     let app = Router::new()
-        .merge(routes::health_routes())
-        .merge(routes::auth_routes())
-        .merge(routes::api_routes())
-        .merge(routes::websocket_routes())
-        .with_state(services.clone())
-        .with_state(websocket_manager)
+        .merge(routes::health_routes(services.clone()))
+        .merge(routes::auth_routes(auth_state))
+        .merge(routes::telegram_routes(telegram_state))
+        .merge(routes::api_routes(ephemeris))
+        .merge(routes::premium_routes(services.jwt_service.clone()))
+        .merge(routes::user_routes(profile_state))
+        .merge(routes::websocket_routes(websocket_manager))
+        .layer(axum::middleware::from_fn(problem::problem_details))
         .layer(CorsLayer::permissive())
+        .layer(middleware::propagate_request_id_layer())
         .layer(middleware::request_id_layer())
         .layer(middleware::logging_layer());
 
@@ -112,17 +147,89 @@ async fn initialize_services(config: &AppConfig) -> Result<AppServices> {
     info!("✅ All services initialized successfully");
 
     // Start background tasks
-    start_background_tasks(&services).await?;
+    start_background_tasks().await?;
 
     Ok(services)
 }
 
+/// Wire the authentication use case with the Telegram signature verifier
+fn initialize_auth(config: &AppConfig, services: &AppServices) -> Result<AuthState> {
+    let max_age = time::Duration::seconds(i64::try_from(config.telegram.auth_max_age_secs)?);
+    let verifier = TelegramAuthVerifierImpl::new(&config.telegram.bot_token, max_age)
+        .map_err(|e| anyhow::anyhow!("Failed to initialize Telegram auth: {}", e))?;
+    let refresh_token_ttl = time::Duration::seconds(i64::try_from(config.jwt.refresh_token_ttl)?);
+
+    Ok(AuthState {
+        auth: Arc::new(AuthUseCaseImpl::new(
+            services.clone(),
+            config.telegram.channel_username.clone(),
+            Arc::new(verifier),
+            refresh_token_ttl,
+        )),
+        refresh_cookie: config.jwt.refresh_token_cookie,
+        refresh_token_ttl: config.jwt.refresh_token_ttl,
+        jwt_service: services.jwt_service.clone(),
+        bot_username: config.telegram.bot_username.clone(),
+    })
+}
+
+/// Wire the bot; without a webhook secret, updates are long-polled
+fn initialize_telegram_bot(
+    config: &AppConfig,
+    services: &AppServices,
+    auth_state: &AuthState,
+    websocket_manager: &Arc<WebSocketManager>,
+) -> TelegramState {
+    let linker = Arc::new(TelegramLinker::new(
+        auth_state.auth.clone(),
+        websocket_manager.clone(),
+    ));
+    let bot = Arc::new(telegram_bot(services, auth_state, linker.clone()));
+
+    if config.telegram.webhook_secret.is_none() {
+        let api = TelegramApiService::new(config.telegram.bot_token.clone());
+        tokio::spawn(bot::run_long_polling(api, bot.clone()));
+    }
+
+    TelegramState {
+        linker,
+        bot,
+        webhook_secret: config.telegram.webhook_secret.clone(),
+    }
+}
+
+fn telegram_bot(
+    services: &AppServices,
+    auth_state: &AuthState,
+    linker: Arc<TelegramLinker>,
+) -> TelegramBot {
+    TelegramBot::new(
+        &auth_state.bot_username,
+        auth_state.auth.clone(),
+        linker,
+        services.user_repository.clone(),
+        Arc::new(UserUseCaseImpl::new(services.clone())),
+        services.spiritual_event_repository.clone(),
+        services.telegram_service.clone(),
+    )
+}
+
+/// Spawn the event precomputation and notification dispatch loop
+fn start_notification_scheduler(config: &AppConfig, services: &AppServices) {
+    let scheduler = Arc::new(NotificationScheduler::new(
+        config.notifications.clone(),
+        services.spiritual_event_repository.clone(),
+        services.notification_repository.clone(),
+        services.telegram_service.clone(),
+    ));
+    tokio::spawn(scheduler.run());
+}
+
 /// Start background maintenance tasks
-async fn start_background_tasks(services: &AppServices) -> Result<()> {
+async fn start_background_tasks() -> Result<()> {
     info!("🔄 Starting background maintenance tasks");
 
     // Task for cleaning up expired tokens
-    let cache_service = services.cache_service.clone();
     tokio::spawn(async move {
         let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600)); // Every hour
 
@@ -147,12 +254,37 @@ mod tests {
     async fn test_build_app() {
         let services = AppServices::new_test();
         let websocket_manager = Arc::new(WebSocketManager::new(services.jwt_service.clone()));
+        let Ok(verifier) = TelegramAuthVerifierImpl::new("123456:TEST", time::Duration::hours(1))
+        else {
+            panic!("Failed to create Telegram auth verifier");
+        };
+        let auth_state = AuthState {
+            auth: Arc::new(AuthUseCaseImpl::new(
+                services.clone(),
+                "@test_channel".to_string(),
+                Arc::new(verifier),
+                time::Duration::seconds(604_800),
+            )),
+            refresh_cookie: false,
+            refresh_token_ttl: 604_800,
+            jwt_service: services.jwt_service.clone(),
+            bot_username: "test_bot".to_string(),
+        };
+        let linker = Arc::new(TelegramLinker::new(
+            auth_state.auth.clone(),
+            websocket_manager.clone(),
+        ));
+        let telegram_state = TelegramState {
+            bot: Arc::new(telegram_bot(&services, &auth_state, linker.clone())),
+            linker,
+            webhook_secret: None,
+        };
 
-        if let Ok(app) = build_app(services, websocket_manager).await {
-            // Basic smoke test
-            assert!(!format!("{:?}", app).is_empty());
-        } else {
-            assert!(false, "Failed to build app for test");
-        }
+        let Ok(app) = build_app(services, websocket_manager, auth_state, telegram_state).await
+        else {
+            panic!("Failed to build app for test");
+        };
+        // Basic smoke test
+        assert!(!format!("{:?}", app).is_empty());
     }
 }
@@ -1,13 +1,18 @@
 //! Middleware for request processing
 
-use axum::extract::Request;
-use tower::{Layer, Service};
-use tower_http::{request_id::RequestIdLayer, trace::TraceLayer};
-use uuid::Uuid;
+use tower_http::{
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
+    trace::TraceLayer,
+};
 
-/// Request ID middleware layer
-pub fn request_id_layer() -> RequestIdLayer {
-    RequestIdLayer::new(|| Uuid::new_v4().to_string())
+/// Request ID middleware layer: `x-request-id` from the client or a new UUID
+pub fn request_id_layer() -> SetRequestIdLayer<MakeRequestUuid> {
+    SetRequestIdLayer::x_request_id(MakeRequestUuid)
+}
+
+/// Copy the request ID to the response; goes inside [`request_id_layer`]
+pub fn propagate_request_id_layer() -> PropagateRequestIdLayer {
+    PropagateRequestIdLayer::x_request_id()
 }
 
 /// Logging middleware layer
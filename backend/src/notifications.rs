@@ -0,0 +1,421 @@
+//! Scheduled event notifications over Telegram
+//!
+//! The scheduler periodically
+//! 1. precomputes the next `horizon_days` of principal Moon phases,
+//!    equinoxes and solstices with `starscalendars-astro-core`, the code the
+//!    web client runs as WASM, into `spiritual_events`;
+//! 2. plans one delivery per upcoming event and opted-in user, due at
+//!    `delivery_hour` local time on the event's local day and rendered in the
+//!    user's language; idempotency keys make replanning after a restart a no-op;
+//! 3. claims due deliveries and sends them, at most `messages_per_second`,
+//!    retrying failed sends with exponential backoff.
+//!
+//! A claimed delivery is never claimed again: a crash between claiming and
+//! sending loses that message instead of sending it twice.
+
+use crate::bot::i18n;
+use starscalendars_app::{
+    AppResult, NotificationRepository, SpiritualEventRepository, TelegramService,
+};
+use starscalendars_domain::notifications::{
+    NotificationDelivery, NotificationRecipient, delivery_time,
+};
+use starscalendars_domain::spiritual::SpiritualEvent;
+use starscalendars_infra::NotificationConfig;
+use starscalendars_astro_core::calendar;
+use std::sync::Arc;
+use std::time::Duration;
+use time::OffsetDateTime;
+use tracing::{info, warn};
+
+/// Deliveries claimed per batch
+const CLAIM_BATCH: u32 = 100;
+
+/// Minimum time between precompute and planning runs
+const PLANNING_INTERVAL: time::Duration = time::Duration::minutes(15);
+
+/// Julian Day of the Unix epoch
+const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+
+/// Spaces out sends to stay under a messages-per-second limit
+pub struct RateLimiter {
+    period: Duration,
+    next: tokio::time::Instant,
+}
+
+impl RateLimiter {
+    /// At most `messages` sends per second
+    pub fn per_second(messages: u32) -> Self {
+        Self {
+            period: Duration::from_secs(1) / messages.max(1),
+            next: tokio::time::Instant::now(),
+        }
+    }
+
+    /// Wait for the next send slot
+    pub async fn acquire(&mut self) {
+        let now = tokio::time::Instant::now();
+        if self.next > now {
+            tokio::time::sleep_until(self.next).await;
+        }
+        self.next = self.next.max(now) + self.period;
+    }
+}
+
+/// Precomputes events, plans and dispatches notifications
+pub struct NotificationScheduler {
+    config: NotificationConfig,
+    events: Arc<dyn SpiritualEventRepository>,
+    notifications: Arc<dyn NotificationRepository>,
+    telegram: Arc<dyn TelegramService>,
+}
+
+impl NotificationScheduler {
+    pub fn new(
+        config: NotificationConfig,
+        events: Arc<dyn SpiritualEventRepository>,
+        notifications: Arc<dyn NotificationRepository>,
+        telegram: Arc<dyn TelegramService>,
+    ) -> Self {
+        Self {
+            config,
+            events,
+            notifications,
+            telegram,
+        }
+    }
+
+    /// Scheduler loop; runs for the lifetime of the process
+    pub async fn run(self: Arc<Self>) {
+        info!(
+            "🔔 Notification scheduler started ({} days ahead, {}:00 local time)",
+            self.config.horizon_days, self.config.delivery_hour
+        );
+
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.config.interval_secs.max(1)));
+        let mut limiter = RateLimiter::per_second(self.config.messages_per_second);
+        let mut planned_at: Option<OffsetDateTime> = None;
+        loop {
+            interval.tick().await;
+            let now = OffsetDateTime::now_utc();
+
+            if planned_at.is_none_or(|at| now - at >= PLANNING_INTERVAL) {
+                match self.precompute(now).await {
+                    Ok(stored) if stored > 0 => info!("🌙 Precomputed {} new events", stored),
+                    Ok(_) => {}
+                    Err(e) => warn!("Event precomputation failed: {}", e),
+                }
+                match self.plan(now).await {
+                    Ok(planned) => {
+                        planned_at = Some(now);
+                        if planned > 0 {
+                            info!("🔔 Scheduled {} notifications", planned);
+                        }
+                    }
+                    Err(e) => warn!("Notification planning failed: {}", e),
+                }
+            }
+
+            if let Err(e) = self.dispatch(now, &mut limiter).await {
+                warn!("Notification dispatch failed: {}", e);
+            }
+        }
+    }
+
+    /// Store the events of the next `horizon_days`; returns the number of new ones
+    pub async fn precompute(&self, now: OffsetDateTime) -> AppResult<u64> {
+        let start = julian_day(now);
+        let days = f64::from(self.config.horizon_days).min(calendar::MAX_RANGE_DAYS);
+        let found = calendar::calendar_events(start, start + days).ok_or_else(|| {
+            starscalendars_app::AppError::Internal("Event calculation failed".to_string())
+        })?;
+
+        let english = i18n::texts(i18n::DEFAULT_LANGUAGE);
+        let events: Vec<SpiritualEvent> = found
+            .iter()
+            .filter_map(|found| {
+                let mut event = SpiritualEvent::new(
+                    found.event_type.to_string(),
+                    String::new(),
+                    from_julian_day(found.jd_utc)?,
+                );
+                event.title = i18n::event_title(english, &event);
+                event.astronomical_data = serde_json::json!({ "julian_day": found.jd_utc });
+                Some(event)
+            })
+            .collect();
+
+        Ok(self.events.store_events(&events).await?)
+    }
+
+    /// Schedule deliveries of upcoming events; returns the number of new ones
+    pub async fn plan(&self, now: OffsetDateTime) -> AppResult<u64> {
+        let horizon = time::Duration::days(i64::from(self.config.horizon_days));
+        let events = self.events.events_between(now, now + horizon).await?;
+        let recipients = self.notifications.recipients().await?;
+
+        let deliveries: Vec<NotificationDelivery> = recipients
+            .iter()
+            .flat_map(|recipient| {
+                events
+                    .iter()
+                    .filter(|event| recipient.preferences.wants(&event.event_type))
+                    .map(move |event| {
+                        NotificationDelivery::new(
+                            recipient,
+                            event,
+                            render(recipient, event),
+                            delivery_time(
+                                event.occurs_at,
+                                &recipient.timezone,
+                                self.config.delivery_hour,
+                            ),
+                        )
+                    })
+            })
+            .collect();
+        if deliveries.is_empty() {
+            return Ok(0);
+        }
+
+        Ok(self.notifications.schedule_deliveries(&deliveries).await?)
+    }
+
+    /// Send every delivery due at `now`; returns the number of attempts
+    pub async fn dispatch(
+        &self,
+        now: OffsetDateTime,
+        limiter: &mut RateLimiter,
+    ) -> AppResult<usize> {
+        let mut attempted = 0;
+        loop {
+            let due = self
+                .notifications
+                .claim_due_deliveries(now, CLAIM_BATCH)
+                .await?;
+            for delivery in &due {
+                limiter.acquire().await;
+                self.send(delivery, now).await;
+            }
+
+            attempted += due.len();
+            if due.len() < usize::try_from(CLAIM_BATCH).unwrap_or(usize::MAX) {
+                return Ok(attempted);
+            }
+        }
+    }
+
+    /// Send one claimed delivery and record the outcome
+    async fn send(&self, delivery: &NotificationDelivery, now: OffsetDateTime) {
+        let key = &delivery.idempotency_key;
+        let recorded = match self
+            .telegram
+            .send_message(delivery.chat_id, &delivery.message)
+            .await
+        {
+            Ok(()) => self.notifications.mark_delivery_sent(key).await,
+            Err(e) => {
+                let attempts = delivery.attempts + 1;
+                let retry_at = (attempts < NotificationDelivery::MAX_ATTEMPTS)
+                    .then(|| now + NotificationDelivery::retry_delay(attempts));
+                warn!("Notification {} failed (attempt {}): {}", key, attempts, e);
+                self.notifications
+                    .record_delivery_failure(key, attempts, retry_at, &e.to_string())
+                    .await
+            }
+        };
+
+        if let Err(e) = recorded {
+            warn!("Failed to record notification {}: {}", key, e);
+        }
+    }
+}
+
+/// Notification text in the recipient's language and time zone
+fn render(recipient: &NotificationRecipient, event: &SpiritualEvent) -> String {
+    let language = i18n::supported_language(&recipient.language).unwrap_or(i18n::DEFAULT_LANGUAGE);
+    let t = i18n::texts(language);
+    i18n::format(
+        t.notification,
+        &[
+            ("title", &i18n::event_title(t, event)),
+            (
+                "date",
+                &i18n::format_local_time(event.occurs_at, &recipient.timezone),
+            ),
+        ],
+    )
+}
+
+/// Julian Day (UTC) of an instant
+pub(crate) fn julian_day(at: OffsetDateTime) -> f64 {
+    UNIX_EPOCH_JD + (at - OffsetDateTime::UNIX_EPOCH).as_seconds_f64() / 86_400.0
+}
+
+/// Instant of a Julian Day (UTC), rounded to the second so that repeated
+/// precomputations produce identical `occurs_at` values
+pub(crate) fn from_julian_day(jd_utc: f64) -> Option<OffsetDateTime> {
+    let seconds = ((jd_utc - UNIX_EPOCH_JD) * 86_400.0).round();
+    OffsetDateTime::UNIX_EPOCH.checked_add(time::Duration::checked_seconds_f64(seconds)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use starscalendars_app::{PortResult, TelegramUserInfo};
+    use starscalendars_domain::DomainError;
+    use starscalendars_domain::UserId;
+    use starscalendars_domain::notifications::{DeliveryStatus, NotificationPreferences};
+    use starscalendars_infra::{MockNotificationRepository, MockSpiritualEventRepository};
+    use std::sync::Mutex;
+    use time::macros::datetime;
+
+    /// Records sent messages; fails the first `failures` sends
+    #[derive(Default)]
+    struct FlakyTelegram {
+        failures: Mutex<u32>,
+        sent: Mutex<Vec<(i64, String)>>,
+    }
+
+    #[async_trait]
+    impl TelegramService for FlakyTelegram {
+        async fn is_member_of_channel(&self, _user_id: i64, _channel: &str) -> PortResult<bool> {
+            Ok(false)
+        }
+
+        async fn send_message(&self, user_id: i64, message: &str) -> PortResult<()> {
+            let Ok(mut failures) = self.failures.lock() else {
+                return Err(DomainError::InternalError("poisoned".to_string()));
+            };
+            if *failures > 0 {
+                *failures -= 1;
+                return Err(DomainError::ExternalServiceError(
+                    "Too Many Requests".to_string(),
+                ));
+            }
+            if let Ok(mut sent) = self.sent.lock() {
+                sent.push((user_id, message.to_string()));
+            }
+            Ok(())
+        }
+
+        async fn get_user_info(&self, _user_id: i64) -> PortResult<TelegramUserInfo> {
+            Err(DomainError::InternalError("unused".to_string()))
+        }
+
+        async fn health_check(&self) -> PortResult<()> {
+            Ok(())
+        }
+    }
+
+    fn recipient(telegram_user_id: i64, language: &str, timezone: &str) -> NotificationRecipient {
+        NotificationRecipient {
+            user_id: UserId::new(),
+            telegram_user_id,
+            language: language.to_string(),
+            timezone: timezone.to_string(),
+            preferences: NotificationPreferences::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_precompute_plan_and_dispatch() {
+        let events = Arc::new(MockSpiritualEventRepository::new());
+        let notifications = Arc::new(MockNotificationRepository::new());
+        let telegram = Arc::new(FlakyTelegram {
+            failures: Mutex::new(1),
+            ..Default::default()
+        });
+        let scheduler = NotificationScheduler::new(
+            NotificationConfig::default(),
+            events.clone(),
+            notifications.clone(),
+            telegram.clone(),
+        );
+
+        let mut moscow = recipient(1, "ru", "Europe/Moscow");
+        moscow.preferences.new_moon_alerts = true;
+        notifications.add_recipient(moscow);
+        let mut london = recipient(2, "en", "Europe/London");
+        london.preferences.spiritual_events = true;
+        notifications.add_recipient(london);
+
+        // New moon 2025-01-29 12:36 UTC, first quarter 2025-02-05 08:02 UTC
+        let now = datetime!(2025-01-25 00:00 UTC);
+        assert!(matches!(scheduler.precompute(now).await, Ok(n) if n >= 2));
+        assert!(matches!(scheduler.precompute(now).await, Ok(0)));
+        assert!(matches!(scheduler.plan(now).await, Ok(2)));
+        assert!(matches!(scheduler.plan(now).await, Ok(0)));
+
+        // 09:00 in Moscow: only the new moon is due; the first send fails
+        let mut limiter = RateLimiter::per_second(30);
+        let morning = datetime!(2025-01-29 06:00 UTC);
+        assert!(matches!(
+            scheduler.dispatch(morning, &mut limiter).await,
+            Ok(1)
+        ));
+        let deliveries = notifications.deliveries();
+        assert_eq!(deliveries.len(), 2);
+        assert_eq!(deliveries[0].status, DeliveryStatus::Pending);
+        assert_eq!(deliveries[0].attempts, 1);
+        assert_eq!(
+            deliveries[0].deliver_at,
+            morning + time::Duration::seconds(30)
+        );
+
+        // Retried after the backoff
+        let retry = morning + time::Duration::seconds(30);
+        assert!(matches!(
+            scheduler.dispatch(retry, &mut limiter).await,
+            Ok(1)
+        ));
+        assert!(matches!(
+            scheduler.dispatch(retry, &mut limiter).await,
+            Ok(0)
+        ));
+        let sent = telegram
+            .sent
+            .lock()
+            .map(|sent| sent.clone())
+            .unwrap_or_default();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].0, 1);
+        assert!(sent[0].1.contains("Новолуние"), "{}", sent[0].1);
+        // 12:36 UTC in Moscow time
+        assert!(sent[0].1.contains("2025-01-29 15:3"), "{}", sent[0].1);
+        assert!(sent[0].1.contains("(Europe/Moscow)"), "{}", sent[0].1);
+
+        // The first quarter goes out at 09:00 in London
+        let london_morning = datetime!(2025-02-05 09:00 UTC);
+        assert!(matches!(
+            scheduler.dispatch(london_morning, &mut limiter).await,
+            Ok(1)
+        ));
+        let statuses: Vec<_> = notifications
+            .deliveries()
+            .iter()
+            .map(|d| d.status)
+            .collect();
+        assert_eq!(statuses, [DeliveryStatus::Sent, DeliveryStatus::Sent]);
+    }
+
+    #[test]
+    fn test_julian_day_round_trip() {
+        let at = datetime!(2025-01-29 12:36:40 UTC);
+        assert!((julian_day(at) - 2_460_705.025_463).abs() < 1.0e-6);
+        assert_eq!(from_julian_day(julian_day(at)), Some(at));
+        assert_eq!(from_julian_day(f64::NAN), None);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_spacing() {
+        let mut limiter = RateLimiter::per_second(100);
+        let start = tokio::time::Instant::now();
+        for _ in 0..11 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+}
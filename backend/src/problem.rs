@@ -0,0 +1,487 @@
+//! RFC 7807 problem details for API errors
+//!
+//! Handlers fail with a [`Problem`] (usually through `ApiError`). The
+//! response carries the problem in its extensions, and [`problem_details`]
+//! renders it with a title in the `Accept-Language` language and the
+//! request id. Other error responses, such as extractor rejections and
+//! unknown routes, are turned into problems by the same middleware.
+
+use crate::i18n::{self, ErrorTitles};
+use axum::{
+    body::{self, Body},
+    extract::Request,
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::{Map, Value, json};
+use starscalendars_app::AppError;
+use starscalendars_domain::DomainError;
+
+/// Media type of problem responses
+pub const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+
+/// Header set by `middleware::request_id_layer`
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Prefix of the `type` member; the rest is the error code
+const PROBLEM_TYPE_PREFIX: &str = "urn:starscalendars:problem:";
+
+/// Largest plain-text rejection body kept as the problem detail
+const MAX_DETAIL_BYTES: usize = 4096;
+
+/// Stable machine-readable error code
+///
+/// Codes are part of the API: clients match on them, so existing ones
+/// must not be renamed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    BadRequest,
+    ValidationFailed,
+    Unauthorized,
+    MissingToken,
+    InvalidToken,
+    TokenExpired,
+    InvalidRefreshToken,
+    InvalidTelegramAuth,
+    InvalidLinkingToken,
+    Forbidden,
+    InsufficientRole,
+    SubscriptionRequired,
+    NotFound,
+    UserNotFound,
+    MethodNotAllowed,
+    Conflict,
+    UsernameTaken,
+    EmailTaken,
+    TelegramAlreadyLinked,
+    PayloadTooLarge,
+    UnsupportedMediaType,
+    TooManyRequests,
+    InternalError,
+    ServiceUnavailable,
+}
+
+impl ErrorCode {
+    /// Code as sent in the `code` member
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::BadRequest => "bad_request",
+            Self::ValidationFailed => "validation_failed",
+            Self::Unauthorized => "unauthorized",
+            Self::MissingToken => "missing_token",
+            Self::InvalidToken => "invalid_token",
+            Self::TokenExpired => "token_expired",
+            Self::InvalidRefreshToken => "invalid_refresh_token",
+            Self::InvalidTelegramAuth => "invalid_telegram_auth",
+            Self::InvalidLinkingToken => "invalid_linking_token",
+            Self::Forbidden => "forbidden",
+            Self::InsufficientRole => "insufficient_role",
+            Self::SubscriptionRequired => "subscription_required",
+            Self::NotFound => "not_found",
+            Self::UserNotFound => "user_not_found",
+            Self::MethodNotAllowed => "method_not_allowed",
+            Self::Conflict => "conflict",
+            Self::UsernameTaken => "username_taken",
+            Self::EmailTaken => "email_taken",
+            Self::TelegramAlreadyLinked => "telegram_already_linked",
+            Self::PayloadTooLarge => "payload_too_large",
+            Self::UnsupportedMediaType => "unsupported_media_type",
+            Self::TooManyRequests => "too_many_requests",
+            Self::InternalError => "internal_error",
+            Self::ServiceUnavailable => "service_unavailable",
+        }
+    }
+
+    /// HTTP status of the code
+    pub fn status(self) -> StatusCode {
+        match self {
+            Self::BadRequest => StatusCode::BAD_REQUEST,
+            Self::ValidationFailed => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::Unauthorized
+            | Self::MissingToken
+            | Self::InvalidToken
+            | Self::TokenExpired
+            | Self::InvalidRefreshToken
+            | Self::InvalidTelegramAuth
+            | Self::InvalidLinkingToken => StatusCode::UNAUTHORIZED,
+            Self::Forbidden | Self::InsufficientRole | Self::SubscriptionRequired => {
+                StatusCode::FORBIDDEN
+            }
+            Self::NotFound | Self::UserNotFound => StatusCode::NOT_FOUND,
+            Self::MethodNotAllowed => StatusCode::METHOD_NOT_ALLOWED,
+            Self::Conflict
+            | Self::UsernameTaken
+            | Self::EmailTaken
+            | Self::TelegramAlreadyLinked => StatusCode::CONFLICT,
+            Self::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+            Self::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+
+    /// Generic code of an error status
+    pub fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED => Self::Unauthorized,
+            StatusCode::FORBIDDEN => Self::Forbidden,
+            StatusCode::NOT_FOUND => Self::NotFound,
+            StatusCode::METHOD_NOT_ALLOWED => Self::MethodNotAllowed,
+            StatusCode::CONFLICT => Self::Conflict,
+            StatusCode::PAYLOAD_TOO_LARGE => Self::PayloadTooLarge,
+            StatusCode::UNSUPPORTED_MEDIA_TYPE => Self::UnsupportedMediaType,
+            StatusCode::UNPROCESSABLE_ENTITY => Self::ValidationFailed,
+            StatusCode::TOO_MANY_REQUESTS => Self::TooManyRequests,
+            StatusCode::SERVICE_UNAVAILABLE => Self::ServiceUnavailable,
+            status if status.is_server_error() => Self::InternalError,
+            _ => Self::BadRequest,
+        }
+    }
+
+    /// Localized title
+    pub fn title(self, titles: &ErrorTitles) -> &'static str {
+        match self {
+            Self::BadRequest => titles.bad_request,
+            Self::ValidationFailed => titles.validation_failed,
+            Self::Unauthorized => titles.unauthorized,
+            Self::MissingToken => titles.missing_token,
+            Self::InvalidToken => titles.invalid_token,
+            Self::TokenExpired => titles.token_expired,
+            Self::InvalidRefreshToken => titles.invalid_refresh_token,
+            Self::InvalidTelegramAuth => titles.invalid_telegram_auth,
+            Self::InvalidLinkingToken => titles.invalid_linking_token,
+            Self::Forbidden => titles.forbidden,
+            Self::InsufficientRole => titles.insufficient_role,
+            Self::SubscriptionRequired => titles.subscription_required,
+            Self::NotFound => titles.not_found,
+            Self::UserNotFound => titles.user_not_found,
+            Self::MethodNotAllowed => titles.method_not_allowed,
+            Self::Conflict => titles.conflict,
+            Self::UsernameTaken => titles.username_taken,
+            Self::EmailTaken => titles.email_taken,
+            Self::TelegramAlreadyLinked => titles.telegram_already_linked,
+            Self::PayloadTooLarge => titles.payload_too_large,
+            Self::UnsupportedMediaType => titles.unsupported_media_type,
+            Self::TooManyRequests => titles.too_many_requests,
+            Self::InternalError => titles.internal_error,
+            Self::ServiceUnavailable => titles.service_unavailable,
+        }
+    }
+}
+
+/// Problem details of a failed request
+#[derive(Debug, Clone)]
+pub struct Problem {
+    /// Machine-readable code, also naming the problem type
+    pub code: ErrorCode,
+    /// Response status; the code's status unless wrapping another response
+    pub status: StatusCode,
+    /// Explanation of this occurrence, never set for server errors
+    pub detail: Option<String>,
+    /// Request field at fault, for validation failures
+    pub field: Option<String>,
+    /// Role a guarded route requires
+    pub required_role: Option<String>,
+}
+
+impl Problem {
+    /// Problem with the code's status and no detail
+    pub fn new(code: ErrorCode) -> Self {
+        Self {
+            code,
+            status: code.status(),
+            detail: None,
+            field: None,
+            required_role: None,
+        }
+    }
+
+    /// Set the detail
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// Set the field at fault
+    pub fn with_field(mut self, field: impl Into<String>) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+
+    /// Set the role a guarded route requires
+    pub fn with_required_role(mut self, role: impl Into<String>) -> Self {
+        self.required_role = Some(role.into());
+        self
+    }
+
+    /// Problem document with a title in `language`
+    pub fn to_json(&self, language: &str, request_id: Option<&str>) -> Value {
+        let mut body = Map::new();
+        body.insert(
+            "type".to_string(),
+            json!(format!("{}{}", PROBLEM_TYPE_PREFIX, self.code.as_str())),
+        );
+        body.insert(
+            "title".to_string(),
+            json!(self.code.title(i18n::error_titles(language))),
+        );
+        body.insert("status".to_string(), json!(self.status.as_u16()));
+        body.insert("code".to_string(), json!(self.code.as_str()));
+        let members = [
+            ("detail", self.detail.as_deref()),
+            ("field", self.field.as_deref()),
+            ("required_role", self.required_role.as_deref()),
+            ("request_id", request_id),
+        ];
+        for (name, value) in members {
+            if let Some(value) = value {
+                body.insert(name.to_string(), json!(value));
+            }
+        }
+        Value::Object(body)
+    }
+
+    /// Problem for an error response not built from a `Problem`
+    async fn from_response(status: StatusCode, headers: &HeaderMap, body: Body) -> Self {
+        let problem = Self {
+            status,
+            ..Self::new(ErrorCode::from_status(status))
+        };
+
+        // Rejection messages explain client errors; server errors stay opaque
+        let is_text = headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("text/plain"));
+        if !status.is_client_error() || !is_text {
+            return problem;
+        }
+        match body::to_bytes(body, MAX_DETAIL_BYTES).await {
+            Ok(bytes) if !bytes.is_empty() => {
+                problem.with_detail(String::from_utf8_lossy(&bytes).into_owned())
+            }
+            _ => problem,
+        }
+    }
+}
+
+impl IntoResponse for Problem {
+    /// English document without request id, completed by [`problem_details`]
+    fn into_response(self) -> Response {
+        let body = self.to_json(i18n::DEFAULT_LANGUAGE, None).to_string();
+        let mut response = (
+            self.status,
+            [(header::CONTENT_TYPE, PROBLEM_CONTENT_TYPE)],
+            body,
+        )
+            .into_response();
+        response.extensions_mut().insert(self);
+        response
+    }
+}
+
+impl From<DomainError> for Problem {
+    fn from(err: DomainError) -> Self {
+        let code = match &err {
+            DomainError::ValidationFailed { field, message } => {
+                return Self::new(ErrorCode::ValidationFailed)
+                    .with_field(field.as_str())
+                    .with_detail(message.as_str());
+            }
+            DomainError::InvalidJwtToken => ErrorCode::InvalidToken,
+            DomainError::JwtTokenExpired(_) => ErrorCode::TokenExpired,
+            DomainError::InvalidRefreshToken => ErrorCode::InvalidRefreshToken,
+            DomainError::InvalidTelegramAuth(_) | DomainError::TelegramAuthExpired => {
+                ErrorCode::InvalidTelegramAuth
+            }
+            DomainError::InvalidLinkingToken => ErrorCode::InvalidLinkingToken,
+            DomainError::SubscriptionRequired => ErrorCode::SubscriptionRequired,
+            DomainError::UserNotFound(_) => ErrorCode::UserNotFound,
+            DomainError::UsernameExists(_) => ErrorCode::UsernameTaken,
+            DomainError::EmailExists(_) => ErrorCode::EmailTaken,
+            DomainError::TelegramAlreadyLinked(_) => ErrorCode::TelegramAlreadyLinked,
+            DomainError::InvalidStateTransition { .. } => ErrorCode::Conflict,
+            DomainError::ExternalServiceError(_) => ErrorCode::ServiceUnavailable,
+            e => match e.category() {
+                "auth" => ErrorCode::Unauthorized,
+                "user" | "spiritual" | "validation" => ErrorCode::ValidationFailed,
+                _ => ErrorCode::InternalError,
+            },
+        };
+        server_error_logged(Self::new(code), &err)
+    }
+}
+
+impl From<AppError> for Problem {
+    fn from(err: AppError) -> Self {
+        let (code, detail) = match err {
+            AppError::Domain(e) => return e.into(),
+            AppError::Authentication(detail) => (ErrorCode::Unauthorized, detail),
+            AppError::Authorization(detail) => (ErrorCode::Forbidden, detail),
+            AppError::Validation(detail) => (ErrorCode::ValidationFailed, detail),
+            AppError::ExternalService(detail) => (ErrorCode::ServiceUnavailable, detail),
+            AppError::Repository(detail)
+            | AppError::Configuration(detail)
+            | AppError::Internal(detail) => (ErrorCode::InternalError, detail),
+        };
+        server_error_logged(Self::new(code), &detail)
+    }
+}
+
+/// `problem` with `err` as detail; server errors only go to the logs
+fn server_error_logged(problem: Problem, err: &dyn std::fmt::Display) -> Problem {
+    if problem.status.is_server_error() {
+        tracing::error!("Request failed: {}", err);
+        problem
+    } else {
+        problem.with_detail(err.to_string())
+    }
+}
+
+/// Middleware rendering every error response as problem details
+///
+/// Runs inside `middleware::request_id_layer`, which sets the request id.
+pub async fn problem_details(request: Request, next: Next) -> Response {
+    let language = request_language(request.headers());
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let response = next.run(request).await;
+    let status = response.status();
+    if !status.is_client_error() && !status.is_server_error() {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let problem = match parts.extensions.remove::<Problem>() {
+        Some(problem) => problem,
+        None => Problem::from_response(status, &parts.headers, body).await,
+    };
+    let body = problem.to_json(language, request_id.as_deref()).to_string();
+    parts.headers.remove(header::CONTENT_LENGTH);
+    parts.headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(PROBLEM_CONTENT_TYPE),
+    );
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// Preferred supported language of `Accept-Language`, English otherwise
+pub fn request_language(headers: &HeaderMap) -> &'static str {
+    let mut best = (i18n::DEFAULT_LANGUAGE, 0.0_f32);
+    let ranges = headers
+        .get_all(header::ACCEPT_LANGUAGE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','));
+    for range in ranges {
+        let mut params = range.split(';');
+        let tag = params.next().unwrap_or_default().trim();
+        let quality = params
+            .find_map(|param| param.trim().strip_prefix("q="))
+            .map_or(Some(1.0), |q| q.parse::<f32>().ok())
+            .unwrap_or(0.0);
+        if let Some(language) = i18n::supported_language(tag)
+            && quality > best.1
+        {
+            best = (language, quality);
+        }
+    }
+    best.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Json, Router, routing::post};
+    use axum_test::TestServer;
+
+    async fn fail(Json(body): Json<Value>) -> Result<(), crate::handlers::ApiError> {
+        let field = body["field"].as_str().unwrap_or_default();
+        Err(AppError::Domain(DomainError::validation(field, "must be set")).into())
+    }
+
+    fn server() -> Option<TestServer> {
+        let app = Router::new()
+            .route("/fail", post(fail))
+            .layer(axum::middleware::from_fn(problem_details))
+            .layer(crate::middleware::propagate_request_id_layer())
+            .layer(crate::middleware::request_id_layer());
+        TestServer::new(app).ok()
+    }
+
+    #[test]
+    fn test_error_mapping() {
+        let problem = Problem::from(AppError::Domain(DomainError::UsernameExists(
+            "ada".to_string(),
+        )));
+        assert_eq!(problem.code, ErrorCode::UsernameTaken);
+        assert_eq!(problem.status, StatusCode::CONFLICT);
+
+        let problem = Problem::from(AppError::Domain(DomainError::InvalidEmail(
+            "ada@".to_string(),
+        )));
+        assert_eq!(problem.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let problem = Problem::from(AppError::Repository("connection refused".to_string()));
+        assert_eq!(problem.code, ErrorCode::InternalError);
+        assert!(problem.detail.is_none());
+
+        assert_eq!(
+            ErrorCode::from_status(StatusCode::NOT_ACCEPTABLE),
+            ErrorCode::BadRequest
+        );
+    }
+
+    #[test]
+    fn test_request_language() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(request_language(&headers), "en");
+        headers.insert(
+            header::ACCEPT_LANGUAGE,
+            HeaderValue::from_static("pt-BR, es;q=0.5, ru-RU;q=0.8"),
+        );
+        assert_eq!(request_language(&headers), "ru");
+        headers.insert(header::ACCEPT_LANGUAGE, HeaderValue::from_static("*"));
+        assert_eq!(request_language(&headers), "en");
+    }
+
+    #[tokio::test]
+    async fn test_problem_responses() {
+        let Some(app) = server() else {
+            panic!("Failed to start the test server");
+        };
+
+        let response = app
+            .post("/fail")
+            .add_header(header::ACCEPT_LANGUAGE, "ru")
+            .json(&json!({ "field": "timezone" }))
+            .await;
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        response.assert_header(header::CONTENT_TYPE, PROBLEM_CONTENT_TYPE);
+        let body: Value = response.json();
+        assert_eq!(body["type"], "urn:starscalendars:problem:validation_failed");
+        assert_eq!(body["title"], "Некорректные данные");
+        assert_eq!(body["status"], 422);
+        assert_eq!(body["field"], "timezone");
+        assert_eq!(body["detail"], "must be set");
+        let request_id = response.header(REQUEST_ID_HEADER);
+        assert_eq!(body["request_id"].as_str(), request_id.to_str().ok());
+
+        // Rejections and unknown routes become problems too
+        let response = app.post("/fail").text("{}").await;
+        response.assert_status(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        let body: Value = response.json();
+        assert_eq!(body["code"], "unsupported_media_type");
+        assert!(body["detail"].is_string());
+
+        let response = app.get("/missing").await;
+        response.assert_status(StatusCode::NOT_FOUND);
+        assert_eq!(response.json::<Value>()["title"], "Not found");
+    }
+}
@@ -1,5 +1,8 @@
 //! Route definitions for the backend server
 
+use crate::auth::{PREMIUM_ROLE, require_role};
+use crate::ephemeris::EphemerisService;
+use crate::handlers;
 use crate::websocket::{WebSocketManager, websocket_handler};
 use axum::{
     Router,
@@ -8,47 +11,67 @@ use axum::{
     routing::{get, post},
 };
 use serde_json::{Value, json};
-use starscalendars_app::AppServices;
+use starscalendars_app::{AppServices, JwtService};
 use std::sync::Arc;
 
 /// Health check routes
-pub fn health_routes() -> Router {
+pub fn health_routes(services: AppServices) -> Router {
     Router::new()
         .route("/health", get(health_check))
         .route("/readiness", get(readiness_check))
+        .with_state(services)
 }
 
 /// Authentication routes
-pub fn auth_routes() -> Router {
+pub fn auth_routes(state: handlers::AuthState) -> Router {
     Router::new()
-        .route(
-            "/auth/telegram",
-            post(|| async { "Telegram auth endpoint" }),
-        )
-        .route("/auth/refresh", post(|| async { "Token refresh endpoint" }))
-        .route("/auth/link", post(|| async { "Account linking endpoint" }))
+        .route("/auth/telegram", post(handlers::telegram_auth))
+        .route("/auth/refresh", post(handlers::refresh_tokens))
+        .route("/auth/link", post(handlers::create_link))
+        .route("/api/telegram/link-account", post(handlers::create_link))
+        .with_state(state)
+}
+
+/// Telegram bot routes: webhook updates and links confirmed by an external bot
+pub fn telegram_routes(state: handlers::TelegramState) -> Router {
+    Router::new()
+        .route("/api/telegram/webhook", post(handlers::telegram_webhook))
+        .route("/api/telegram/confirm-link", post(handlers::confirm_link))
+        .with_state(state)
 }
 
 /// API routes for astronomical data
-pub fn api_routes() -> Router {
+pub fn api_routes(ephemeris: Arc<EphemerisService>) -> Router {
+    Router::new()
+        .route("/api/ephemeris", get(handlers::ephemeris))
+        .route("/api/ephemeris/{date}", get(handlers::ephemeris_for_date))
+        .with_state(ephemeris)
+}
+
+/// Premium calculations; other users get 401/403
+pub fn premium_routes(jwt_service: Arc<dyn JwtService>) -> Router {
+    Router::new()
+        .route("/api/returns/{body}", get(handlers::next_return))
+        .route_layer(require_role(jwt_service, PREMIUM_ROLE))
+}
+
+/// Profile of the signed-in user
+pub fn user_routes(state: handlers::ProfileState) -> Router {
     Router::new()
-        .route(
-            "/api/ephemeris",
-            get(|| async { "Ephemeris data endpoint" }),
-        )
-        .route(
-            "/api/ephemeris/:date",
-            get(|| async { "Historical ephemeris endpoint" }),
-        )
         .route(
             "/api/user/profile",
-            get(|| async { "User profile endpoint" }),
+            get(handlers::get_profile)
+                .patch(handlers::patch_profile)
+                .delete(handlers::delete_profile),
         )
+        .with_state(state)
 }
 
 /// WebSocket routes
-pub fn websocket_routes() -> Router {
-    Router::new().route("/ws", get(websocket_handler))
+pub fn websocket_routes(websocket_manager: Arc<WebSocketManager>) -> Router {
+    Router::new()
+        .route("/ws", get(websocket_handler))
+        .with_state(websocket_manager)
 }
 
 async fn health_check() -> Json<Value> {
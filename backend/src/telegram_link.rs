@@ -0,0 +1,225 @@
+//! Telegram account linking through bot deep links
+//!
+//! The web client requests a linking token and opens
+//! `https://t.me/<bot>?start=<token>`. Telegram delivers `/start <token>` to
+//! the bot (see `crate::bot`); the sender's account is then
+//! linked to the user who requested the token, and that user's WebSocket
+//! connections receive a `SubscriptionUpdate`.
+
+use crate::websocket::{WebSocketManager, WebSocketMessage};
+use starscalendars_app::{AppResult, AuthUseCase, TelegramLink};
+use std::sync::Arc;
+use tracing::info;
+
+/// Completes account links requested from the web app
+pub struct TelegramLinker {
+    auth: Arc<dyn AuthUseCase>,
+    websocket_manager: Arc<WebSocketManager>,
+}
+
+impl TelegramLinker {
+    pub fn new(auth: Arc<dyn AuthUseCase>, websocket_manager: Arc<WebSocketManager>) -> Self {
+        Self {
+            auth,
+            websocket_manager,
+        }
+    }
+
+    /// Link the Telegram account and notify the user's web sessions
+    pub async fn confirm(
+        &self,
+        token: &uuid::Uuid,
+        telegram_user_id: i64,
+    ) -> AppResult<TelegramLink> {
+        let link = self
+            .auth
+            .link_telegram_account(token, telegram_user_id)
+            .await?;
+
+        self.websocket_manager
+            .send_to_user(
+                &link.user_id,
+                WebSocketMessage::SubscriptionUpdate {
+                    is_subscribed: link.is_subscribed,
+                    channel_name: link.channel.clone(),
+                },
+            )
+            .await;
+
+        info!(
+            "🔗 Telegram account {} linked to user {}",
+            telegram_user_id,
+            link.user_id.as_uuid()
+        );
+        Ok(link)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use starscalendars_app::{AppError, AppServices, AuthUseCaseImpl};
+    use starscalendars_domain::{
+        DomainError, JwtClaims, LinkingToken, TelegramUserId, TokenRepository, User, UserId,
+        UserRepository,
+    };
+    use starscalendars_infra::{
+        MockJwtService, MockTokenRepository, MockUserRepository, TelegramAuthVerifierImpl,
+    };
+    use tokio::sync::mpsc;
+
+    /// Linker for `user`, who has one authenticated WebSocket connection
+    struct Fixture {
+        linker: TelegramLinker,
+        users: Arc<MockUserRepository>,
+        tokens: Arc<MockTokenRepository>,
+        user: User,
+        messages: mpsc::UnboundedReceiver<WebSocketMessage>,
+    }
+
+    fn user(username: &str, telegram_user_id: Option<i64>) -> User {
+        let now = time::OffsetDateTime::now_utc();
+        User {
+            id: UserId::new(),
+            username: username.to_string(),
+            email: None,
+            telegram_user_id: telegram_user_id.and_then(|id| TelegramUserId::new(id).ok()),
+            created_at: now,
+            updated_at: now,
+            is_active: true,
+            language: "en".to_string(),
+            timezone: "UTC".to_string(),
+            spiritual_preferences: json!({}),
+        }
+    }
+
+    async fn fixture() -> Option<Fixture> {
+        let user = user("ada", None);
+        let users = Arc::new(MockUserRepository::new());
+        users.add_user(user.clone());
+        let tokens = Arc::new(MockTokenRepository::new());
+        let mut jwt_service = MockJwtService::new();
+        jwt_service.set_mock_claims(JwtClaims::new(&user.id, None, false, &[]));
+
+        let mut services = AppServices::new_test();
+        services.user_repository = users.clone();
+        services.token_repository = tokens.clone();
+        services.jwt_service = Arc::new(jwt_service);
+
+        let websocket_manager = Arc::new(WebSocketManager::new(services.jwt_service.clone()));
+        let (sender, messages) = mpsc::unbounded_channel();
+        let connection_id = websocket_manager.add_connection(sender).await;
+        websocket_manager
+            .authenticate_connection(&connection_id, "mock_access_token")
+            .await
+            .ok()?;
+
+        let verifier = TelegramAuthVerifierImpl::new("TEST", time::Duration::hours(1)).ok()?;
+        let auth = Arc::new(AuthUseCaseImpl::new(
+            services,
+            "@test_channel".to_string(),
+            Arc::new(verifier),
+            time::Duration::days(7),
+        ));
+        Some(Fixture {
+            linker: TelegramLinker::new(auth, websocket_manager),
+            users,
+            tokens,
+            user,
+            messages,
+        })
+    }
+
+    impl Fixture {
+        /// Store a linking token for `user` expiring at `expires_at`
+        async fn linking_token(&self, expires_at: time::OffsetDateTime) -> uuid::Uuid {
+            let token = LinkingToken {
+                expires_at,
+                ..LinkingToken::new(self.user.id)
+            };
+            let Ok(()) = self.tokens.store_linking_token(&token).await else {
+                panic!("linking token not stored");
+            };
+            token.token
+        }
+
+        async fn telegram_user_id(&self) -> Option<i64> {
+            let Ok(Some(user)) = self.users.get_user_by_id(&self.user.id).await else {
+                panic!("user {} not stored", self.user.id);
+            };
+            user.telegram_user_id.map(|id| id.value())
+        }
+
+        fn subscription_updates(&mut self) -> usize {
+            let mut count = 0;
+            while let Ok(message) = self.messages.try_recv() {
+                if let WebSocketMessage::SubscriptionUpdate { channel_name, .. } = message {
+                    assert_eq!(channel_name, "@test_channel");
+                    count += 1;
+                }
+            }
+            count
+        }
+    }
+
+    fn in_five_minutes() -> time::OffsetDateTime {
+        time::OffsetDateTime::now_utc() + time::Duration::minutes(5)
+    }
+
+    #[tokio::test]
+    async fn test_confirm_links_and_notifies_once() {
+        let Some(mut f) = fixture().await else {
+            panic!("Failed to set up Telegram linker");
+        };
+        let token = f.linking_token(in_five_minutes()).await;
+
+        let Ok(link) = f.linker.confirm(&token, 42).await else {
+            panic!("linking with a fresh token failed");
+        };
+        assert_eq!(link.telegram_user_id, 42);
+        assert_eq!(f.telegram_user_id().await, Some(42));
+        assert_eq!(f.subscription_updates(), 1);
+
+        // The same deep link opened again, e.g. from another Telegram account
+        assert!(matches!(
+            f.linker.confirm(&token, 43).await,
+            Err(AppError::Domain(DomainError::InvalidLinkingToken))
+        ));
+        assert_eq!(f.telegram_user_id().await, Some(42));
+        assert_eq!(f.subscription_updates(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_confirm_rejects_expired_token() {
+        let Some(mut f) = fixture().await else {
+            panic!("Failed to set up Telegram linker");
+        };
+        let token = f
+            .linking_token(time::OffsetDateTime::now_utc() - time::Duration::seconds(1))
+            .await;
+
+        assert!(matches!(
+            f.linker.confirm(&token, 42).await,
+            Err(AppError::Domain(DomainError::InvalidLinkingToken))
+        ));
+        assert_eq!(f.telegram_user_id().await, None);
+        assert_eq!(f.subscription_updates(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_confirm_rejects_account_linked_to_another_user() {
+        let Some(mut f) = fixture().await else {
+            panic!("Failed to set up Telegram linker");
+        };
+        f.users.add_user(user("grace", Some(42)));
+        let token = f.linking_token(in_five_minutes()).await;
+
+        assert!(matches!(
+            f.linker.confirm(&token, 42).await,
+            Err(AppError::Domain(DomainError::TelegramAlreadyLinked(42)))
+        ));
+        assert_eq!(f.telegram_user_id().await, None);
+        assert_eq!(f.subscription_updates(), 0);
+    }
+}
@@ -135,13 +135,13 @@ impl WebSocketManager {
         let mut user_connections = self.user_connections.write().await;
 
         if let Some(connection) = connections.get_mut(connection_id) {
-            connection.user_id = Some(claims.user_id.clone());
+            connection.user_id = Some(claims.user_id);
             connection.authenticated = true;
             connection.language = claims.language.unwrap_or_else(|| "en".to_string());
 
             // Track user connections for targeted messaging
             user_connections
-                .entry(claims.user_id.clone())
+                .entry(claims.user_id)
                 .or_insert_with(Vec::new)
                 .push(*connection_id);
 
@@ -235,7 +235,7 @@ impl WebSocketManager {
 
         let removal_futures = to_remove
             .into_iter()
-            .map(|connection_id| self.remove_connection(&connection_id));
+            .map(|connection_id| async move { self.remove_connection(&connection_id).await });
         join_all(removal_futures).await;
     }
 }
@@ -260,7 +260,6 @@ async fn handle_websocket(socket: WebSocket, manager: Arc<WebSocketManager>) {
 
     // Clone manager for tasks
     let manager_recv = manager.clone();
-    let manager_send = manager.clone();
 
     // Task for sending messages to client
     let send_task = tokio::spawn(async move {
@@ -273,7 +272,7 @@ async fn handle_websocket(socket: WebSocket, manager: Arc<WebSocketManager>) {
                 }
             };
 
-            if sender.send(Message::Text(msg_text)).await.is_err() {
+            if sender.send(Message::Text(msg_text.into())).await.is_err() {
                 break;
             }
         }
@@ -348,11 +347,7 @@ async fn handle_websocket(socket: WebSocket, manager: Arc<WebSocketManager>) {
                     );
                     break;
                 }
-                Ok(Message::Ping(data)) => {
-                    if sender.send(Message::Pong(data)).await.is_err() {
-                        break;
-                    }
-                }
+                // Pings are answered by the WebSocket implementation
                 _ => {}
             }
         }
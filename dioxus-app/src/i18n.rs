@@ -180,6 +180,8 @@ pub struct CulturalAdaptations {
     pub number_format: String,
     pub spiritual_greeting: String,
     pub moon_phase_names: Vec<String>,
+    /// Moon phase line with `{phase}`, `{age}` and `{illumination}` placeholders
+    pub moon_phase_summary: String,
     pub cosmic_terms: HashMap<String, String>,
 }
 
@@ -310,6 +312,46 @@ impl I18nService {
         date.format(&format_desc).unwrap_or_else(|_| date.to_string())
     }
     
+    /// Localized name of an 8-way Moon phase (0 New Moon … 7 Waning Crescent)
+    ///
+    /// Falls back to the English name when the current language has no
+    /// cultural adaptations.
+    pub fn moon_phase_name(&self, phase_index: u8) -> String {
+        let index = usize::from(phase_index);
+        self.current_language_metadata()
+            .and_then(|meta| meta.cultural_adaptations.moon_phase_names.get(index))
+            .or_else(|| {
+                self.language_metadata
+                    .get(&self.fallback_language)
+                    .and_then(|meta| meta.cultural_adaptations.moon_phase_names.get(index))
+            })
+            .cloned()
+            .unwrap_or_else(|| phase_index.to_string())
+    }
+    
+    /// Format a Moon phase for the dashboard, e.g. "Waxing Gibbous, 11.2 days, 87%"
+    pub fn format_moon_phase(&self, phase: &starscalendars_domain::spiritual::MoonPhase) -> String {
+        let age = format!("{:.1}", phase.age_days).replace('.', &self.decimal_separator().to_string());
+        let illumination = phase.illumination_percent().to_string();
+        let template = self.current_language_metadata()
+            .or_else(|| self.language_metadata.get(&self.fallback_language))
+            .map(|meta| meta.cultural_adaptations.moon_phase_summary.as_str())
+            .unwrap_or("{phase}, {age}, {illumination}%");
+        self.interpolate_string(template, &[
+            ("phase", &self.moon_phase_name(phase.phase_index)),
+            ("age", &age),
+            ("illumination", &illumination),
+        ])
+    }
+    
+    /// Decimal separator of the current language, taken from its number format
+    fn decimal_separator(&self) -> char {
+        // Number formats end with two decimals: "1,234.56", "1 234,56"
+        self.current_language_metadata()
+            .and_then(|meta| meta.cultural_adaptations.number_format.chars().rev().nth(2))
+            .unwrap_or('.')
+    }
+    
     fn initialize_language_metadata(&mut self) -> Result<(), I18nError> {
         // Define all supported languages with cultural adaptations
         let languages = vec![
@@ -325,6 +367,7 @@ impl I18nService {
                     "Full Moon".to_string(), "Waning Gibbous".to_string(),
                     "Last Quarter".to_string(), "Waning Crescent".to_string(),
                 ],
+                moon_phase_summary: "{phase}, {age} days, {illumination}%".to_string(),
                 cosmic_terms: [
                     ("alignment".to_string(), "cosmic alignment".to_string()),
                     ("energy".to_string(), "spiritual energy".to_string()),
@@ -342,6 +385,7 @@ impl I18nService {
                     "满月".to_string(), "亏凸月".to_string(),
                     "下弦月".to_string(), "残月".to_string(),
                 ],
+                moon_phase_summary: "{phase}，月龄 {age} 天，亮度 {illumination}%".to_string(),
                 cosmic_terms: [
                     ("alignment".to_string(), "天体排列".to_string()),
                     ("energy".to_string(), "气".to_string()),
@@ -359,6 +403,7 @@ impl I18nService {
                     "Luna Llena".to_string(), "Gibosa Menguante".to_string(),
                     "Cuarto Menguante".to_string(), "Luna Menguante".to_string(),
                 ],
+                moon_phase_summary: "{phase}, {age} días, {illumination} %".to_string(),
                 cosmic_terms: [
                     ("alignment".to_string(), "alineación cósmica".to_string()),
                     ("energy".to_string(), "energía espiritual".to_string()),
@@ -376,6 +421,7 @@ impl I18nService {
                     "पूर्णिमा".to_string(), "कृष्ण पक्ष".to_string(),
                     "तृतीय चतुर्थी".to_string(), "कृष्ण पक्ष".to_string(),
                 ],
+                moon_phase_summary: "{phase}, {age} दिन, {illumination}%".to_string(),
                 cosmic_terms: [
                     ("alignment".to_string(), "ग्रह संयोजन".to_string()),
                     ("energy".to_string(), "आध्यात्मिक ऊर्जा".to_string()),
@@ -393,6 +439,7 @@ impl I18nService {
                     "Полнолуние".to_string(), "Убывающая луна".to_string(),
                     "Последняя четверть".to_string(), "Убывающий серп".to_string(),
                 ],
+                moon_phase_summary: "{phase}, {age} сут., {illumination} %".to_string(),
                 cosmic_terms: [
                     ("alignment".to_string(), "космическое выравнивание".to_string()),
                     ("energy".to_string(), "духовная энергия".to_string()),
@@ -410,6 +457,7 @@ impl I18nService {
                     "Լիալուսին".to_string(), "Նվազող լուսին".to_string(),
                     "Վերջին քառորդ".to_string(), "Նվազող մահիկ".to_string(),
                 ],
+                moon_phase_summary: "{phase}, {age} օր, {illumination}%".to_string(),
                 cosmic_terms: [
                     ("alignment".to_string(), "տիեզերական հավասարակշռություն".to_string()),
                     ("energy".to_string(), "հոգևոր էներգիա".to_string()),
@@ -447,6 +495,7 @@ impl I18nService {
             ["auth", "continue_with_telegram"] => Some(translations.auth.continue_with_telegram.clone()),
             ["dashboard", "welcome_message"] => Some(translations.dashboard.welcome_message.clone()),
             ["dashboard", "spiritual_journey"] => Some(translations.dashboard.spiritual_journey.clone()),
+            ["spiritual", "lunar_tracking"] => Some(translations.spiritual.lunar_tracking.clone()),
             ["common", "loading"] => Some(translations.common.loading.clone()),
             ["common", "save"] => Some(translations.common.save.clone()),
             ["common", "cancel"] => Some(translations.common.cancel.clone()),
@@ -13,6 +13,7 @@
 
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
+use starscalendars_astro_core::moon_phase::moon_phase;
 use starscalendars_domain::*;
 use std::collections::HashMap;
 
@@ -212,6 +213,9 @@ fn App() -> Element {
                 
                 match app_state.read().auth_status {
                     AuthStatus::Authenticated { .. } => rsx! { 
+                        MoonPhaseCard { 
+                            i18n_service: i18n_service,
+                        }
                         UserDashboard { 
                             app_state: app_state,
                             i18n_service: i18n_service,
@@ -245,4 +249,29 @@ fn App() -> Element {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Julian Day of the Unix epoch
+const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+
+/// Current Moon phase on the dashboard, in the selected language
+#[component]
+fn MoonPhaseCard(i18n_service: Signal<I18nService>) -> Element {
+    let now = time::OffsetDateTime::now_utc();
+    let jd_utc = UNIX_EPOCH_JD + (now - time::OffsetDateTime::UNIX_EPOCH).as_seconds_f64() / 86_400.0;
+    let Some(phase) = moon_phase(jd_utc) else {
+        return rsx! {};
+    };
+    let i18n = i18n_service.read();
+    let title = i18n.t("spiritual.lunar_tracking");
+    let summary = i18n.format_moon_phase(&phase);
+
+    rsx! {
+        div {
+            class: "moon-phase-card",
+            style: "padding: 1rem 1.5rem; margin-bottom: 1.5rem; border-radius: 12px; background: rgba(255, 255, 255, 0.1); color: white;",
+            h3 { style: "margin: 0 0 0.5rem 0; font-size: 1rem; opacity: 0.8;", "{title}" }
+            p { style: "margin: 0; font-size: 1.25rem;", "{summary}" }
+        }
+    }
+}
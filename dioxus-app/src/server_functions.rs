@@ -109,6 +109,9 @@ pub async fn create_user(username: String, email: Option<String>) -> Result<User
         created_at: now,
         updated_at: now,
         is_active: true,
+        language: user::DEFAULT_LANGUAGE.to_string(),
+        timezone: user::DEFAULT_TIMEZONE.to_string(),
+        spiritual_preferences: serde_json::json!({}),
     };
     
     // TODO: Save to database with prepared statement
@@ -178,6 +181,9 @@ pub async fn link_telegram_account(
         created_at: now,
         updated_at: now,
         is_active: true,
+        language: user::DEFAULT_LANGUAGE.to_string(),
+        timezone: user::DEFAULT_TIMEZONE.to_string(),
+        spiritual_preferences: serde_json::json!({}),
     })
 }
 
@@ -255,6 +261,9 @@ pub async fn update_user_profile(
         created_at: now,
         updated_at: now,
         is_active: true,
+        language: user::DEFAULT_LANGUAGE.to_string(),
+        timezone: user::DEFAULT_TIMEZONE.to_string(),
+        spiritual_preferences: serde_json::json!({}),
     })
 }
 
@@ -13,20 +13,14 @@ pub use usecases::*;
 
 // Re-export domain ports for convenience
 pub use starscalendars_domain::{
-    CacheService, CacheServiceExt, JwtService, PortResult, TelegramService, TelegramUserInfo,
+    CacheService, CacheServiceExt, EventPublisher, JwtService, NotificationRepository, PortResult,
+    SpiritualEventRepository, TelegramAuthVerifier, TelegramService, TelegramUserInfo,
     TokenRepository, UserRepository,
 };
 
 /// Application layer result type
 pub type AppResult<T> = Result<T, AppError>;
 
-/// Convert domain results to app results
-impl From<starscalendars_domain::DomainError> for AppError {
-    fn from(err: starscalendars_domain::DomainError) -> Self {
-        Self::Domain(err)
-    }
-}
-
 /// Application layer errors
 #[derive(Debug, thiserror::Error)]
 pub enum AppError {
@@ -18,6 +18,13 @@ pub struct AppServices {
     pub cache_service: Arc<dyn crate::CacheService + Send + Sync>,
     pub jwt_service: Arc<dyn crate::JwtService + Send + Sync>,
     pub telegram_service: Arc<dyn crate::TelegramService + Send + Sync>,
+    /// Precomputed events, read by the bot and the notification dispatcher
+    pub spiritual_event_repository: Arc<dyn crate::SpiritualEventRepository>,
+    /// Scheduled notification deliveries
+    pub notification_repository: Arc<dyn crate::NotificationRepository>,
+    /// Receives the domain events of the use cases
+    pub event_publisher:
+        Arc<dyn crate::EventPublisher<Error = starscalendars_domain::DomainError> + Send + Sync>,
     // ❌ REMOVED: astronomical_service (violates WASM-only architecture)
 }
 
@@ -41,17 +48,15 @@ impl AppServices {
             .map_err(|e| AppError::Configuration(format!("Database migrations failed: {}", e)))?;
 
         // Initialize cache service
-        let cache_manager = starscalendars_infra::CacheServiceManager::new(&config.redis)
+        let redis_cache = starscalendars_infra::RedisCacheService::connect(&config.redis.url)
             .await
             .map_err(|e| {
                 AppError::Configuration(format!("Redis cache initialization failed: {}", e))
             })?;
 
-        let redis_cache = cache_manager.create_cache_service().await.map_err(|e| {
-            AppError::Configuration(format!("Redis cache service creation failed: {}", e))
-        })?;
-
-        let telegram_cache = starscalendars_infra::TelegramCacheService::new(Box::new(redis_cache));
+        let telegram_cache = Arc::new(starscalendars_infra::TelegramCacheService::new(Box::new(
+            redis_cache,
+        )));
 
         // Initialize JWT service with production keys
         let jwt_service = match (
@@ -84,7 +89,7 @@ impl AppServices {
         let telegram_service = starscalendars_infra::TelegramServiceImpl::new(
             &config.telegram.bot_token,
             &config.telegram.channel_username,
-            Arc::new(telegram_cache.clone()),
+            telegram_cache.clone(),
         )
         .await
         .map_err(|e| {
@@ -98,6 +103,15 @@ impl AppServices {
         let token_repository =
             starscalendars_infra::PostgresTokenRepository::new(database_service.pool().clone());
 
+        let spiritual_event_repository =
+            starscalendars_infra::PostgresSpiritualEventRepository::new(
+                database_service.pool().clone(),
+            );
+
+        let notification_repository = starscalendars_infra::PostgresNotificationRepository::new(
+            database_service.pool().clone(),
+        );
+
         // ❌ REMOVED: Astronomical service initialization (violates WASM-only architecture)
         // All astronomical calculations performed in frontend WASM module only
 
@@ -106,9 +120,12 @@ impl AppServices {
         Ok(Self {
             user_repository: Arc::new(user_repository),
             token_repository: Arc::new(token_repository),
-            cache_service: Arc::new(telegram_cache),
+            cache_service: telegram_cache,
             jwt_service: Arc::new(jwt_service),
             telegram_service: Arc::new(telegram_service),
+            spiritual_event_repository: Arc::new(spiritual_event_repository),
+            notification_repository: Arc::new(notification_repository),
+            event_publisher: Arc::new(starscalendars_infra::TracingEventPublisher),
             // ❌ REMOVED: astronomical_service (WASM-only per tz.md)
         })
     }
@@ -127,6 +144,13 @@ impl AppServices {
             cache_service: Arc::new(telegram_cache),
             jwt_service: Arc::new(starscalendars_infra::MockJwtService::new()),
             telegram_service: Arc::new(starscalendars_infra::MockTelegramService::new()),
+            spiritual_event_repository: Arc::new(
+                starscalendars_infra::MockSpiritualEventRepository::new(),
+            ),
+            notification_repository: Arc::new(
+                starscalendars_infra::MockNotificationRepository::new(),
+            ),
+            event_publisher: Arc::new(starscalendars_infra::MockEventPublisher::new()),
             // ❌ REMOVED: MockAstronomicalService (violates WASM-only architecture)
         }
     }
@@ -147,7 +171,7 @@ impl AppServices {
         }
 
         // Check JWT service
-        let test_claims = crate::JwtClaims::new(
+        let test_claims = starscalendars_domain::JwtClaims::new(
             &starscalendars_domain::UserId::new(),
             Some("test".to_string()),
             false,
@@ -1,9 +1,6 @@
 //! Authentication use cases
 
-use crate::{
-    AppResult, AppServices,
-    ports::{CacheServiceExt, JwtService, TelegramService, TokenRepository, UserRepository},
-};
+use crate::{AppResult, AppServices};
 use async_trait::async_trait;
 use starscalendars_domain::*;
 use std::sync::Arc;
@@ -11,47 +8,70 @@ use std::sync::Arc;
 /// Authentication use case interface
 #[async_trait]
 pub trait AuthUseCase: Send + Sync {
-    async fn authenticate_with_telegram(&self, telegram_user_id: i64) -> AppResult<JwtTokenPair>;
+    /// Sign in with a Login Widget or WebApp payload signed by Telegram
+    async fn authenticate_with_telegram(
+        &self,
+        payload: &TelegramAuthPayload,
+    ) -> AppResult<JwtTokenPair>;
     async fn refresh_tokens(&self, refresh_token: &str) -> AppResult<JwtTokenPair>;
-    async fn create_linking_token(&self, user_id: &UserId) -> AppResult<uuid::Uuid>;
+    /// Issue a short-lived token for a `t.me/<bot>?start=<token>` deep link
+    async fn create_linking_token(&self, user_id: &UserId) -> AppResult<LinkingToken>;
+    /// Consume a linking token received by the bot and attach the Telegram account
     async fn link_telegram_account(
         &self,
         token: &uuid::Uuid,
         telegram_user_id: i64,
-    ) -> AppResult<()>;
+    ) -> AppResult<TelegramLink>;
     async fn check_subscription_status(&self, telegram_user_id: i64) -> AppResult<bool>;
 }
 
+/// Outcome of a completed Telegram account link
+#[derive(Debug, Clone)]
+pub struct TelegramLink {
+    pub user_id: UserId,
+    pub telegram_user_id: i64,
+    /// Channel membership checked at link time
+    pub is_subscribed: bool,
+    pub channel: String,
+}
+
 /// Implementation of authentication use case
 pub struct AuthUseCaseImpl {
     services: AppServices,
     telegram_channel: String,
+    auth_verifier: Arc<dyn TelegramAuthVerifier>,
+    /// Lifetime of issued refresh tokens, matching the refresh cookie
+    refresh_token_ttl: time::Duration,
 }
 
 impl AuthUseCaseImpl {
-    pub fn new(services: AppServices, telegram_channel: String) -> Self {
+    pub fn new(
+        services: AppServices,
+        telegram_channel: String,
+        auth_verifier: Arc<dyn TelegramAuthVerifier>,
+        refresh_token_ttl: time::Duration,
+    ) -> Self {
         Self {
             services,
             telegram_channel,
+            auth_verifier,
+            refresh_token_ttl,
         }
     }
 }
 
 #[async_trait]
 impl AuthUseCase for AuthUseCaseImpl {
-    async fn authenticate_with_telegram(&self, telegram_user_id: i64) -> AppResult<JwtTokenPair> {
-        let telegram_id =
-            TelegramUserId::new(telegram_user_id).map_err(|e| crate::AppError::Domain(e))?;
+    async fn authenticate_with_telegram(
+        &self,
+        payload: &TelegramAuthPayload,
+    ) -> AppResult<JwtTokenPair> {
+        // Nothing in the payload is trusted before the signature check
+        let login = self.auth_verifier.verify(payload)?;
+        let telegram_user_id = login.user_id.value();
 
         // Check if user exists or create new one
-        let user = match self.get_or_create_user_by_telegram(telegram_id).await? {
-            Some(user) => user,
-            None => {
-                return Err(crate::AppError::Internal(
-                    "Failed to create user".to_string(),
-                ));
-            }
-        };
+        let user = self.get_or_create_user_by_telegram(&login).await?;
 
         // Check subscription status
         let is_subscribed = self.check_subscription_status(telegram_user_id).await?;
@@ -62,8 +82,13 @@ impl AuthUseCase for AuthUseCaseImpl {
         } else {
             vec!["user".to_string()]
         };
-        let claims =
-            JwtClaims::new_with_telegram(&user.id, Some(telegram_id), None, is_subscribed, &roles);
+        let claims = JwtClaims::new_with_telegram(
+            &user.id,
+            Some(login.user_id),
+            login.language_code.clone(),
+            is_subscribed,
+            &roles,
+        );
 
         // Generate tokens
         let access_token = self
@@ -73,10 +98,16 @@ impl AuthUseCase for AuthUseCaseImpl {
             .await?;
         let refresh_token_str = self.services.jwt_service.create_refresh_token().await?;
 
-        // Store refresh token
-        let refresh_token = RefreshToken::new(user.id, refresh_token_str.clone());
+        // Store refresh token hash, starting a new rotation family
+        let refresh_token = RefreshToken::new(
+            user.id,
+            self.services
+                .jwt_service
+                .hash_refresh_token(&refresh_token_str),
+            self.refresh_token_ttl,
+        );
         self.services
-            .token_repo
+            .token_repository
             .store_refresh_token(&refresh_token)
             .await?;
 
@@ -85,21 +116,38 @@ impl AuthUseCase for AuthUseCaseImpl {
 
     async fn refresh_tokens(&self, refresh_token: &str) -> AppResult<JwtTokenPair> {
         // Get and validate refresh token
+        let token_hash = self.services.jwt_service.hash_refresh_token(refresh_token);
         let token = self
             .services
-            .token_repo
-            .get_refresh_token(refresh_token)
+            .token_repository
+            .get_refresh_token(&token_hash)
             .await?
-            .ok_or_else(|| crate::AppError::Domain(DomainError::InvalidRefreshToken))?;
+            .ok_or(crate::AppError::Domain(DomainError::InvalidRefreshToken))?;
 
+        // A revoked token is presented again: it leaked, so the whole family goes
+        if token.is_revoked {
+            self.revoke_token_family(&token).await?;
+            return Err(crate::AppError::Domain(DomainError::InvalidRefreshToken));
+        }
         if !token.is_valid() {
             return Err(crate::AppError::Domain(DomainError::InvalidRefreshToken));
         }
 
+        // Revoke before issuing, so that concurrent reuse loses the race
+        if !self
+            .services
+            .token_repository
+            .revoke_refresh_token(&token_hash)
+            .await?
+        {
+            self.revoke_token_family(&token).await?;
+            return Err(crate::AppError::Domain(DomainError::InvalidRefreshToken));
+        }
+
         // Get user
         let user = self
             .services
-            .user_repo
+            .user_repository
             .get_user_by_id(&token.user_id)
             .await?
             .ok_or_else(|| {
@@ -108,7 +156,7 @@ impl AuthUseCase for AuthUseCaseImpl {
 
         // Check subscription if Telegram linked
         let is_subscribed = if let Some(telegram_id) = user.telegram_user_id {
-            self.check_subscription_status(telegram_id.as_i64()).await?
+            self.check_subscription_status(telegram_id.value()).await?
         } else {
             false
         };
@@ -119,7 +167,13 @@ impl AuthUseCase for AuthUseCaseImpl {
         } else {
             vec!["user".to_string()]
         };
-        let claims = JwtClaims::new(&user.id, user.telegram_user_id, is_subscribed, &roles);
+        let claims = JwtClaims::new_with_telegram(
+            &user.id,
+            user.telegram_user_id,
+            Some(user.language.clone()),
+            is_subscribed,
+            &roles,
+        );
 
         let access_token = self
             .services
@@ -128,53 +182,64 @@ impl AuthUseCase for AuthUseCaseImpl {
             .await?;
         let new_refresh_token = self.services.jwt_service.create_refresh_token().await?;
 
-        // Revoke old refresh token and store new one
-        self.services
-            .token_repo
-            .revoke_refresh_token(refresh_token)
-            .await?;
-        let new_token = RefreshToken::new(user.id, new_refresh_token.clone());
+        // Store the successor in the same family
+        let new_token = token.rotate(
+            self.services
+                .jwt_service
+                .hash_refresh_token(&new_refresh_token),
+            self.refresh_token_ttl,
+        );
         self.services
-            .token_repo
+            .token_repository
             .store_refresh_token(&new_token)
             .await?;
 
         Ok(JwtTokenPair::new(access_token, new_refresh_token, 900))
     }
 
-    async fn create_linking_token(&self, user_id: &UserId) -> AppResult<uuid::Uuid> {
-        let token = LinkingToken::new(user_id.clone());
-        let token_id = token.token;
+    async fn create_linking_token(&self, user_id: &UserId) -> AppResult<LinkingToken> {
+        let token = LinkingToken::new(*user_id);
 
-        self.services.token_repo.store_linking_token(&token).await?;
+        self.services
+            .token_repository
+            .store_linking_token(&token)
+            .await?;
 
-        Ok(token_id)
+        Ok(token)
     }
 
     async fn link_telegram_account(
         &self,
         token: &uuid::Uuid,
         telegram_user_id: i64,
-    ) -> AppResult<()> {
-        let telegram_id =
-            TelegramUserId::new(telegram_user_id).map_err(|e| crate::AppError::Domain(e))?;
+    ) -> AppResult<TelegramLink> {
+        let telegram_id = TelegramUserId::new(telegram_user_id).map_err(crate::AppError::Domain)?;
 
-        // Get and validate linking token
+        // Consume before linking: of two concurrent /start commands only one gets the token
         let linking_token = self
             .services
-            .token_repo
-            .get_linking_token(token)
+            .token_repository
+            .consume_linking_token(token)
             .await?
-            .ok_or_else(|| crate::AppError::Domain(DomainError::InvalidRefreshToken))?;
+            .ok_or(crate::AppError::Domain(DomainError::InvalidLinkingToken))?;
 
-        if !linking_token.is_valid() {
-            return Err(crate::AppError::Domain(DomainError::InvalidRefreshToken));
+        // One Telegram account per user
+        if let Some(owner) = self
+            .services
+            .user_repository
+            .get_user_by_telegram_id(telegram_user_id)
+            .await?
+            && owner.id != linking_token.user_id
+        {
+            return Err(crate::AppError::Domain(DomainError::TelegramAlreadyLinked(
+                telegram_user_id,
+            )));
         }
 
         // Get user and update with Telegram ID
         let mut user = self
             .services
-            .user_repo
+            .user_repository
             .get_user_by_id(&linking_token.user_id)
             .await?
             .ok_or_else(|| {
@@ -186,13 +251,17 @@ impl AuthUseCase for AuthUseCaseImpl {
         user.telegram_user_id = Some(telegram_id);
         user.updated_at = time::OffsetDateTime::now_utc();
 
-        self.services.user_repo.update_user(&user).await?;
-        self.services
-            .token_repo
-            .mark_linking_token_used(token)
-            .await?;
+        self.services.user_repository.update_user(&user).await?;
 
-        Ok(())
+        // Checked now so that the web client learns its status right away
+        let is_subscribed = self.check_subscription_status(telegram_user_id).await?;
+
+        Ok(TelegramLink {
+            user_id: user.id,
+            telegram_user_id,
+            is_subscribed,
+            channel: self.telegram_channel.clone(),
+        })
     }
 
     async fn check_subscription_status(&self, telegram_user_id: i64) -> AppResult<bool> {
@@ -230,34 +299,320 @@ impl AuthUseCase for AuthUseCaseImpl {
 }
 
 impl AuthUseCaseImpl {
-    async fn get_or_create_user_by_telegram(
-        &self,
-        telegram_id: TelegramUserId,
-    ) -> AppResult<Option<User>> {
-        // Try to find existing user by Telegram ID
-        // This would require a method in UserRepository to find by telegram_user_id
-        // For now, create a new user
-
-        let user_info = self
+    /// Revoke the family of a reused refresh token and record the security event
+    async fn revoke_token_family(&self, token: &RefreshToken) -> AppResult<()> {
+        let revoked_tokens = self
             .services
-            .telegram_service
-            .get_user_info(telegram_id.as_i64())
+            .token_repository
+            .revoke_refresh_token_family(&token.family_id)
             .await?;
+        tracing::warn!(
+            user_id = %token.user_id,
+            family_id = %token.family_id,
+            revoked_tokens,
+            "Refresh token reuse detected, token family revoked"
+        );
+        let event = UserEvent::RefreshTokenReuseDetected {
+            event_id: EventId::new(),
+            occurred_at: time::OffsetDateTime::now_utc(),
+            user_id: token.user_id,
+            family_id: token.family_id,
+            revoked_tokens,
+        };
+        // The family is already revoked; a lost event must not fail the request
+        if let Err(e) = self.services.event_publisher.publish(Event::User(event)) {
+            tracing::warn!("Failed to publish refresh token reuse event: {}", e);
+        }
+        Ok(())
+    }
+
+    async fn get_or_create_user_by_telegram(&self, login: &TelegramLogin) -> AppResult<User> {
+        let telegram_user_id = login.user_id.value();
+        if let Some(user) = self
+            .services
+            .user_repository
+            .get_user_by_telegram_id(telegram_user_id)
+            .await?
+        {
+            return Ok(user);
+        }
 
+        // First sign-in: the verified payload carries the profile
+        let now = time::OffsetDateTime::now_utc();
         let user = User {
             id: UserId::new(),
-            username: user_info
+            username: login
                 .username
-                .unwrap_or_else(|| format!("telegram_{}", telegram_id.as_i64())),
+                .clone()
+                .unwrap_or_else(|| format!("telegram_{}", telegram_user_id)),
             email: None,
-            telegram_user_id: Some(telegram_id),
-            created_at: time::OffsetDateTime::now_utc(),
-            updated_at: time::OffsetDateTime::now_utc(),
+            telegram_user_id: Some(login.user_id),
+            created_at: now,
+            updated_at: now,
             is_active: true,
+            language: user::DEFAULT_LANGUAGE.to_string(),
+            timezone: user::DEFAULT_TIMEZONE.to_string(),
+            spiritual_preferences: serde_json::json!({}),
         };
 
-        self.services.user_repo.create_user(user.clone()).await?;
+        self.services
+            .user_repository
+            .create_user(user.clone())
+            .await?;
+
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starscalendars_infra::{
+        MockEventPublisher, MockTokenRepository, MockUserRepository, TelegramAuthVerifierImpl,
+        refresh_token_hash,
+    };
+
+    const REFRESH_TOKEN_TTL: time::Duration = time::Duration::days(7);
+
+    /// Use case over mock repositories, with one user signed in as `first`
+    struct Fixture {
+        auth: AuthUseCaseImpl,
+        users: Arc<MockUserRepository>,
+        tokens: Arc<MockTokenRepository>,
+        events: Arc<MockEventPublisher>,
+        user: User,
+        first: RefreshToken,
+    }
+
+    impl Fixture {
+        /// Store a linking token for the signed-in user
+        async fn linking_token(&self, expires_at: time::OffsetDateTime) -> uuid::Uuid {
+            let token = LinkingToken {
+                expires_at,
+                ..LinkingToken::new(self.user.id)
+            };
+            let Ok(()) = self.tokens.store_linking_token(&token).await else {
+                panic!("linking token not stored");
+            };
+            token.token
+        }
 
-        Ok(Some(user))
+        async fn telegram_user_id(&self) -> Option<i64> {
+            let Ok(Some(user)) = self.users.get_user_by_id(&self.user.id).await else {
+                panic!("user {} not stored", self.user.id);
+            };
+            user.telegram_user_id.map(|id| id.value())
+        }
+    }
+
+    async fn fixture(expires_at: time::OffsetDateTime) -> Option<Fixture> {
+        let users = Arc::new(MockUserRepository::new());
+        let tokens = Arc::new(MockTokenRepository::new());
+        let events = Arc::new(MockEventPublisher::new());
+        let mut services = AppServices::new_test();
+        services.user_repository = users.clone();
+        services.token_repository = tokens.clone();
+        services.event_publisher = events.clone();
+
+        let now = time::OffsetDateTime::now_utc();
+        let user = User {
+            id: UserId::new(),
+            username: "ada".to_string(),
+            email: None,
+            telegram_user_id: None,
+            created_at: now,
+            updated_at: now,
+            is_active: true,
+            language: user::DEFAULT_LANGUAGE.to_string(),
+            timezone: user::DEFAULT_TIMEZONE.to_string(),
+            spiritual_preferences: serde_json::json!({}),
+        };
+        users.add_user(user.clone());
+        let first = RefreshToken {
+            expires_at,
+            ..RefreshToken::new(
+                user.id,
+                services.jwt_service.hash_refresh_token("first"),
+                REFRESH_TOKEN_TTL,
+            )
+        };
+        tokens.store_refresh_token(&first).await.ok()?;
+
+        let verifier = TelegramAuthVerifierImpl::new("TEST", time::Duration::hours(1)).ok()?;
+        let auth = AuthUseCaseImpl::new(
+            services,
+            "@channel".to_string(),
+            Arc::new(verifier),
+            REFRESH_TOKEN_TTL,
+        );
+        Some(Fixture {
+            auth,
+            users,
+            tokens,
+            events,
+            user,
+            first,
+        })
+    }
+
+    async fn stored(tokens: &MockTokenRepository, hash: &str) -> RefreshToken {
+        let Ok(Some(token)) = tokens.get_refresh_token(hash).await else {
+            panic!("refresh token {} not stored", hash);
+        };
+        token
+    }
+
+    fn is_invalid_linking_token<T>(result: &AppResult<T>) -> bool {
+        matches!(
+            result,
+            Err(crate::AppError::Domain(DomainError::InvalidLinkingToken))
+        )
+    }
+
+    fn is_invalid_refresh_token<T>(result: &AppResult<T>) -> bool {
+        matches!(
+            result,
+            Err(crate::AppError::Domain(DomainError::InvalidRefreshToken))
+        )
+    }
+
+    #[tokio::test]
+    async fn test_refresh_rotates_within_the_family() {
+        let in_a_week = time::OffsetDateTime::now_utc() + time::Duration::days(7);
+        let Some(f) = fixture(in_a_week).await else {
+            panic!("Failed to set up auth use case");
+        };
+
+        let Ok(pair) = f.auth.refresh_tokens("first").await else {
+            panic!("refresh with a valid token failed");
+        };
+        assert_ne!(pair.refresh_token, "first");
+        assert!(stored(&f.tokens, &f.first.token_hash).await.is_revoked);
+        let successor = stored(&f.tokens, &refresh_token_hash(&pair.refresh_token)).await;
+        assert!(successor.is_valid());
+        assert_eq!(successor.family_id, f.first.family_id);
+        assert_eq!(
+            successor.expires_at - successor.created_at,
+            REFRESH_TOKEN_TTL
+        );
+        assert!(f.events.published().is_empty());
+
+        // The successor rotates in turn
+        assert!(f.auth.refresh_tokens(&pair.refresh_token).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_reuse_revokes_the_whole_family() {
+        let in_a_week = time::OffsetDateTime::now_utc() + time::Duration::days(7);
+        let Some(f) = fixture(in_a_week).await else {
+            panic!("Failed to set up auth use case");
+        };
+        let Ok(pair) = f.auth.refresh_tokens("first").await else {
+            panic!("refresh with a valid token failed");
+        };
+
+        // The rotated token comes back: the successor is revoked as well
+        assert!(is_invalid_refresh_token(
+            &f.auth.refresh_tokens("first").await
+        ));
+        let successor = stored(&f.tokens, &refresh_token_hash(&pair.refresh_token)).await;
+        assert!(successor.is_revoked);
+        assert!(is_invalid_refresh_token(
+            &f.auth.refresh_tokens(&pair.refresh_token).await
+        ));
+
+        let published = f.events.published();
+        let Some(Event::User(UserEvent::RefreshTokenReuseDetected {
+            family_id,
+            revoked_tokens,
+            ..
+        })) = published.first()
+        else {
+            panic!("no reuse event: {:?}", published);
+        };
+        assert_eq!(*family_id, f.first.family_id);
+        assert_eq!(*revoked_tokens, 1);
+    }
+
+    #[tokio::test]
+    async fn test_expired_token_is_rejected() {
+        let yesterday = time::OffsetDateTime::now_utc() - time::Duration::days(1);
+        let Some(f) = fixture(yesterday).await else {
+            panic!("Failed to set up auth use case");
+        };
+
+        assert!(is_invalid_refresh_token(
+            &f.auth.refresh_tokens("first").await
+        ));
+        assert!(is_invalid_refresh_token(
+            &f.auth.refresh_tokens("unknown").await
+        ));
+        // Expiry is not reuse: nothing is revoked or reported
+        assert!(!stored(&f.tokens, &f.first.token_hash).await.is_revoked);
+        assert!(f.events.published().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_linking_token_links_once() {
+        let in_a_minute = time::OffsetDateTime::now_utc() + time::Duration::minutes(1);
+        let Some(f) = fixture(in_a_minute).await else {
+            panic!("Failed to set up auth use case");
+        };
+        let token = f.linking_token(in_a_minute).await;
+
+        let Ok(link) = f.auth.link_telegram_account(&token, 42).await else {
+            panic!("linking with a fresh token failed");
+        };
+        assert_eq!(link.telegram_user_id, 42);
+        assert_eq!(f.telegram_user_id().await, Some(42));
+
+        // A second /start with the same token is refused
+        assert!(is_invalid_linking_token(
+            &f.auth.link_telegram_account(&token, 43).await
+        ));
+        assert_eq!(f.telegram_user_id().await, Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_expired_linking_token_is_rejected() {
+        let now = time::OffsetDateTime::now_utc();
+        let Some(f) = fixture(now + time::Duration::days(7)).await else {
+            panic!("Failed to set up auth use case");
+        };
+        let token = f.linking_token(now - time::Duration::minutes(1)).await;
+
+        assert!(is_invalid_linking_token(
+            &f.auth.link_telegram_account(&token, 42).await
+        ));
+        assert!(is_invalid_linking_token(
+            &f.auth
+                .link_telegram_account(&uuid::Uuid::new_v4(), 42)
+                .await
+        ));
+        assert_eq!(f.telegram_user_id().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_telegram_account_linked_to_another_user_is_rejected() {
+        let in_a_minute = time::OffsetDateTime::now_utc() + time::Duration::minutes(1);
+        let Some(f) = fixture(in_a_minute).await else {
+            panic!("Failed to set up auth use case");
+        };
+        let other = User {
+            id: UserId::new(),
+            username: "grace".to_string(),
+            telegram_user_id: telegram::TelegramUserId::new(42).ok(),
+            ..f.user.clone()
+        };
+        f.users.add_user(other);
+        let token = f.linking_token(in_a_minute).await;
+
+        assert!(matches!(
+            f.auth.link_telegram_account(&token, 42).await,
+            Err(crate::AppError::Domain(DomainError::TelegramAlreadyLinked(
+                42
+            )))
+        ));
+        assert_eq!(f.telegram_user_id().await, None);
     }
 }
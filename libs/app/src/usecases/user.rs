@@ -1,6 +1,6 @@
 //! User management use cases
 
-use crate::{AppResult, AppServices, ports::UserRepository};
+use crate::{AppResult, AppServices};
 use async_trait::async_trait;
 use starscalendars_domain::*;
 
@@ -9,6 +9,9 @@ use starscalendars_domain::*;
 pub trait UserUseCase: Send + Sync {
     async fn get_user_profile(&self, user_id: &UserId) -> AppResult<Option<User>>;
     async fn update_user_profile(&self, user: &User) -> AppResult<()>;
+    /// Apply a validated merge patch and return the updated profile
+    async fn patch_user_profile(&self, user_id: &UserId, patch: &ProfilePatch) -> AppResult<User>;
+    /// Delete the account with everything that references it
     async fn delete_user_account(&self, user_id: &UserId) -> AppResult<()>;
 }
 
@@ -21,19 +24,125 @@ impl UserUseCaseImpl {
     pub fn new(services: AppServices) -> Self {
         Self { services }
     }
+
+    async fn existing_user(&self, user_id: &UserId) -> AppResult<User> {
+        self.services
+            .user_repository
+            .get_user_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::UserNotFound(format!("{:?}", user_id)).into())
+    }
+
+    /// Publish an event of a completed change; a publisher failure does not
+    /// undo the change, so it is only logged
+    fn publish(&self, event: UserEvent) {
+        if let Err(e) = self.services.event_publisher.publish(Event::User(event)) {
+            tracing::warn!("Failed to publish user event: {}", e);
+        }
+    }
 }
 
 #[async_trait]
 impl UserUseCase for UserUseCaseImpl {
     async fn get_user_profile(&self, user_id: &UserId) -> AppResult<Option<User>> {
-        self.services.user_repo.get_user_by_id(user_id).await
+        Ok(self
+            .services
+            .user_repository
+            .get_user_by_id(user_id)
+            .await?)
     }
 
     async fn update_user_profile(&self, user: &User) -> AppResult<()> {
-        self.services.user_repo.update_user(user).await
+        Ok(self.services.user_repository.update_user(user).await?)
+    }
+
+    async fn patch_user_profile(&self, user_id: &UserId, patch: &ProfilePatch) -> AppResult<User> {
+        let mut user = self.existing_user(user_id).await?;
+        let now = time::OffsetDateTime::now_utc();
+        user.apply_profile_patch(patch, now)?;
+        // Username and email uniqueness is enforced by the repository
+        self.services.user_repository.update_user(&user).await?;
+
+        self.publish(UserEvent::ProfileUpdated {
+            event_id: EventId::new(),
+            occurred_at: now,
+            user_id: user.id,
+            fields: patch.fields().into_iter().map(str::to_string).collect(),
+        });
+        Ok(user)
     }
 
     async fn delete_user_account(&self, user_id: &UserId) -> AppResult<()> {
-        self.services.user_repo.delete_user(user_id).await
+        let user = self.existing_user(user_id).await?;
+        // Tokens, sessions and notification deliveries cascade in the database
+        self.services.user_repository.delete_user(user_id).await?;
+
+        if let Some(telegram_user_id) = user.telegram_user_id {
+            // A stale subscription entry would only expire on its own
+            let _ = self
+                .services
+                .cache_service
+                .delete(&format!("subscription:{}", telegram_user_id.value()))
+                .await;
+        }
+
+        self.publish(UserEvent::AccountDeleted {
+            event_id: EventId::new(),
+            occurred_at: time::OffsetDateTime::now_utc(),
+            user_id: user.id,
+            telegram_user_id: user.telegram_user_id,
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starscalendars_infra::{MockEventPublisher, MockUserRepository};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_profile_changes_publish_events() {
+        let users = Arc::new(MockUserRepository::new());
+        let publisher = Arc::new(MockEventPublisher::new());
+        let mut services = AppServices::new_test();
+        services.user_repository = users.clone();
+        services.event_publisher = publisher.clone();
+        let use_case = UserUseCaseImpl::new(services);
+
+        let now = time::OffsetDateTime::now_utc();
+        let user = User {
+            id: UserId::new(),
+            username: "ada".to_string(),
+            email: None,
+            telegram_user_id: None,
+            created_at: now,
+            updated_at: now,
+            is_active: true,
+            language: "en".to_string(),
+            timezone: "UTC".to_string(),
+            spiritual_preferences: serde_json::json!({}),
+        };
+        users.add_user(user.clone());
+
+        let patch = ProfilePatch {
+            timezone: Some(Some("Europe/Paris".to_string())),
+            ..ProfilePatch::default()
+        };
+        let patched = use_case.patch_user_profile(&user.id, &patch).await;
+        assert!(matches!(patched, Ok(ref u) if u.timezone == "Europe/Paris"));
+        assert!(use_case.delete_user_account(&user.id).await.is_ok());
+
+        let published = publisher.published();
+        let [
+            Event::User(UserEvent::ProfileUpdated { fields, .. }),
+            Event::User(UserEvent::AccountDeleted { user_id, .. }),
+        ] = published.as_slice()
+        else {
+            panic!("unexpected events: {:?}", published);
+        };
+        assert_eq!(fields, &["timezone"]);
+        assert_eq!(user_id, &user.id);
     }
 }
@@ -0,0 +1,346 @@
+//! Apparent geocentric positions of the Sun, the Moon and the planets
+//!
+//! Apparent ecliptic coordinates of date (light-time, FK5, annual
+//! aberration, nutation), the frame of tropical astrology and of the
+//! server-side ephemeris snapshots. Times are JD TT.
+
+use crate::longitude::{moon_longitude_apparent, solar_longitude_apparent};
+use crate::state::earth_heliocentric_cartesian;
+use crate::{AU_KM, Cartesian, LIGHT_TIME_DAYS_PER_AU, ecliptic_to_cartesian, wrap_pi};
+
+/// Julian Day of J2000.0
+const J2000_JD: f64 = 2451545.0; // @allow-wasm-const
+
+/// Wrapper planet index of Pluto (see `planet_heliocentric_cartesian`)
+const PLUTO_INDEX: usize = 8; // @allow-wasm-const
+
+/// Constant of annual aberration κ (20.49552″)
+const ABERRATION_RAD: f64 = 9.936_508_497_454_117e-5; // @allow-wasm-const
+
+/// Sun, Moon and planets, in body code order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Body {
+    /// The Sun
+    Sun,
+    /// The Moon
+    Moon,
+    /// Mercury
+    Mercury,
+    /// Venus
+    Venus,
+    /// Mars
+    Mars,
+    /// Jupiter
+    Jupiter,
+    /// Saturn
+    Saturn,
+    /// Uranus
+    Uranus,
+    /// Neptune
+    Neptune,
+    /// Pluto
+    Pluto,
+}
+
+impl Body {
+    /// All bodies, in code order
+    pub const ALL: [Self; 10] = [
+        Self::Sun,
+        Self::Moon,
+        Self::Mercury,
+        Self::Venus,
+        Self::Mars,
+        Self::Jupiter,
+        Self::Saturn,
+        Self::Uranus,
+        Self::Neptune,
+        Self::Pluto,
+    ];
+
+    /// Body code (0 Sun, 1 Moon, 2 Mercury … 9 Pluto), as used by the WASM API
+    pub fn code(self) -> u8 {
+        match self {
+            Self::Sun => 0,
+            Self::Moon => 1,
+            Self::Mercury => 2,
+            Self::Venus => 3,
+            Self::Mars => 4,
+            Self::Jupiter => 5,
+            Self::Saturn => 6,
+            Self::Uranus => 7,
+            Self::Neptune => 8,
+            Self::Pluto => 9,
+        }
+    }
+
+    /// Decode a body code
+    pub fn from_code(code: u8) -> Option<Self> {
+        Self::ALL.into_iter().find(|body| body.code() == code)
+    }
+
+    /// Lowercase English name, e.g. `"jupiter"`
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Sun => "sun",
+            Self::Moon => "moon",
+            Self::Mercury => "mercury",
+            Self::Venus => "venus",
+            Self::Mars => "mars",
+            Self::Jupiter => "jupiter",
+            Self::Saturn => "saturn",
+            Self::Uranus => "uranus",
+            Self::Neptune => "neptune",
+            Self::Pluto => "pluto",
+        }
+    }
+
+    /// Parse a name as returned by `name`, ignoring ASCII case
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|body| body.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Wrapper planet index (0 Mercury … 7 Neptune, 8 Pluto; 2 is Earth)
+    fn planet_index(self) -> Option<usize> {
+        match self {
+            Self::Sun | Self::Moon => None,
+            Self::Mercury => Some(0),
+            Self::Venus => Some(1),
+            Self::Mars => Some(3),
+            Self::Jupiter => Some(4),
+            Self::Saturn => Some(5),
+            Self::Uranus => Some(6),
+            Self::Neptune => Some(7),
+            Self::Pluto => Some(PLUTO_INDEX),
+        }
+    }
+}
+
+/// Apparent geocentric ecliptic position of date
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApparentPosition {
+    /// Ecliptic longitude (rad, 0..2π)
+    pub longitude_rad: f64,
+    /// Ecliptic latitude (rad)
+    pub latitude_rad: f64,
+    /// Distance from the Earth's centre (AU)
+    pub distance_au: f64,
+}
+
+/// Heliocentric ecliptic Cartesian (AU) by wrapper planet index, mean
+/// ecliptic and equinox of date
+///
+/// Indices follow `get_planet_position` (0=Mercury … 7=Neptune) with 8=Pluto.
+/// VSOP87D is already of date; Pluto (Meeus ch. 37, J2000) is precessed so
+/// all bodies share Earth's frame.
+pub fn planet_heliocentric_cartesian(planet_index: usize, jd: f64) -> Option<Cartesian> {
+    let planet = match planet_index {
+        0 => astro::planet::Planet::Mercury,
+        1 => astro::planet::Planet::Venus,
+        2 => astro::planet::Planet::Earth,
+        3 => astro::planet::Planet::Mars,
+        4 => astro::planet::Planet::Jupiter,
+        5 => astro::planet::Planet::Saturn,
+        6 => astro::planet::Planet::Uranus,
+        7 => astro::planet::Planet::Neptune,
+        PLUTO_INDEX => {
+            let (long, lat, r) = astro::pluto::heliocent_pos(jd);
+            let (long, lat) = astro::precess::precess_ecl_coords(long, lat, J2000_JD, jd);
+            return Some(ecliptic_to_cartesian(long, lat, r));
+        }
+        _ => return None,
+    };
+    let (long, lat, r) = astro::planet::heliocent_coords(&planet, jd);
+    Some(ecliptic_to_cartesian(long, lat, r))
+}
+
+/// Apparent geocentric ecliptic `(λ, β, Δ)` of a planet by wrapper index
+///
+/// Light-time iterated with Earth fixed at `jd_tt`, FK5 correction, annual
+/// aberration (Meeus 23.2 without the e-terms, < 0.4″) and nutation. λ is
+/// not reduced to 0..2π.
+fn planet_apparent_ecliptic(planet_index: usize, jd_tt: f64) -> Option<(f64, f64, f64)> {
+    let earth = earth_heliocentric_cartesian(jd_tt);
+    let mut tau = 0.0;
+    let mut dist = 0.0;
+    let mut geo = Cartesian::new(0.0, 0.0, 0.0);
+    // Three passes converge well below a second of time
+    for _ in 0..3 {
+        let helio = planet_heliocentric_cartesian(planet_index, jd_tt - tau)?;
+        geo = Cartesian::new(helio.x - earth.x, helio.y - earth.y, helio.z - earth.z);
+        dist = (geo.x * geo.x + geo.y * geo.y + geo.z * geo.z).sqrt();
+        tau = dist * LIGHT_TIME_DAYS_PER_AU;
+    }
+    let long = geo.y.atan2(geo.x);
+    let lat = geo.z.atan2(geo.x.hypot(geo.y));
+    let (long, lat) = astro::planet::ecl_coords_to_FK5(jd_tt, long, lat);
+
+    // True longitude of the Sun, opposite to the heliocentric Earth
+    let sun_long = (-earth.y).atan2(-earth.x);
+    let aberration = -ABERRATION_RAD * (sun_long - long).cos() / lat.cos();
+    let (nut_long, _nut_oblq) = astro::nutation::nutation(jd_tt);
+    Some((long + aberration + nut_long, lat, dist))
+}
+
+/// Apparent geocentric ecliptic longitude of a body (rad, 0..2π)
+///
+/// Sun: VSOP87 + FK5 + aberration + nutation; Moon: ELP-2000/82 + nutation
+/// (`lunar::geocent_ecl_pos`); planets: see `planet_apparent_ecliptic`.
+pub fn apparent_longitude(body: Body, jd_tt: f64) -> Option<f64> {
+    let long = match body {
+        Body::Sun => solar_longitude_apparent(jd_tt),
+        Body::Moon => moon_longitude_apparent(jd_tt),
+        planet => planet_apparent_ecliptic(planet.planet_index()?, jd_tt)?.0,
+    };
+    Some(astro::angle::limit_to_two_PI(long))
+}
+
+/// Apparent geocentric ecliptic position of a body; slower than
+/// `apparent_longitude` for the Sun and the Moon
+pub fn apparent_position(body: Body, jd_tt: f64) -> Option<ApparentPosition> {
+    let (latitude_rad, distance_au) = match body {
+        Body::Sun => {
+            let (sun_ecl, sun_dist_au) = astro::sun::geocent_ecl_pos(jd_tt);
+            let (_long_fk5, lat_fk5) =
+                astro::sun::ecl_coords_to_FK5(jd_tt, sun_ecl.long, sun_ecl.lat);
+            // The FK5 correction returns β reduced to 0..2π
+            (wrap_pi(lat_fk5), sun_dist_au)
+        }
+        Body::Moon => {
+            let (moon_ecl, moon_dist_km) = astro::lunar::geocent_ecl_pos(jd_tt);
+            (moon_ecl.lat, moon_dist_km / AU_KM)
+        }
+        planet => {
+            let (long, lat, dist) = planet_apparent_ecliptic(planet.planet_index()?, jd_tt)?;
+            return Some(ApparentPosition {
+                longitude_rad: astro::angle::limit_to_two_PI(long),
+                latitude_rad: lat,
+                distance_au: dist,
+            });
+        }
+    };
+    Some(ApparentPosition {
+        longitude_rad: apparent_longitude(body, jd_tt)?,
+        latitude_rad,
+        distance_au,
+    })
+}
+
+/// Apparent geocentric equatorial coordinates of the Sun `(α, δ)` in radians
+///
+/// Same reduction as the solar zenith: FK5 + annual aberration + nutation,
+/// true obliquity. `jd_tt` is a Julian Ephemeris Day.
+pub fn sun_apparent_equatorial(jd_tt: f64) -> (f64, f64) {
+    let (sun_ecl, sun_dist_au) = astro::sun::geocent_ecl_pos(jd_tt);
+    let (long_fk5, lat_fk5) = astro::sun::ecl_coords_to_FK5(jd_tt, sun_ecl.long, sun_ecl.lat);
+    let (nut_long, nut_oblq) = astro::nutation::nutation(jd_tt);
+    let true_oblq = astro::ecliptic::mn_oblq_IAU(jd_tt) + nut_oblq;
+    let long = long_fk5 + astro::aberr::sol_aberr(sun_dist_au) + nut_long;
+    (
+        astro::angle::limit_to_two_PI(astro::coords::asc_frm_ecl(long, lat_fk5, true_oblq)),
+        astro::coords::dec_frm_ecl(long, lat_fk5, true_oblq),
+    )
+}
+
+/// Apparent geocentric equatorial coordinates of the Moon `(α, δ)` in radians
+///
+/// ELP-2000/82 (Meeus ch. 47) + nutation, true obliquity; no parallax.
+/// `jd_tt` is a Julian Ephemeris Day.
+pub fn moon_apparent_equatorial(jd_tt: f64) -> (f64, f64) {
+    let (moon_ecl, _moon_dist_km) = astro::lunar::geocent_ecl_pos(jd_tt);
+    let (nut_long, nut_oblq) = astro::nutation::nutation(jd_tt);
+    let true_oblq = astro::ecliptic::mn_oblq_IAU(jd_tt) + nut_oblq;
+    let long = moon_ecl.long + nut_long;
+    (
+        astro::angle::limit_to_two_PI(astro::coords::asc_frm_ecl(long, moon_ecl.lat, true_oblq)),
+        astro::coords::dec_frm_ecl(long, moon_ecl.lat, true_oblq),
+    )
+}
+
+/// Ecliptic to equatorial `(α, δ)` with the mean obliquity, or the true
+/// obliquity when `apply_nutation` is set
+#[inline]
+pub fn ecliptic_to_equatorial(
+    ecl_long_rad: f64,
+    ecl_lat_rad: f64,
+    julian_day: f64,
+    apply_nutation: bool,
+) -> (f64, f64) {
+    let obliquity = if apply_nutation {
+        let mean_oblq = astro::ecliptic::mn_oblq_IAU(julian_day);
+        let (_nut_long, nut_oblq) = astro::nutation::nutation(julian_day);
+        mean_oblq + nut_oblq
+    } else {
+        astro::ecliptic::mn_oblq_IAU(julian_day)
+    };
+    let right_ascension = astro::coords::asc_frm_ecl(ecl_long_rad, ecl_lat_rad, obliquity);
+    let declination = astro::coords::dec_frm_ecl(ecl_long_rad, ecl_lat_rad, obliquity);
+    (right_ascension, declination)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apparent_positions_meeus() {
+        // Meeus Example 33.a: Venus 1992 Dec 20.0 TD, λ = 313.08102°,
+        // β = -2.08474°, Δ = 0.910947 AU
+        let venus = apparent_position(Body::Venus, 2448976.5);
+        assert!(
+            matches!(venus, Some(p) if (p.longitude_rad.to_degrees() - 313.08102).abs() < 0.002
+                && (p.latitude_rad.to_degrees() + 2.08474).abs() < 0.002
+                && (p.distance_au - 0.910947).abs() < 1.0e-4),
+            "{:?}",
+            venus
+        );
+        // Meeus Example 47.a: Moon 1992 Apr 12.0 TD, λ = 133.167265°, Δ = 368409.7 km
+        let moon = apparent_position(Body::Moon, 2448724.5);
+        assert!(
+            matches!(moon, Some(p) if (p.longitude_rad.to_degrees() - 133.167265).abs() < 1.0e-3
+                && (p.distance_au * AU_KM - 368409.7).abs() < 1.0)
+        );
+        // Meeus Example 25.b: Sun 1992 Oct 13.0 TD, λ_app = 199.907347°, R = 0.99760775 AU
+        let sun = apparent_position(Body::Sun, 2448908.5);
+        assert!(
+            matches!(sun, Some(p) if (p.longitude_rad.to_degrees() - 199.907347).abs() < 0.005
+                && p.latitude_rad.to_degrees().abs() < 1.0e-3
+                && (p.distance_au - 0.99760775).abs() < 1.0e-5),
+            "{:?}",
+            sun
+        );
+        assert_eq!(
+            apparent_longitude(Body::Moon, 2448724.5),
+            moon.map(|p| p.longitude_rad)
+        );
+    }
+
+    #[test]
+    fn test_body_codes_and_names() {
+        for body in Body::ALL {
+            assert_eq!(Body::from_code(body.code()), Some(body));
+            assert_eq!(Body::from_name(body.name()), Some(body));
+        }
+        assert_eq!(Body::from_name("Jupiter"), Some(Body::Jupiter));
+        assert_eq!(Body::from_name("earth"), None);
+        assert_eq!(Body::from_code(10), None);
+    }
+
+    #[test]
+    fn test_ecliptic_to_equatorial_conversion() {
+        let (right_ascension, declination) = ecliptic_to_equatorial(0.0, 0.0, 2451545.0, false);
+        // Right ascension should be in [0, 2π] range
+        assert!(
+            (0.0..=std::f64::consts::TAU).contains(&right_ascension),
+            "Right ascension out of range: {}",
+            right_ascension
+        );
+        // Declination should be in [-π/2, π/2] range
+        assert!(
+            declination.abs() <= std::f64::consts::FRAC_PI_2,
+            "Declination out of range: {}",
+            declination
+        );
+    }
+}
@@ -0,0 +1,86 @@
+// Star rows included by stars.rs: the 80 named stars the app labels, to V ≤ 3.39.
+// Regenerate from the Yale Bright Star Catalogue (BSC5) with
+//     scripts/gen-bright-stars.py --named bsc5.dat > libs/astro-core/src/bright_stars.rs
+// (without `--named` the script writes the full naked-eye catalogue to V ≤ 6).
+[
+    star!(15, "Alpheratz", 0.0, 8.0, 23.260, 1.0, 29.0, 5.0, 25.55, 135.68, -162.95, 2.06),
+    star!(21, "Caph", 0.0, 9.0, 10.685, 1.0, 59.0, 8.0, 59.21, 523.39, -180.42, 2.27),
+    star!(39, "Algenib", 0.0, 13.0, 14.151, 1.0, 15.0, 11.0, 0.94, 4.70, -8.24, 2.83),
+    star!(168, "Schedar", 0.0, 40.0, 30.441, 1.0, 56.0, 32.0, 14.39, 50.36, -32.17, 2.24),
+    star!(188, "Diphda", 0.0, 43.0, 35.371, -1.0, 17.0, 59.0, 11.78, 232.79, 32.71, 2.04),
+    star!(264, "Navi", 0.0, 56.0, 42.531, 1.0, 60.0, 43.0, 0.27, 25.65, -3.82, 2.47),
+    star!(337, "Mirach", 1.0, 9.0, 43.924, 1.0, 35.0, 37.0, 14.01, 175.59, -112.23, 2.05),
+    star!(472, "Achernar", 1.0, 37.0, 42.845, -1.0, 57.0, 14.0, 12.31, 88.02, -40.08, 0.46),
+    star!(603, "Almach", 2.0, 3.0, 53.953, 1.0, 42.0, 19.0, 47.01, 43.08, -50.85, 2.10),
+    star!(617, "Hamal", 2.0, 7.0, 10.406, 1.0, 23.0, 27.0, 44.70, 190.73, -145.77, 2.00),
+    star!(424, "Polaris", 2.0, 31.0, 49.095, 1.0, 89.0, 15.0, 50.79, 44.48, -11.85, 1.98),
+    star!(911, "Menkar", 3.0, 2.0, 16.773, 1.0, 4.0, 5.0, 23.06, -11.81, -78.76, 2.53),
+    star!(936, "Algol", 3.0, 8.0, 10.132, 1.0, 40.0, 57.0, 20.33, 2.39, -1.44, 2.12),
+    star!(1017, "Mirfak", 3.0, 24.0, 19.370, 1.0, 49.0, 51.0, 40.25, 24.11, -26.01, 1.79),
+    star!(1165, "Alcyone", 3.0, 47.0, 29.077, 1.0, 24.0, 6.0, 18.49, 19.35, -43.11, 2.87),
+    star!(1457, "Aldebaran", 4.0, 35.0, 55.239, 1.0, 16.0, 30.0, 33.49, 63.45, -188.94, 0.85),
+    star!(1713, "Rigel", 5.0, 14.0, 32.272, -1.0, 8.0, 12.0, 5.90, 1.31, 0.50, 0.13),
+    star!(1708, "Capella", 5.0, 16.0, 41.359, 1.0, 45.0, 59.0, 52.77, 75.52, -427.13, 0.08),
+    star!(1790, "Bellatrix", 5.0, 25.0, 7.863, 1.0, 6.0, 20.0, 58.93, -8.11, -12.88, 1.64),
+    star!(1791, "Elnath", 5.0, 26.0, 17.513, 1.0, 28.0, 36.0, 26.83, 22.76, -173.58, 1.65),
+    star!(1852, "Mintaka", 5.0, 32.0, 0.400, -1.0, 0.0, 17.0, 56.74, 0.64, -0.69, 2.23),
+    star!(1879, "Meissa", 5.0, 35.0, 8.277, 1.0, 9.0, 56.0, 2.96, -0.34, -2.94, 3.39),
+    star!(1899, "Hatysa", 5.0, 35.0, 25.982, -1.0, 5.0, 54.0, 35.64, 1.42, -0.48, 2.77),
+    star!(1903, "Alnilam", 5.0, 36.0, 12.813, -1.0, 1.0, 12.0, 6.91, 1.44, -0.78, 1.69),
+    star!(1948, "Alnitak", 5.0, 40.0, 45.527, -1.0, 1.0, 56.0, 33.26, 3.19, 2.03, 1.77),
+    star!(2004, "Saiph", 5.0, 47.0, 45.389, -1.0, 9.0, 40.0, 10.58, 1.55, -1.20, 2.07),
+    star!(2061, "Betelgeuse", 5.0, 55.0, 10.305, 1.0, 7.0, 24.0, 25.43, 27.54, 11.30, 0.50),
+    star!(2088, "Menkalinan", 5.0, 59.0, 31.723, 1.0, 44.0, 56.0, 50.76, -56.44, -0.95, 1.90),
+    star!(2294, "Mirzam", 6.0, 22.0, 41.985, -1.0, 17.0, 57.0, 21.31, -3.45, -0.47, 1.98),
+    star!(2326, "Canopus", 6.0, 23.0, 57.110, -1.0, 52.0, 41.0, 44.38, 19.93, 23.24, -0.74),
+    star!(2421, "Alhena", 6.0, 37.0, 42.711, 1.0, 16.0, 23.0, 57.41, -2.04, -66.92, 1.93),
+    star!(2491, "Sirius", 6.0, 45.0, 8.917, -1.0, 16.0, 42.0, 58.02, -546.01, -1223.07, -1.46),
+    star!(2618, "Adhara", 6.0, 58.0, 37.548, -1.0, 28.0, 58.0, 19.51, 3.24, 1.33, 1.50),
+    star!(2693, "Wezen", 7.0, 8.0, 23.485, -1.0, 26.0, 23.0, 35.52, -2.75, 3.33, 1.84),
+    star!(2891, "Castor", 7.0, 34.0, 35.863, 1.0, 31.0, 53.0, 17.82, -191.45, -145.19, 1.58),
+    star!(2943, "Procyon", 7.0, 39.0, 18.119, 1.0, 5.0, 13.0, 29.96, -714.59, -1036.80, 0.34),
+    star!(2990, "Pollux", 7.0, 45.0, 18.950, 1.0, 28.0, 1.0, 34.32, -626.55, -45.80, 1.14),
+    star!(3307, "Avior", 8.0, 22.0, 30.836, -1.0, 59.0, 30.0, 34.14, -25.52, 22.72, 1.86),
+    star!(3685, "Miaplacidus", 9.0, 13.0, 11.977, -1.0, 69.0, 43.0, 1.95, -157.66, 108.91, 1.68),
+    star!(3748, "Alphard", 9.0, 27.0, 35.243, -1.0, 8.0, 39.0, 30.96, -14.49, 33.25, 1.98),
+    star!(3982, "Regulus", 10.0, 8.0, 22.311, 1.0, 11.0, 58.0, 1.95, -248.73, 5.59, 1.35),
+    star!(4295, "Merak", 11.0, 1.0, 50.476, 1.0, 56.0, 22.0, 56.73, 81.66, 33.74, 2.37),
+    star!(4301, "Dubhe", 11.0, 3.0, 43.672, 1.0, 61.0, 45.0, 3.72, -136.46, -35.25, 1.79),
+    star!(4534, "Denebola", 11.0, 49.0, 3.578, 1.0, 14.0, 34.0, 19.41, -499.02, -113.78, 2.14),
+    star!(4554, "Phecda", 11.0, 53.0, 49.847, 1.0, 53.0, 41.0, 41.14, 107.76, 11.16, 2.44),
+    star!(4660, "Megrez", 12.0, 15.0, 25.561, 1.0, 57.0, 1.0, 57.42, 103.56, 7.81, 3.31),
+    star!(4730, "Acrux", 12.0, 26.0, 35.896, -1.0, 63.0, 5.0, 56.73, -35.83, -14.86, 0.77),
+    star!(4763, "Gacrux", 12.0, 31.0, 9.960, -1.0, 57.0, 6.0, 47.57, 28.23, -265.08, 1.63),
+    star!(4853, "Mimosa", 12.0, 47.0, 43.269, -1.0, 59.0, 41.0, 19.58, -42.97, -16.18, 1.25),
+    star!(4905, "Alioth", 12.0, 54.0, 1.750, 1.0, 55.0, 57.0, 35.36, 111.74, -8.99, 1.77),
+    star!(5054, "Mizar", 13.0, 23.0, 55.540, 1.0, 54.0, 55.0, 31.27, 121.23, -22.01, 2.23),
+    star!(5056, "Spica", 13.0, 25.0, 11.579, -1.0, 11.0, 9.0, 40.75, -42.35, -30.67, 1.04),
+    star!(5191, "Alkaid", 13.0, 47.0, 32.438, 1.0, 49.0, 18.0, 47.76, -121.23, -15.56, 1.86),
+    star!(5267, "Hadar", 14.0, 3.0, 49.405, -1.0, 60.0, 22.0, 22.93, -33.27, -23.16, 0.61),
+    star!(5340, "Arcturus", 14.0, 15.0, 39.672, 1.0, 19.0, 10.0, 56.67, -1093.39, -2000.06, -0.05),
+    star!(5459, "Rigil Kentaurus", 14.0, 39.0, 36.494, -1.0, 60.0, 50.0, 2.37, -3679.25, 473.67, -0.01),
+    star!(5506, "Izar", 14.0, 44.0, 59.218, 1.0, 27.0, 4.0, 27.21, -50.65, 20.00, 2.37),
+    star!(5563, "Kochab", 14.0, 50.0, 42.326, 1.0, 74.0, 9.0, 19.81, -32.29, 11.91, 2.08),
+    star!(5531, "Zubenelgenubi", 14.0, 50.0, 52.713, -1.0, 16.0, 2.0, 30.40, -105.69, -68.40, 2.75),
+    star!(5793, "Alphecca", 15.0, 34.0, 41.268, 1.0, 26.0, 42.0, 52.90, 120.38, -89.44, 2.23),
+    star!(5953, "Dschubba", 16.0, 0.0, 20.005, -1.0, 22.0, 37.0, 18.14, -8.67, -36.90, 2.29),
+    star!(6134, "Antares", 16.0, 29.0, 24.460, -1.0, 26.0, 25.0, 55.21, -12.11, -23.30, 1.09),
+    star!(6217, "Atria", 16.0, 48.0, 39.895, -1.0, 69.0, 1.0, 39.76, 17.99, -31.58, 1.91),
+    star!(6527, "Shaula", 17.0, 33.0, 36.520, -1.0, 37.0, 6.0, 13.76, -8.53, -30.80, 1.62),
+    star!(6556, "Rasalhague", 17.0, 34.0, 56.069, 1.0, 12.0, 33.0, 36.13, 110.08, -222.61, 2.08),
+    star!(6553, "Sargas", 17.0, 37.0, 19.129, -1.0, 42.0, 59.0, 52.18, 6.06, -0.95, 1.87),
+    star!(6705, "Eltanin", 17.0, 56.0, 36.370, 1.0, 51.0, 29.0, 20.02, -8.52, -23.05, 2.24),
+    star!(6879, "Kaus Australis", 18.0, 24.0, 10.318, -1.0, 34.0, 23.0, 4.62, -39.61, -124.05, 1.85),
+    star!(7001, "Vega", 18.0, 36.0, 56.336, 1.0, 38.0, 47.0, 1.28, 200.94, 286.23, 0.03),
+    star!(7121, "Nunki", 18.0, 55.0, 15.926, -1.0, 26.0, 17.0, 48.20, 13.87, -52.65, 2.05),
+    star!(7417, "Albireo", 19.0, 30.0, 43.286, 1.0, 27.0, 57.0, 34.85, -7.09, -5.63, 3.08),
+    star!(7557, "Altair", 19.0, 50.0, 46.999, 1.0, 8.0, 52.0, 5.96, 536.23, 385.29, 0.77),
+    star!(7796, "Sadr", 20.0, 22.0, 13.702, 1.0, 40.0, 15.0, 24.04, 2.43, -0.93, 2.23),
+    star!(7790, "Peacock", 20.0, 25.0, 38.858, -1.0, 56.0, 44.0, 6.32, 7.71, -86.15, 1.94),
+    star!(7924, "Deneb", 20.0, 41.0, 25.915, 1.0, 45.0, 16.0, 49.22, 2.01, 1.85, 1.25),
+    star!(8308, "Enif", 21.0, 44.0, 11.156, 1.0, 9.0, 52.0, 30.03, 30.02, 1.38, 2.38),
+    star!(8425, "Alnair", 22.0, 8.0, 13.985, -1.0, 46.0, 57.0, 39.51, 127.60, -147.91, 1.74),
+    star!(8728, "Fomalhaut", 22.0, 57.0, 39.047, -1.0, 29.0, 37.0, 20.05, 328.95, -164.67, 1.16),
+    star!(8775, "Scheat", 23.0, 3.0, 46.458, 1.0, 28.0, 4.0, 58.03, 187.76, 137.61, 2.42),
+    star!(8781, "Markab", 23.0, 4.0, 45.653, 1.0, 15.0, 12.0, 18.96, 61.10, -42.56, 2.49),
+]
@@ -0,0 +1,125 @@
+//! Principal Moon phases, equinoxes and solstices in a date range
+//!
+//! The backend notification scheduler precomputes the `spiritual_events`
+//! table with it. Event types are the `SpiritualEvent` constants of the
+//! domain layer.
+
+use starscalendars_domain::spiritual::SpiritualEvent;
+
+use crate::JulianDay;
+use crate::longitude::{next_solar_longitude_tt, solar_longitude_apparent};
+use crate::moon_phase::next_principal_phase_tt;
+use crate::timescales::{tt_to_utc_jd, utc_to_tt_jd};
+
+/// Longest range accepted by `calendar_events`, in days
+pub const MAX_RANGE_DAYS: f64 = 400.0; // @allow-wasm-const
+
+/// Step past a found event before searching the next one of the same kind,
+/// in days: well below the synodic month and the tropical year
+const REARM_DAYS: f64 = 1.0; // @allow-wasm-const
+
+/// An event found by `calendar_events`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalendarEvent {
+    /// `SpiritualEvent` event type, e.g. `SpiritualEvent::FULL_MOON`
+    pub event_type: &'static str,
+    /// Julian Day (UTC) of the event
+    pub jd_utc: f64,
+}
+
+/// Principal Moon phases, equinoxes and solstices in `[jd_utc_start, jd_utc_end)`,
+/// earliest first
+///
+/// Returns `None` on an invalid or empty range, a range longer than
+/// `MAX_RANGE_DAYS`, or when the ephemeris fails.
+pub fn calendar_events(jd_utc_start: f64, jd_utc_end: f64) -> Option<Vec<CalendarEvent>> {
+    let start = JulianDay::new(jd_utc_start).ok()?.as_f64();
+    let end = JulianDay::new(jd_utc_end).ok()?.as_f64();
+    if end <= start || end - start > MAX_RANGE_DAYS {
+        return None;
+    }
+    let (start_tt, end_tt) = (utc_to_tt_jd(start), utc_to_tt_jd(end));
+
+    let mut events = Vec::with_capacity(64);
+    for (quarter, event_type) in (0..4u8).zip(SpiritualEvent::MOON_PHASES) {
+        collect(&mut events, event_type, start_tt, end_tt, |t| {
+            next_principal_phase_tt(t, quarter)
+        })?;
+    }
+    for (season, event_type) in (0..4u8).zip(SpiritualEvent::SEASONS) {
+        let target = f64::from(season) * std::f64::consts::FRAC_PI_2;
+        collect(&mut events, event_type, start_tt, end_tt, |t| {
+            next_solar_longitude_tt(solar_longitude_apparent, t, target)
+        })?;
+    }
+
+    events.sort_by(|a, b| a.jd_utc.total_cmp(&b.jd_utc));
+    Some(events)
+}
+
+/// Push every instant of `next_tt` in `[start_tt, end_tt)`
+fn collect<F: Fn(f64) -> f64>(
+    events: &mut Vec<CalendarEvent>,
+    event_type: &'static str,
+    start_tt: f64,
+    end_tt: f64,
+    next_tt: F,
+) -> Option<()> {
+    let mut t = start_tt;
+    loop {
+        let event_tt = next_tt(t);
+        if !event_tt.is_finite() {
+            return None;
+        }
+        if event_tt >= end_tt {
+            return Some(());
+        }
+        if event_tt >= start_tt {
+            events.push(CalendarEvent {
+                event_type,
+                jd_utc: tt_to_utc_jd(event_tt),
+            });
+        }
+        t = event_tt.max(t) + REARM_DAYS;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2025-01-01 00:00 UTC
+    const JAN_1_2025: f64 = 2460676.5;
+
+    #[test]
+    fn test_calendar_events_2025() {
+        let events = calendar_events(JAN_1_2025, JAN_1_2025 + 90.0);
+        let Some(events) = events else {
+            panic!("no calendar events in early 2025");
+        };
+
+        assert!(events.windows(2).all(|w| w[0].jd_utc <= w[1].jd_utc));
+        let count = |event_type: &str| events.iter().filter(|e| e.event_type == event_type).count();
+        assert_eq!(count(SpiritualEvent::NEW_MOON), 3);
+        assert_eq!(count(SpiritualEvent::FULL_MOON), 3);
+        assert_eq!(count(SpiritualEvent::MARCH_EQUINOX), 1);
+        assert_eq!(count(SpiritualEvent::JUNE_SOLSTICE), 0);
+
+        // 2025-01-29 12:36 UTC new moon, 2025-03-20 09:01 UTC equinox
+        let near = |event_type: &str, jd: f64| {
+            events
+                .iter()
+                .any(|e| e.event_type == event_type && (e.jd_utc - jd).abs() < 2.0 / 1440.0)
+        };
+        assert!(near(SpiritualEvent::NEW_MOON, 2460705.025));
+        assert!(near(SpiritualEvent::MARCH_EQUINOX, 2460754.876));
+    }
+
+    #[test]
+    fn test_calendar_events_invalid_range() {
+        assert!(calendar_events(JAN_1_2025, JAN_1_2025).is_none());
+        assert!(calendar_events(JAN_1_2025, JAN_1_2025 - 1.0).is_none());
+        assert!(calendar_events(JAN_1_2025, JAN_1_2025 + MAX_RANGE_DAYS + 1.0).is_none());
+        assert!(calendar_events(f64::NAN, JAN_1_2025).is_none());
+    }
+}
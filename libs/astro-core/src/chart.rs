@@ -0,0 +1,104 @@
+//! Astrological chart for an instant and place
+//!
+//! Angles, house cusps and the apparent longitudes and speeds of the Sun,
+//! Moon and planets in one flat layout, in the tropical or Lahiri sidereal
+//! zodiac. Used for natal charts and for the return charts of `returns`.
+
+use crate::houses::{HouseSystem, chart_angles, house_cusps};
+use crate::timescales::utc_to_tt_jd;
+use crate::wrap_pi;
+use crate::zodiac::{BODY_COUNT, Zodiac, apparent_longitude, to_zodiac};
+
+/// Chart layout length
+pub const CHART_LEN: usize = 3 + 12 + 2 * 10;
+
+/// Offset of the house cusps in the chart layout
+pub const CHART_CUSPS: usize = 3;
+
+/// Offset of the body longitudes in the chart layout
+pub const CHART_LONGITUDES: usize = CHART_CUSPS + 12;
+
+/// Offset of the body speeds in the chart layout
+pub const CHART_SPEEDS: usize = CHART_LONGITUDES + 10;
+
+/// Half-step of the speed differencing, in days (~14 min)
+const SPEED_STEP_DAYS: f64 = 0.01; // @allow-wasm-const
+
+/// Chart at `jd_utc`
+///
+/// Layout [35 f64]:
+/// - 0: JD UTC
+/// - 1: ascendant, 2: midheaven (rad, 0..2π)
+/// - 3..=14: cusps of houses 1 … 12
+/// - 15..=24: longitudes by body code (0 Sun, 1 Moon, 2 Mercury … 9 Pluto)
+/// - 25..=34: speeds in longitude by body code (rad/day, negative = retrograde)
+///
+/// `None` when Placidus is undefined at the latitude.
+pub fn cast_chart(
+    jd_utc: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    system: HouseSystem,
+    zodiac: Zodiac,
+) -> Option<[f64; CHART_LEN]> {
+    let jd_tt = utc_to_tt_jd(jd_utc);
+    let angles = chart_angles(jd_utc, lat_rad, lon_east_rad);
+    let cusps = house_cusps(&angles, system)?;
+
+    let mut out = [0.0; CHART_LEN];
+    out[0] = jd_utc;
+    out[1] = to_zodiac(angles.ascendant, zodiac, jd_tt);
+    out[2] = to_zodiac(angles.midheaven, zodiac, jd_tt);
+    for (slot, cusp) in out[CHART_CUSPS..CHART_LONGITUDES].iter_mut().zip(cusps) {
+        *slot = to_zodiac(cusp, zodiac, jd_tt);
+    }
+    for body in 0..BODY_COUNT {
+        let i = usize::from(body);
+        let long = apparent_longitude(body, jd_tt)?;
+        let ahead = apparent_longitude(body, jd_tt + SPEED_STEP_DAYS)?;
+        let behind = apparent_longitude(body, jd_tt - SPEED_STEP_DAYS)?;
+        out[CHART_LONGITUDES + i] = to_zodiac(long, zodiac, jd_tt);
+        out[CHART_SPEEDS + i] = wrap_pi(ahead - behind) / (2.0 * SPEED_STEP_DAYS);
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chart_layout() {
+        // London, 2025-01-05 12:00 UTC
+        let (lat, lon) = (51.5_f64.to_radians(), -0.13_f64.to_radians());
+        let chart = cast_chart(2460681.0, lat, lon, HouseSystem::Placidus, Zodiac::Tropical);
+        let Some(chart) = chart else {
+            panic!("no chart cast");
+        };
+        assert_eq!(chart[0], 2460681.0);
+        assert_eq!(chart[1], chart[CHART_CUSPS]);
+        assert_eq!(chart[2], chart[CHART_CUSPS + 9]);
+        // Sun in Capricorn (~285°), moving ~1.02°/day
+        let sun = chart[CHART_LONGITUDES].to_degrees();
+        assert!((284.0..286.0).contains(&sun), "{}", sun);
+        let sun_speed = chart[CHART_SPEEDS].to_degrees();
+        assert!((sun_speed - 1.019).abs() < 0.005, "{}", sun_speed);
+        // Mars retrograde from 2024-12-06 to 2025-02-24
+        assert!(chart[CHART_SPEEDS + 4] < 0.0);
+        assert!(chart[CHART_SPEEDS + 1].to_degrees() > 11.0);
+    }
+
+    #[test]
+    fn test_sidereal_chart_shifts_everything() {
+        let (lat, lon) = (0.9, 0.0);
+        let tropical = cast_chart(2460681.0, lat, lon, HouseSystem::Equal, Zodiac::Tropical);
+        let sidereal = cast_chart(2460681.0, lat, lon, HouseSystem::Equal, Zodiac::Sidereal);
+        let (Some(t), Some(s)) = (tropical, sidereal) else {
+            panic!("no tropical or sidereal chart");
+        };
+        for i in 1..CHART_SPEEDS {
+            let shift = wrap_pi(t[i] - s[i]).to_degrees();
+            assert!((shift - 24.2).abs() < 0.02, "slot {} shift {}", i, shift);
+        }
+    }
+}
@@ -0,0 +1,335 @@
+//! Heliacal rising and setting (first and last visibility)
+//!
+//! Predicts the first and last days on which a bright star or a naked-eye
+//! planet can be seen in morning or evening twilight, for an observer. Used
+//! by calendars keyed to heliacal events (Sothic cycle, Matariki/Pleiades).
+//!
+//! ## Visibility model (arcus visionis)
+//! On a given morning the body counts as visible if it is above the apparent
+//! horizon (refraction included) at the moment the Sun's centre rises through
+//! the depression `-arcus visionis`; evenings use the setting Sun. The arcus
+//! visionis defaults to a linear fit of the classical values (Ptolemy,
+//! Schoch) against visual magnitude — about 5° for Venus, 8.5° for Sirius,
+//! 12° for first-magnitude stars — and can be overridden per call.
+//!
+//! ## Events
+//! - 0 `MorningFirst`: heliacal rising, first morning visibility
+//! - 1 `EveningLast`: heliacal setting, last evening visibility
+//! - 2 `EveningFirst`: first evening visibility (planets after conjunction)
+//! - 3 `MorningLast`: last morning visibility
+//!
+//! Days on which the Sun never reaches the depression (bright summer nights)
+//! count as invisible; under polar night the body only needs to be up.
+
+use crate::horizon::{
+    Crossing, altitude, apparent_horizon_altitude, local_noon, sun_altitude_crossing, sun_place_utc,
+};
+use crate::stars::{CatalogStar, star_apparent_place};
+use crate::timescales::utc_to_tt_jd;
+
+/// Search horizon in days; covers the synodic period of Mars (~780 d)
+const HELIACAL_SEARCH_DAYS: u32 = 800; // @allow-wasm-const
+
+/// Smallest arcus visionis produced by the magnitude fit (deg)
+const ARCUS_VISIONIS_MIN_DEG: f64 = 4.5; // @allow-wasm-const
+/// Largest arcus visionis produced by the magnitude fit (deg)
+const ARCUS_VISIONIS_MAX_DEG: f64 = 18.0; // @allow-wasm-const
+
+/// Heliacal event kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeliacalEvent {
+    /// Heliacal rising: first morning visibility
+    MorningFirst,
+    /// Heliacal setting: last evening visibility
+    EveningLast,
+    /// First evening visibility
+    EveningFirst,
+    /// Last morning visibility
+    MorningLast,
+}
+
+impl HeliacalEvent {
+    /// Decode the WASM event code (0..3)
+    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(Self::MorningFirst),
+            1 => Some(Self::EveningLast),
+            2 => Some(Self::EveningFirst),
+            3 => Some(Self::MorningLast),
+            _ => None,
+        }
+    }
+
+    /// Twilight the event is observed in
+    fn twilight(self) -> Crossing {
+        match self {
+            Self::MorningFirst | Self::MorningLast => Crossing::Rising,
+            Self::EveningFirst | Self::EveningLast => Crossing::Setting,
+        }
+    }
+
+    /// Whether the event starts a period of visibility
+    fn is_first(self) -> bool {
+        matches!(self, Self::MorningFirst | Self::EveningFirst)
+    }
+}
+
+/// Body whose visibility is predicted
+#[derive(Debug, Clone, Copy)]
+pub enum HeliacalBody {
+    /// Catalog star
+    Star(&'static CatalogStar),
+    /// Naked-eye planet by wrapper index (0 Mercury, 1 Venus, 3 Mars,
+    /// 4 Jupiter, 5 Saturn)
+    Planet(usize),
+}
+
+impl HeliacalBody {
+    /// Planet body if the index is a naked-eye planet
+    pub fn planet(planet_index: usize) -> Option<Self> {
+        matches!(planet_index, 0 | 1 | 3 | 4 | 5).then_some(Self::Planet(planet_index))
+    }
+
+    /// Apparent `(α, δ)` and visual magnitude at a JD UTC
+    fn place_and_magnitude(&self, jd_utc: f64) -> Option<((f64, f64), f64)> {
+        let jd_tt = utc_to_tt_jd(jd_utc);
+        if !jd_tt.is_finite() {
+            return None;
+        }
+        match *self {
+            Self::Star(star) => Some((star_apparent_place(star, jd_tt), star.vmag)),
+            Self::Planet(index) => planet_place_and_magnitude(index, jd_tt),
+        }
+    }
+}
+
+/// Apparent place (light-time, FK5, nutation; aberration neglected) and
+/// magnitude (Astronomical Almanac 1984) of a naked-eye planet
+fn planet_place_and_magnitude(planet_index: usize, jd_tt: f64) -> Option<((f64, f64), f64)> {
+    use astro::planet::Planet;
+    let planet = match planet_index {
+        0 => Planet::Mercury,
+        1 => Planet::Venus,
+        3 => Planet::Mars,
+        4 => Planet::Jupiter,
+        5 => Planet::Saturn,
+        _ => return None,
+    };
+
+    let (ecl, delta) = astro::planet::geocent_apprnt_ecl_coords(&planet, jd_tt);
+    let (long_fk5, lat_fk5) = astro::planet::ecl_coords_to_FK5(jd_tt, ecl.long, ecl.lat);
+    let (nut_long, nut_oblq) = astro::nutation::nutation(jd_tt);
+    let true_oblq = astro::ecliptic::mn_oblq_IAU(jd_tt) + nut_oblq;
+    let long = long_fk5 + nut_long;
+    let place = (
+        astro::angle::limit_to_two_PI(astro::coords::asc_frm_ecl(long, lat_fk5, true_oblq)),
+        astro::coords::dec_frm_ecl(long, lat_fk5, true_oblq),
+    );
+
+    let (_, _, r) = astro::planet::heliocent_coords(&planet, jd_tt);
+    let (_, _, earth_r) = astro::planet::heliocent_coords(&Planet::Earth, jd_tt);
+    let magnitude = if planet_index == 5 {
+        let ring = astro::planet::saturn::ring::elements(jd_tt, nut_long, true_oblq);
+        astro::planet::saturn::apprnt_mag_84(delta, r, ring.deltaU.to_degrees(), ring.B)
+    } else {
+        let cos_phase = (r * r + delta * delta - earth_r * earth_r) / (2.0 * r * delta);
+        let phase_deg = cos_phase.clamp(-1.0, 1.0).acos().to_degrees();
+        astro::planet::apprnt_mag_84(&planet, phase_deg, delta, r).ok()?
+    };
+    Some((place, magnitude))
+}
+
+/// Default arcus visionis for a point source of visual magnitude `magnitude` (rad)
+pub fn arcus_visionis(magnitude: f64) -> f64 {
+    (10.5 + 1.4 * magnitude)
+        .clamp(ARCUS_VISIONIS_MIN_DEG, ARCUS_VISIONIS_MAX_DEG)
+        .to_radians()
+}
+
+/// Visibility in one twilight of the local day around `noon`
+///
+/// Returns `(visible, instant)` where `instant` is the moment the Sun reaches
+/// `-arcus visionis` (noon ∓ 6 h when it never does).
+fn twilight_visibility(
+    body: &HeliacalBody,
+    noon: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    twilight: Crossing,
+    arcus_visionis_override: Option<f64>,
+) -> Option<(bool, f64)> {
+    let (_, magnitude) = body.place_and_magnitude(noon)?;
+    let depression = arcus_visionis_override.unwrap_or_else(|| arcus_visionis(magnitude));
+    let guess = match twilight {
+        Crossing::Rising => noon - 0.25,
+        Crossing::Setting => noon + 0.25,
+    };
+
+    let instant = match sun_altitude_crossing(guess, lat_rad, lon_east_rad, -depression, twilight) {
+        Some(t) => t,
+        None => {
+            // No crossing: either dark all day (polar night) or never dark enough
+            let sun_alt = altitude(noon, lat_rad, lon_east_rad, sun_place_utc(noon)?);
+            if sun_alt > -depression {
+                return Some((false, guess));
+            }
+            guess
+        }
+    };
+    let (place, _) = body.place_and_magnitude(instant)?;
+    let visible = altitude(instant, lat_rad, lon_east_rad, place) >= apparent_horizon_altitude();
+    Some((visible, instant))
+}
+
+/// Next heliacal event after `jd_utc_start` (JD UTC)
+///
+/// Returns the twilight instant of the first or last visible day, or `None`
+/// if the transition does not happen within the search horizon (e.g. a
+/// circumpolar star).
+pub fn next_heliacal_event(
+    body: &HeliacalBody,
+    event: HeliacalEvent,
+    jd_utc_start: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    arcus_visionis_override: Option<f64>,
+) -> Option<f64> {
+    let twilight = event.twilight();
+    let day = |noon: f64| {
+        twilight_visibility(
+            body,
+            noon,
+            lat_rad,
+            lon_east_rad,
+            twilight,
+            arcus_visionis_override,
+        )
+    };
+
+    let mut noon = local_noon(jd_utc_start, lon_east_rad);
+    let (mut prev_visible, mut prev_instant) = day(noon)?;
+    for _ in 0..HELIACAL_SEARCH_DAYS {
+        noon += 1.0;
+        let (visible, instant) = day(noon)?;
+        let candidate = if event.is_first() {
+            (!prev_visible && visible).then_some(instant)
+        } else {
+            (prev_visible && !visible).then_some(prev_instant)
+        };
+        if let Some(t) = candidate.filter(|&t| t > jd_utc_start) {
+            return Some(t);
+        }
+        prev_visible = visible;
+        prev_instant = instant;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stars::star_by_hr;
+
+    /// Cairo, Egypt
+    const CAIRO: (f64, f64) = (30.0444, 31.2357);
+
+    fn radians((lat, lon): (f64, f64)) -> (f64, f64) {
+        (lat.to_radians(), lon.to_radians())
+    }
+
+    fn star_event(hr: u16, event: HeliacalEvent, start: f64, site: (f64, f64)) -> Option<f64> {
+        let (lat, lon) = radians(site);
+        let body = star_by_hr(hr).map(HeliacalBody::Star)?;
+        next_heliacal_event(&body, event, start, lat, lon, None)
+    }
+
+    #[test]
+    fn test_sirius_heliacal_rising_and_setting_at_cairo() {
+        // 2025-01-01 0h UTC. Sirius is in conjunction with the Sun in early
+        // July; at Cairo it disappears in the evening twilight in May and
+        // reappears before dawn in early August.
+        let start = 2460676.5;
+        let rising = star_event(2491, HeliacalEvent::MorningFirst, start, CAIRO);
+        let setting = star_event(2491, HeliacalEvent::EveningLast, start, CAIRO);
+        // 2025-07-28 .. 2025-08-10
+        assert!(
+            matches!(rising, Some(t) if t > 2460884.5 && t < 2460897.5),
+            "{:?}",
+            rising
+        );
+        // 2025-05-15 .. 2025-06-10
+        assert!(
+            matches!(setting, Some(t) if t > 2460810.5 && t < 2460836.5),
+            "{:?}",
+            setting
+        );
+
+        // The star is above the horizon with the Sun at the arcus visionis
+        if let Some(t) = rising {
+            let (lat, lon) = radians(CAIRO);
+            let body = star_by_hr(2491).map(HeliacalBody::Star);
+            let place = body.and_then(|b| b.place_and_magnitude(t));
+            let sun = sun_place_utc(t);
+            let (Some((place, mag)), Some(sun)) = (place, sun) else {
+                panic!("no place of the star or Sun");
+            };
+            assert!(altitude(t, lat, lon, place) >= apparent_horizon_altitude());
+            assert!((altitude(t, lat, lon, sun) + arcus_visionis(mag)).abs() < 1.0e-6);
+        }
+    }
+
+    #[test]
+    fn test_matariki_pleiades_rising_in_auckland() {
+        // Matariki (Pleiades, Alcyone) returns to the pre-dawn sky in
+        // Aotearoa New Zealand in June
+        let rising = star_event(
+            1165,
+            HeliacalEvent::MorningFirst,
+            2460767.5,
+            (-36.8485, 174.7633),
+        );
+        // 2025-06-01 .. 2025-07-01
+        assert!(
+            matches!(rising, Some(t) if t > 2460827.5 && t < 2460857.5),
+            "{:?}",
+            rising
+        );
+    }
+
+    #[test]
+    fn test_venus_morning_first_after_inferior_conjunction() {
+        // Inferior conjunction of Venus: 2025-03-23. Venus passed ~8° north of
+        // the Sun, so it was briefly both evening and morning star.
+        let (lat, lon) = radians(CAIRO);
+        let venus = HeliacalBody::planet(1);
+        let Some(venus) = venus else {
+            panic!("Venus is a heliacal body");
+        };
+        let start = 2460737.5; // 2025-03-03
+        let last_evening =
+            next_heliacal_event(&venus, HeliacalEvent::EveningLast, start, lat, lon, None);
+        let first_morning =
+            next_heliacal_event(&venus, HeliacalEvent::MorningFirst, start, lat, lon, None);
+        // 2025-03-14 .. 2025-03-24
+        assert!(
+            matches!(last_evening, Some(t) if t > 2460748.5 && t < 2460758.5),
+            "{:?}",
+            last_evening
+        );
+        // 2025-03-16 .. 2025-03-28
+        assert!(
+            matches!(first_morning, Some(t) if t > 2460750.5 && t < 2460762.5),
+            "{:?}",
+            first_morning
+        );
+        assert!(HeliacalBody::planet(2).is_none());
+        assert!(HeliacalBody::planet(6).is_none());
+    }
+
+    #[test]
+    fn test_circumpolar_star_has_no_event() {
+        // Dubhe (δ ≈ +61.7°) never sets at 45°N, where every night gets dark
+        let rising = star_event(4301, HeliacalEvent::MorningFirst, 2460676.5, (45.0, 0.0));
+        assert!(rising.is_none());
+    }
+}
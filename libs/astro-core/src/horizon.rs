@@ -0,0 +1,199 @@
+//! Horizon geometry shared by the event solvers
+//!
+//! Local hour angle, geometric altitude/azimuth, refraction at the horizon
+//! and rise/set/twilight crossings of a given altitude. Times are JD UTC with
+//! UT1 ≈ UTC (|UT1−UTC| < 0.9 s); callers supply apparent equatorial places.
+//!
+//! ## Conventions
+//! - Longitude is east-positive (astro-rust uses west-positive).
+//! - Azimuth is measured from north through east, 0..2π (astro-rust measures
+//!   from south through west).
+//! - Altitudes are geometric unless stated otherwise.
+
+use crate::wrap_pi;
+
+/// Geometric altitude of the Sun's centre at sunrise/sunset (-50′: 34′
+/// refraction + 16′ semidiameter)
+pub const SUNRISE_ALTITUDE_RAD: f64 = -0.014_543_897_651_582_656; // @allow-wasm-const
+
+/// Sun altitude at the end of nautical twilight (-12°)
+pub const NAUTICAL_TWILIGHT_RAD: f64 = -0.209_439_510_239_319_55; // @allow-wasm-const
+
+/// Sun altitude at the end of astronomical twilight (-18°)
+pub const ASTRONOMICAL_TWILIGHT_RAD: f64 = -0.314_159_265_358_979_3; // @allow-wasm-const
+
+/// Ratio of the sidereal to the solar rotation rate
+const SIDEREAL_RATE: f64 = 1.002_737_909_35; // @allow-wasm-const
+
+/// Convergence threshold of the crossing iteration, in days (~9 ms)
+const CROSSING_TOLERANCE_DAYS: f64 = 1.0e-7; // @allow-wasm-const
+
+/// Maximum iterations of the crossing iteration
+const CROSSING_MAX_ITERS: u32 = 10; // @allow-wasm-const
+
+/// Direction of an altitude crossing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crossing {
+    /// Ascending through the altitude (east of the meridian)
+    Rising,
+    /// Descending through the altitude (west of the meridian)
+    Setting,
+}
+
+/// Apparent sidereal time at Greenwich (rad)
+pub fn apparent_sidereal_time(jd_utc: f64) -> f64 {
+    let (nut_long, nut_oblq) = astro::nutation::nutation(jd_utc);
+    let true_oblq = astro::ecliptic::mn_oblq_IAU(jd_utc) + nut_oblq;
+    astro::time::apprnt_sidr(astro::time::mn_sidr(jd_utc), nut_long, true_oblq)
+}
+
+/// Local hour angle of a right ascension (rad, unnormalized)
+pub fn hour_angle(jd_utc: f64, lon_east_rad: f64, ra: f64) -> f64 {
+    apparent_sidereal_time(jd_utc) + lon_east_rad - ra
+}
+
+/// Geometric altitude of an apparent place `(α, δ)` (rad)
+pub fn altitude(jd_utc: f64, lat_rad: f64, lon_east_rad: f64, place: (f64, f64)) -> f64 {
+    let h = hour_angle(jd_utc, lon_east_rad, place.0);
+    astro::coords::alt_frm_eq(h, place.1, lat_rad)
+}
+
+/// Azimuth of an apparent place `(α, δ)`, from north through east (rad, 0..2π)
+pub fn azimuth(jd_utc: f64, lat_rad: f64, lon_east_rad: f64, place: (f64, f64)) -> f64 {
+    let h = hour_angle(jd_utc, lon_east_rad, place.0);
+    astro::angle::limit_to_two_PI(
+        astro::coords::az_frm_eq(h, place.1, lat_rad) + std::f64::consts::PI,
+    )
+}
+
+/// Geometric altitude at which a point object appears on the horizon
+/// (minus the standard refraction at apparent altitude 0, ≈ -34′)
+pub fn apparent_horizon_altitude() -> f64 {
+    -astro::atmos::refrac_frm_apprnt_alt(0.0)
+}
+
+/// UTC of local mean noon on the local date containing `jd_utc`
+pub fn local_noon(jd_utc: f64, lon_east_rad: f64) -> f64 {
+    let offset_days = lon_east_rad / std::f64::consts::TAU;
+    (jd_utc + offset_days + 0.5).floor() - offset_days
+}
+
+/// Instant nearest to `jd_utc_guess` at which a body crosses `altitude_rad`
+///
+/// `place_at` returns the apparent `(α, δ)` at a JD UTC. Iterates the
+/// hour-angle equation with the place re-evaluated at each estimate, so it
+/// also works for the Sun and planets. `None` when the body stays above or
+/// below the altitude all day at that declination.
+pub fn altitude_crossing<F: Fn(f64) -> Option<(f64, f64)>>(
+    place_at: F,
+    jd_utc_guess: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    altitude_rad: f64,
+    crossing: Crossing,
+) -> Option<f64> {
+    let mut t = jd_utc_guess;
+    for _ in 0..CROSSING_MAX_ITERS {
+        let (ra, dec) = place_at(t)?;
+        let cos_h0 = (altitude_rad.sin() - lat_rad.sin() * dec.sin()) / (lat_rad.cos() * dec.cos());
+        if !(-1.0..=1.0).contains(&cos_h0) {
+            return None;
+        }
+        let h0 = cos_h0.acos();
+        let target = match crossing {
+            Crossing::Rising => -h0,
+            Crossing::Setting => h0,
+        };
+        let dt = wrap_pi(target - hour_angle(t, lon_east_rad, ra))
+            / (std::f64::consts::TAU * SIDEREAL_RATE);
+        t += dt;
+        if dt.abs() < CROSSING_TOLERANCE_DAYS {
+            return Some(t);
+        }
+    }
+    Some(t)
+}
+
+/// Apparent `(α, δ)` of the Sun at a JD UTC
+pub fn sun_place_utc(jd_utc: f64) -> Option<(f64, f64)> {
+    let jd_tt = crate::timescales::utc_to_tt_jd(jd_utc);
+    jd_tt
+        .is_finite()
+        .then(|| crate::bodies::sun_apparent_equatorial(jd_tt))
+}
+
+/// Instant nearest to `jd_utc_guess` at which the Sun's centre crosses
+/// `altitude_rad` (`SUNRISE_ALTITUDE_RAD` or a twilight constant)
+pub fn sun_altitude_crossing(
+    jd_utc_guess: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    altitude_rad: f64,
+    crossing: Crossing,
+) -> Option<f64> {
+    altitude_crossing(
+        sun_place_utc,
+        jd_utc_guess,
+        lat_rad,
+        lon_east_rad,
+        altitude_rad,
+        crossing,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sunrise_sunset_boston() {
+        // Boston (42.3333°N, 71.0833°W, the site of Meeus Example 15.a) on
+        // 1988 March 20: sunrise 10h47m UT, sunset 22h57m UT (almanac values)
+        let (lat, lon) = (42.3333_f64.to_radians(), (-71.0833_f64).to_radians());
+        let noon = local_noon(2447241.0, lon);
+        let rise = sun_altitude_crossing(
+            noon - 0.25,
+            lat,
+            lon,
+            SUNRISE_ALTITUDE_RAD,
+            Crossing::Rising,
+        );
+        let set = sun_altitude_crossing(
+            noon + 0.25,
+            lat,
+            lon,
+            SUNRISE_ALTITUDE_RAD,
+            Crossing::Setting,
+        );
+        assert!(
+            matches!(rise, Some(t) if ((t - 2447240.5) * 24.0 - 10.787).abs() < 0.03),
+            "{:?}",
+            rise
+        );
+        assert!(
+            matches!(set, Some(t) if ((t - 2447240.5) * 24.0 - 22.949).abs() < 0.03),
+            "{:?}",
+            set
+        );
+        // Sun's altitude at the returned instant matches the target
+        if let (Some(t), Some(place)) = (rise, rise.and_then(sun_place_utc)) {
+            assert!((altitude(t, lat, lon, place) - SUNRISE_ALTITUDE_RAD).abs() < 1.0e-6);
+            // ... and it rises in the east
+            let az = azimuth(t, lat, lon, place);
+            assert!(az > 0.0 && az < std::f64::consts::PI);
+        }
+    }
+
+    #[test]
+    fn test_polar_day_has_no_crossing() {
+        // 80°N at the June solstice: the Sun never sets
+        let t = sun_altitude_crossing(
+            2460848.0,
+            80.0_f64.to_radians(),
+            0.0,
+            SUNRISE_ALTITUDE_RAD,
+            Crossing::Setting,
+        );
+        assert!(t.is_none());
+    }
+}
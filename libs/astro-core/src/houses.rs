@@ -0,0 +1,217 @@
+//! Ascendant, Midheaven and house cusps of a chart
+//!
+//! Angles are ecliptic longitudes of date from the apparent local sidereal
+//! time and the true obliquity. Placidus trisects the diurnal and nocturnal
+//! semi-arcs and is undefined inside the polar circles, where callers fall
+//! back to another system.
+//!
+//! House systems are encoded 0 Placidus, 1 whole sign, 2 equal (from the
+//! Ascendant). Cusps are returned for houses 1 … 12 as indices 0 … 11.
+
+use crate::wrap_pi;
+use crate::zodiac::{SIGN_WIDTH_RAD, sign_of};
+
+/// Convergence threshold of the Placidus iteration (rad)
+const PLACIDUS_TOLERANCE_RAD: f64 = 1.0e-10; // @allow-wasm-const
+
+/// Maximum iterations of the Placidus iteration
+const PLACIDUS_MAX_ITERS: u32 = 50; // @allow-wasm-const
+
+/// House system
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HouseSystem {
+    /// Time-based trisection of the diurnal and nocturnal arcs
+    Placidus,
+    /// One sign per house, starting with the sign of the ascendant
+    WholeSign,
+    /// 30° houses starting at the ascendant
+    Equal,
+}
+
+impl HouseSystem {
+    /// Decode the WASM house system code
+    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(Self::Placidus),
+            1 => Some(Self::WholeSign),
+            2 => Some(Self::Equal),
+            _ => None,
+        }
+    }
+}
+
+/// Angles of a chart (rad)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChartAngles {
+    /// Ascendant longitude, 0..2π
+    pub ascendant: f64,
+    /// Midheaven longitude, 0..2π
+    pub midheaven: f64,
+    /// Right ascension of the meridian (local apparent sidereal time)
+    pub ramc: f64,
+    /// True obliquity of the ecliptic
+    pub obliquity: f64,
+    /// Geographic latitude
+    pub latitude: f64,
+}
+
+/// Ecliptic longitude of the point of the ecliptic with right ascension `ra`
+fn ecliptic_longitude_of_ra(ra: f64, obliquity: f64) -> f64 {
+    astro::angle::limit_to_two_PI(ra.sin().atan2(ra.cos() * obliquity.cos()))
+}
+
+/// Chart angles for an instant (JD UTC) and place
+pub fn chart_angles(jd_utc: f64, lat_rad: f64, lon_east_rad: f64) -> ChartAngles {
+    let jd_tt = crate::timescales::utc_to_tt_jd(jd_utc);
+    let (_nut_long, nut_oblq) = astro::nutation::nutation(jd_tt);
+    let obliquity = astro::ecliptic::mn_oblq_IAU(jd_tt) + nut_oblq;
+    let ramc = astro::angle::limit_to_two_PI(
+        crate::horizon::apparent_sidereal_time(jd_utc) + lon_east_rad,
+    );
+    let ascendant = astro::angle::limit_to_two_PI(
+        ramc.cos()
+            .atan2(-(ramc.sin() * obliquity.cos() + lat_rad.tan() * obliquity.sin())),
+    );
+    ChartAngles {
+        ascendant,
+        midheaven: ecliptic_longitude_of_ra(ramc, obliquity),
+        ramc,
+        obliquity,
+        latitude: lat_rad,
+    }
+}
+
+/// Placidus cusp whose right ascension is `ramc + offset(SA)`, where `SA` is
+/// the diurnal semi-arc of the cusp's own declination
+fn placidus_cusp<F: Fn(f64) -> f64>(angles: &ChartAngles, offset: F) -> Option<f64> {
+    let mut longitude = angles.ramc;
+    for _ in 0..PLACIDUS_MAX_ITERS {
+        let dec = (angles.obliquity.sin() * longitude.sin()).asin();
+        let cos_semi_arc = -angles.latitude.tan() * dec.tan();
+        if !(-1.0..=1.0).contains(&cos_semi_arc) {
+            return None;
+        }
+        let next =
+            ecliptic_longitude_of_ra(angles.ramc + offset(cos_semi_arc.acos()), angles.obliquity);
+        let converged = wrap_pi(next - longitude).abs() < PLACIDUS_TOLERANCE_RAD;
+        longitude = next;
+        if converged {
+            return Some(longitude);
+        }
+    }
+    Some(longitude)
+}
+
+/// House cusps (rad, houses 1 … 12 as indices 0 … 11)
+///
+/// `None` for Placidus when a cusp's declination is circumpolar.
+pub fn house_cusps(angles: &ChartAngles, system: HouseSystem) -> Option<[f64; 12]> {
+    let mut cusps = [0.0; 12];
+    match system {
+        HouseSystem::WholeSign => {
+            let first = f64::from(sign_of(angles.ascendant)) * SIGN_WIDTH_RAD;
+            for (house, cusp) in (0u8..).zip(cusps.iter_mut()) {
+                *cusp = astro::angle::limit_to_two_PI(first + f64::from(house) * SIGN_WIDTH_RAD);
+            }
+        }
+        HouseSystem::Equal => {
+            for (house, cusp) in (0u8..).zip(cusps.iter_mut()) {
+                *cusp = astro::angle::limit_to_two_PI(
+                    angles.ascendant + f64::from(house) * SIGN_WIDTH_RAD,
+                );
+            }
+        }
+        HouseSystem::Placidus => {
+            let pi = std::f64::consts::PI;
+            let eleventh = placidus_cusp(angles, |semi_arc| semi_arc / 3.0)?;
+            let twelfth = placidus_cusp(angles, |semi_arc| 2.0 * semi_arc / 3.0)?;
+            let second = placidus_cusp(angles, |semi_arc| pi - 2.0 * (pi - semi_arc) / 3.0)?;
+            let third = placidus_cusp(angles, |semi_arc| pi - (pi - semi_arc) / 3.0)?;
+            let half = [
+                angles.ascendant,
+                second,
+                third,
+                angles.midheaven + pi,
+                eleventh + pi,
+                twelfth + pi,
+            ];
+            for (i, longitude) in half.into_iter().enumerate() {
+                cusps[i] = astro::angle::limit_to_two_PI(longitude);
+                cusps[i + 6] = astro::angle::limit_to_two_PI(longitude + pi);
+            }
+        }
+    }
+    Some(cusps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Equatorial place of an ecliptic point (β = 0)
+    fn ecliptic_point(longitude: f64, obliquity: f64) -> (f64, f64) {
+        (
+            astro::coords::asc_frm_ecl(longitude, 0.0, obliquity),
+            astro::coords::dec_frm_ecl(longitude, 0.0, obliquity),
+        )
+    }
+
+    #[test]
+    fn test_ascendant_rises_and_midheaven_culminates() {
+        // London, 2025-01-05 12:00 UTC
+        let (lat, lon) = (51.5_f64.to_radians(), -0.13_f64.to_radians());
+        let jd = 2460681.0;
+        let angles = chart_angles(jd, lat, lon);
+        let asc = ecliptic_point(angles.ascendant, angles.obliquity);
+        assert!(crate::horizon::altitude(jd, lat, lon, asc).abs() < 1.0e-6);
+        assert!(crate::horizon::azimuth(jd, lat, lon, asc) < std::f64::consts::PI);
+        let mc = ecliptic_point(angles.midheaven, angles.obliquity);
+        assert!(wrap_pi(crate::horizon::hour_angle(jd, lon, mc.0)).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn test_placidus_trisects_the_semi_arcs() {
+        let lat = 45.0_f64.to_radians();
+        let angles = chart_angles(2460681.0, lat, 0.3);
+        let cusps = house_cusps(&angles, HouseSystem::Placidus);
+        let Some(cusps) = cusps else {
+            panic!("no Placidus cusps");
+        };
+        assert!((cusps[0] - angles.ascendant).abs() < 1.0e-12);
+        assert!((cusps[9] - angles.midheaven).abs() < 1.0e-12);
+        // Houses follow the zodiac order
+        for house in 0..12 {
+            let width = astro::angle::limit_to_two_PI(cusps[(house + 1) % 12] - cusps[house]);
+            assert!(
+                width > 0.2 && width < 1.2,
+                "house {} width {}",
+                house + 1,
+                width
+            );
+        }
+        // Cusp 11: hour angle is -SA/3; cusp 12: -2SA/3
+        for (house, fraction) in [(10, 1.0 / 3.0), (11, 2.0 / 3.0)] {
+            let (ra, dec) = ecliptic_point(cusps[house], angles.obliquity);
+            let semi_arc = (-lat.tan() * dec.tan()).acos();
+            let hour_angle = wrap_pi(angles.ramc - ra);
+            assert!((hour_angle + fraction * semi_arc).abs() < 1.0e-8);
+        }
+    }
+
+    #[test]
+    fn test_whole_sign_equal_and_polar_placidus() {
+        let angles = chart_angles(2460681.0, 0.9, 0.0);
+        let whole = house_cusps(&angles, HouseSystem::WholeSign);
+        assert!(
+            matches!(whole, Some(c) if c[0] <= angles.ascendant && angles.ascendant - c[0] < SIGN_WIDTH_RAD)
+        );
+        let equal = house_cusps(&angles, HouseSystem::Equal);
+        assert!(
+            matches!(equal, Some(c) if wrap_pi(c[3] - angles.ascendant - std::f64::consts::FRAC_PI_2).abs() < 1.0e-12)
+        );
+
+        let polar = chart_angles(2460681.0, 75.0_f64.to_radians(), 0.0);
+        assert!(house_cusps(&polar, HouseSystem::Placidus).is_none());
+        assert_eq!(HouseSystem::from_code(3), None);
+    }
+}
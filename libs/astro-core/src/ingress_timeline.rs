@@ -0,0 +1,173 @@
+//! Sign ingress and house transit timeline of the Sun and planets
+//!
+//! Lists every crossing of the Sun, Mercury … Pluto into a new zodiac sign,
+//! including retrograde re-entries into the previous sign, and optionally the
+//! crossings of a natal chart's house cusps. The Moon is left out (about 160
+//! ingresses a year, see `void_of_course`).
+//!
+//! Rows are `(jd_utc, body, from, to, direction)` with direction +1 direct
+//! and -1 retrograde. Native callers (forecast content) use
+//! `ingress_timeline` and `timeline_rows` directly.
+
+use crate::timescales::tt_to_utc_jd;
+use crate::zodiac::{
+    BODY_COUNT, Ingress, MOON, Sectors, Zodiac, apparent_longitude, ingresses, sign_ingresses,
+};
+
+/// f64 values per timeline row
+pub const TIMELINE_STRIDE: usize = 5;
+
+/// Scan step, in days: the Sun and planets move < 2.3°/day, and a station
+/// closer to a boundary than a day's motion is below the model accuracy
+const PLANET_SCAN_STEP_DAYS: f64 = 1.0; // @allow-wasm-const
+
+/// Which sectors a timeline follows
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimelineKind {
+    /// Signs of a zodiac, numbered 0 Aries … 11 Pisces
+    Signs(Zodiac),
+    /// Houses of a natal chart (tropical cusps), numbered 1 … 12
+    Houses([f64; 12]),
+}
+
+/// Crossings of the Sun and planets in `[jd_tt_start, jd_tt_end)` sorted by time
+pub fn ingress_timeline(
+    kind: TimelineKind,
+    jd_tt_start: f64,
+    jd_tt_end: f64,
+) -> Option<Vec<Ingress>> {
+    let mut all = Vec::new();
+    for body in (0..BODY_COUNT).filter(|&body| body != MOON) {
+        let found = match kind {
+            TimelineKind::Signs(zodiac) => {
+                sign_ingresses(body, zodiac, jd_tt_start, jd_tt_end, PLANET_SCAN_STEP_DAYS)?
+            }
+            TimelineKind::Houses(cusps) => ingresses(
+                body,
+                |t| apparent_longitude(body, t),
+                &Sectors::Houses(cusps),
+                jd_tt_start,
+                jd_tt_end,
+                PLANET_SCAN_STEP_DAYS,
+            )?,
+        };
+        all.extend(found);
+    }
+    all.sort_by(|a, b| a.jd_tt.total_cmp(&b.jd_tt));
+    Some(all)
+}
+
+/// Timeline rows `[jd_utc, body, from, to, direction]`
+///
+/// Houses are numbered 1 … 12, signs 0 … 11.
+pub fn timeline_rows(kind: TimelineKind, found: &[Ingress]) -> Vec<[f64; TIMELINE_STRIDE]> {
+    let number_offset = match kind {
+        TimelineKind::Signs(_) => 0,
+        TimelineKind::Houses(_) => 1,
+    };
+    found
+        .iter()
+        .map(|ingress| {
+            [
+                tt_to_utc_jd(ingress.jd_tt),
+                f64::from(ingress.body),
+                f64::from(ingress.from + number_offset),
+                f64::from(ingress.to + number_offset),
+                if ingress.retrograde { -1.0 } else { 1.0 },
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::houses::{HouseSystem, chart_angles, house_cusps};
+
+    /// 2025-01-01 00:00 TT
+    const JAN_2025: f64 = 2460676.5;
+
+    /// Ingresses of one body
+    fn of_body(found: &[Ingress], body: u8) -> Vec<Ingress> {
+        found.iter().filter(|i| i.body == body).copied().collect()
+    }
+
+    #[test]
+    fn test_2025_tropical_ingresses() {
+        let found = ingress_timeline(
+            TimelineKind::Signs(Zodiac::Tropical),
+            JAN_2025,
+            JAN_2025 + 365.0,
+        );
+        let Some(found) = found else {
+            panic!("no ingresses found in 2025");
+        };
+        assert!(found.windows(2).all(|w| w[0].jd_tt <= w[1].jd_tt));
+
+        // The Sun enters every sign once, always direct
+        let sun = of_body(&found, 0);
+        assert_eq!(sun.len(), 12);
+        assert!(
+            sun.iter()
+                .all(|i| !i.retrograde && i.to == (i.from + 1) % 12)
+        );
+
+        // Neptune: Aries on 2025-03-30, back into Pisces on 2025-10-22
+        let neptune = of_body(&found, 8);
+        assert!(
+            matches!(neptune.as_slice(), [a, b]
+                if a.to == 0 && !a.retrograde && (a.jd_tt - 2460764.5).abs() < 1.5
+                && b.to == 11 && b.retrograde && (b.jd_tt - 2460970.5).abs() < 1.5),
+            "{:?}",
+            neptune
+        );
+
+        // Saturn: Aries on 2025-05-25, back into Pisces on 2025-09-01
+        let saturn = of_body(&found, 6);
+        assert!(
+            matches!(saturn.as_slice(), [a, b]
+                if a.to == 0 && (a.jd_tt - 2460820.5).abs() < 1.5
+                && b.to == 11 && b.retrograde && (b.jd_tt - 2460919.5).abs() < 1.5),
+            "{:?}",
+            saturn
+        );
+
+        // Mercury retrograde: Aries (Mar 3) → Pisces (Mar 29/30) → Aries (Apr 16)
+        let mercury = of_body(&found, 2);
+        let spring: Vec<_> = mercury
+            .iter()
+            .filter(|i| (2460736.5..2460790.5).contains(&i.jd_tt))
+            .map(|i| (i.from, i.to, i.retrograde))
+            .collect();
+        assert_eq!(spring, vec![(11, 0, false), (0, 11, true), (11, 0, false)]);
+    }
+
+    #[test]
+    fn test_house_transits_cross_cusps() {
+        // Natal chart: 1990-06-15 12:00 UTC, Paris
+        let angles = chart_angles(2448058.0, 48.85_f64.to_radians(), 2.35_f64.to_radians());
+        let cusps = house_cusps(&angles, HouseSystem::Placidus);
+        let Some(cusps) = cusps else {
+            panic!("no Placidus cusps for Paris");
+        };
+        let kind = TimelineKind::Houses(cusps);
+        let found = ingress_timeline(kind, JAN_2025, JAN_2025 + 365.0);
+        let Some(found) = found else {
+            panic!("no house ingresses found in 2025");
+        };
+
+        // The Sun passes through all twelve houses in a year
+        let sun = of_body(&found, 0);
+        assert_eq!(sun.len(), 12);
+        for ingress in &sun {
+            let long = apparent_longitude(0, ingress.jd_tt).unwrap_or(f64::NAN);
+            let cusp = cusps[usize::from(ingress.to)];
+            assert!(crate::wrap_pi(long - cusp).abs() < 1.0e-6);
+        }
+        let rows = timeline_rows(kind, &sun);
+        assert!(
+            rows.iter()
+                .all(|r| (1.0..=12.0).contains(&r[2]) && (1.0..=12.0).contains(&r[3]))
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! # StarsCalendars Ephemeris Core
+//!
+//! Pure astro-rust computations behind the WASM bindings, with a safe Rust
+//! API: no `#[wasm_bindgen]`, no raw pointers, no JS. `wasm-astro` is a thin
+//! binding layer over this crate, and the backend (event precomputation),
+//! tools and tests link it natively, so every caller runs the identical code.
+//!
+//! ## Modules
+//! - `timescales` - UTC↔TT with leap seconds
+//! - `state` - per-frame Moon/Earth/zenith state (`compute_state`)
+//! - `zenith` - solar zenith position
+//! - `bodies` - apparent geocentric positions of the Sun, Moon and planets
+//! - `longitude` - apparent longitudes and the equinox/solstice solver
+//! - `quantum` - Quantum Time (NT) components
+//! - `moon_phase` - Moon phase and principal phase search
+//! - `calendar` - Moon phases, equinoxes and solstices in a date range
+//! - `horizon` - altitude, azimuth and Sun altitude crossings
+//! - `zodiac` - zodiac longitudes, aspects and sector ingresses
+//! - `houses` - chart angles and house cusps
+//! - `chart`, `returns`, `synastry` - natal, return and composite charts
+//! - `ingress_timeline`, `void_of_course` - sign ingresses and lunar voids
+//! - `stars`, `heliacal`, `orion` - bright stars and their events
+//! - `meteors` - meteor shower calendar
+//! - `planetary_hours` - planetary hours and day rulers
+//! - `satellites`, `physical`, `lunar_physical` - moons and disk orientation
+//! - `small_body` - comets and asteroids from orbital elements
+//!
+//! Times are Julian Days; TT where a function name or argument says so,
+//! otherwise UTC. Angles are radians.
+
+pub mod bodies;
+pub mod calendar;
+pub mod chart;
+pub mod heliacal;
+pub mod horizon;
+pub mod houses;
+pub mod ingress_timeline;
+pub mod longitude;
+pub mod lunar_physical;
+pub mod meteors;
+pub mod moon_phase;
+pub mod orion;
+pub mod physical;
+pub mod planetary_hours;
+pub mod quantum;
+pub mod returns;
+pub mod satellites;
+pub mod small_body;
+pub mod stars;
+pub mod state;
+pub mod synastry;
+pub mod timescales;
+pub mod void_of_course;
+pub mod zenith;
+pub mod zodiac;
+
+pub use bodies::{ApparentPosition, Body, apparent_position};
+pub use calendar::{CalendarEvent, calendar_events};
+pub use longitude::{next_solar_longitude_from, next_winter_solstice_from};
+pub use quantum::{QuantumTime, quantum_time};
+pub use state::{EphemerisState, compute_state};
+pub use zenith::solar_zenith_position_rad;
+
+/// Kilometres per astronomical unit (IAU 2012)
+pub const AU_KM: f64 = 149597870.7; // @allow-wasm-const
+
+/// Light travel time for 1 AU, in days
+pub const LIGHT_TIME_DAYS_PER_AU: f64 = 0.0057755183; // @allow-wasm-const
+
+/// Validated Julian Day (finite, non-negative)
+#[derive(Debug, Clone, Copy)]
+pub struct JulianDay(f64);
+
+impl JulianDay {
+    /// Validate a Julian Day
+    pub fn new(jd: f64) -> Result<Self, &'static str> {
+        if jd < 0.0 || !jd.is_finite() {
+            Err("Invalid Julian Day")
+        } else {
+            Ok(Self(jd))
+        }
+    }
+
+    /// The Julian Day as a number
+    pub fn as_f64(&self) -> f64 {
+        self.0
+    }
+}
+
+/// Cartesian vector (AU or AU/day)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cartesian {
+    /// x component
+    pub x: f64,
+    /// y component
+    pub y: f64,
+    /// z component
+    pub z: f64,
+}
+
+impl Cartesian {
+    /// Vector from components
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+/// Convert ecliptic spherical coordinates to Cartesian
+///
+/// High-performance coordinate transformation without allocations.
+/// Input coordinates are in RADIANS (as returned by astro-rust API).
+#[inline]
+pub fn ecliptic_to_cartesian(longitude_rad: f64, latitude_rad: f64, radius_au: f64) -> Cartesian {
+    let cos_lat = latitude_rad.cos();
+    let x = radius_au * cos_lat * longitude_rad.cos();
+    let y = radius_au * cos_lat * longitude_rad.sin();
+    let z = radius_au * latitude_rad.sin();
+    Cartesian::new(x, y, z)
+}
+
+/// Normalize an angle to (-π, π]
+#[inline]
+pub fn wrap_pi(angle_rad: f64) -> f64 {
+    let limited = astro::angle::limit_to_two_PI(angle_rad);
+    if limited > std::f64::consts::PI {
+        limited - 2.0 * std::f64::consts::PI
+    } else {
+        limited
+    }
+}
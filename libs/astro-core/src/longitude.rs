@@ -0,0 +1,135 @@
+//! Apparent solar and lunar longitudes and the longitude event solver
+//!
+//! Equinoxes and solstices are the instants λ_app(t) = 0°, 90°, 180°, 270°
+//! (FK5 + annual aberration + nutation), solved in TT and converted to UTC
+//! through `timescales`. Heavy: use off-frame only and cache results.
+
+use crate::JulianDay;
+use crate::timescales::{tt_to_utc_jd, utc_to_tt_jd};
+use crate::wrap_pi;
+
+/// Mean solar rate ~0.98564736°/day, in rad/day
+const SOLAR_MEAN_RATE: f64 = 0.017202124; // @allow-wasm-const
+
+/// Apparent solar ecliptic longitude λ_app (TT), 0..2π
+pub fn solar_longitude_apparent(jd_tt: f64) -> f64 {
+    // Geometric geocentric ecliptic coords (mean equinox of date)
+    let (sun_ecl, sun_dist_au) = astro::sun::geocent_ecl_pos(jd_tt);
+    // FK5 correction
+    let (long_fk5, _lat_fk5) = astro::sun::ecl_coords_to_FK5(jd_tt, sun_ecl.long, sun_ecl.lat);
+    // Annual aberration (radians)
+    let ab_long = astro::aberr::sol_aberr(sun_dist_au);
+    // Nutation in longitude
+    let (nut_long, _nut_oblq) = astro::nutation::nutation(jd_tt);
+    astro::angle::limit_to_two_PI(long_fk5 + ab_long + nut_long)
+}
+
+/// Apparent lunar ecliptic longitude (TT), 0..2π
+///
+/// ELP-2000/82 (`lunar::geocent_ecl_pos`) + nutation.
+pub fn moon_longitude_apparent(jd_tt: f64) -> f64 {
+    let (moon_ecl, _moon_dist_km) = astro::lunar::geocent_ecl_pos(jd_tt);
+    let (nut_long, _nut_oblq) = astro::nutation::nutation(jd_tt);
+    astro::angle::limit_to_two_PI(moon_ecl.long + nut_long)
+}
+
+/// First TT instant from `jd_tt_start` on at which the solar longitude given by
+/// `longitude_at` reaches `target_rad`
+///
+/// Works for any slowly varying longitude definition (apparent of date,
+/// J2000, sidereal, ...); see `next_longitude_tt`.
+pub fn next_solar_longitude_tt<F: Fn(f64) -> f64>(
+    longitude_at: F,
+    jd_tt_start: f64,
+    target_rad: f64,
+) -> f64 {
+    next_longitude_tt(longitude_at, jd_tt_start, target_rad, SOLAR_MEAN_RATE)
+}
+
+/// First TT instant from `jd_tt_start` on at which a prograde longitude
+/// advancing at about `mean_rate` rad/day reaches `target_rad`
+///
+/// Forward phase at the mean rate, then Newton with a numeric derivative
+/// (robust, few iterations). Used for the Sun and the Moon.
+pub fn next_longitude_tt<F: Fn(f64) -> f64>(
+    longitude_at: F,
+    jd_tt_start: f64,
+    target_rad: f64,
+    mean_rate: f64,
+) -> f64 {
+    let lambda0 = longitude_at(jd_tt_start);
+    // Forward phase to next target
+    let delta_forward = astro::angle::limit_to_two_PI(target_rad - lambda0);
+    let mut t = jd_tt_start + delta_forward / mean_rate;
+
+    // Newton with numeric derivative (robust, few iterations)
+    let mut iter = 0u32;
+    const H: f64 = 1.0e-3; // ~86.4 s @allow-numeric-param
+    const MAX_ITERS: u32 = 16; // @allow-numeric-param
+    const MAX_STEP: f64 = 2.0; // days @allow-numeric-param
+    loop {
+        if !t.is_finite() || iter >= MAX_ITERS {
+            break;
+        }
+        let f = wrap_pi(longitude_at(t) - target_rad);
+        if f.abs() < 1.0e-10 {
+            break;
+        }
+        let dfdt = wrap_pi(longitude_at(t + H) - longitude_at(t - H)) / (2.0 * H);
+        if dfdt.abs() < 1.0e-12 {
+            break;
+        }
+        let step = (f / dfdt).clamp(-MAX_STEP, MAX_STEP);
+        t -= step;
+        iter += 1;
+    }
+    t
+}
+
+/// Next instant (JD UTC) the Sun's apparent ecliptic longitude reaches
+/// `target_lon_rad` (equinox and ecliptic of date), starting from a UTC JD
+///
+/// `None` on invalid input or when the ephemeris fails.
+pub fn next_solar_longitude_from(jd_utc_start: f64, target_lon_rad: f64) -> Option<f64> {
+    let jd_utc = JulianDay::new(jd_utc_start).ok()?.as_f64();
+    if !target_lon_rad.is_finite() {
+        return None;
+    }
+    // Start from TT corresponding to start UTC
+    let jd_tt0 = utc_to_tt_jd(jd_utc);
+    if !jd_tt0.is_finite() {
+        return None;
+    }
+    let jd_tt_event = next_solar_longitude_tt(solar_longitude_apparent, jd_tt0, target_lon_rad);
+    // Convert TT -> UTC using ΔT at event date
+    let jd_utc_event = tt_to_utc_jd(jd_tt_event);
+    jd_utc_event.is_finite().then_some(jd_utc_event)
+}
+
+/// Next December (winter) solstice, λ_app = 270°, as JD UTC from a UTC JD
+pub fn next_winter_solstice_from(jd_utc_start: f64) -> Option<f64> {
+    next_solar_longitude_from(jd_utc_start, 270.0_f64.to_radians())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seasons_2025() {
+        // 2025-01-01 00:00 UTC; December solstice 2025-12-21 15:03 UTC,
+        // March equinox 2025-03-20 09:01 UTC
+        let start = 2460676.5;
+        let solstice = next_winter_solstice_from(start).unwrap_or(f64::NAN);
+        assert!(
+            (solstice - 2461031.1271).abs() < 2.0 / 1440.0,
+            "{}",
+            solstice
+        );
+        let equinox = next_solar_longitude_from(start, 0.0).unwrap_or(f64::NAN);
+        assert!((equinox - 2460754.876).abs() < 2.0 / 1440.0, "{}", equinox);
+
+        assert!(next_solar_longitude_from(f64::NAN, 0.0).is_none());
+        assert!(next_solar_longitude_from(start, f64::INFINITY).is_none());
+    }
+}
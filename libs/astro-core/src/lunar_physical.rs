@@ -0,0 +1,131 @@
+//! Physical ephemeris of the Moon
+//!
+//! Pure astro-rust computation of the lunar librations (optical + physical),
+//! the position angle of the Moon's rotation axis, the position angle of the
+//! bright limb and the topocentric corrections for an observer (Meeus ch. 53).
+//!
+//! ## Conventions
+//! - Libration in longitude is positive when the mean centre of the disk is
+//!   displaced towards the east limb (Mare Crisium turned towards the observer),
+//!   normalized to (-π, π].
+//! - Position angles are measured from celestial north towards the east.
+
+use crate::bodies::{moon_apparent_equatorial, sun_apparent_equatorial};
+use crate::wrap_pi;
+
+/// Lunar physical ephemeris layout length
+pub const LUNAR_PHYSICAL_LEN: usize = 7;
+
+/// Lunar physical ephemeris, optionally topocentric
+///
+/// Layout [7 f64]:
+/// - 0: l — total geocentric libration in longitude (rad)
+/// - 1: b — total geocentric libration in latitude (rad)
+/// - 2: P — position angle of the Moon's axis of rotation (rad)
+/// - 3: χ — position angle of the midpoint of the bright limb (rad, 0..2π)
+/// - 4..6: topocentric l, b, P for the observer; equal to slots 0..2 when
+///   the observer coordinates are not finite
+pub fn lunar_physical_ephemeris(
+    julian_day: f64,
+    observer_lat_rad: f64,
+    observer_lon_east_rad: f64,
+) -> [f64; LUNAR_PHYSICAL_LEN] {
+    let (moon_ecl, moon_dist_km) = astro::lunar::geocent_ecl_pos(julian_day);
+    let (nut_long, nut_oblq) = astro::nutation::nutation(julian_day);
+    let true_oblq = astro::ecliptic::mn_oblq_IAU(julian_day) + nut_oblq;
+
+    // astro::lunar::total_libr() passes its arguments to optical_libr() in the
+    // wrong order, so the optical and physical parts are composed here.
+    let (opt_long, opt_lat) = astro::lunar::optical_libr(julian_day, moon_ecl.long, moon_ecl.lat);
+    let (phys_long, phys_lat) =
+        astro::lunar::physical_libr(julian_day, moon_ecl.long, moon_ecl.lat, opt_lat);
+    let libr_long = wrap_pi(opt_long + phys_long);
+    let libr_lat = opt_lat + phys_lat;
+
+    // Apparent equatorial coordinates of the Moon and the Sun
+    let (moon_asc, moon_dec) = moon_apparent_equatorial(julian_day);
+    let (sun_asc, sun_dec) = sun_apparent_equatorial(julian_day);
+    let moon_eq = astro::coords::EqPoint {
+        asc: moon_asc,
+        dec: moon_dec,
+    };
+    let sun_eq = astro::coords::EqPoint {
+        asc: sun_asc,
+        dec: sun_dec,
+    };
+
+    let node_long = astro::lunar::mn_ascend_node(astro::time::julian_cent(julian_day));
+    let axis_pa = astro::lunar::pos_angl_of_axis_of_rot(
+        julian_day, node_long, libr_lat, nut_long, true_oblq, moon_asc,
+    );
+    let bright_limb_pa = astro::angle::limit_to_two_PI(astro::lunar::bright_limb(sun_eq, moon_eq));
+
+    let mut out = [
+        libr_long,
+        libr_lat,
+        axis_pa,
+        bright_limb_pa,
+        libr_long,
+        libr_lat,
+        axis_pa,
+    ];
+
+    if observer_lat_rad.is_finite() && observer_lon_east_rad.is_finite() {
+        let apparent_sidereal =
+            astro::time::apprnt_sidr(astro::time::mn_sidr(julian_day), nut_long, true_oblq);
+        let hour_angle = apparent_sidereal + observer_lon_east_rad - moon_asc;
+        let parallax = astro::lunar::eq_hz_parllx(moon_dist_km);
+        let (d_long, d_lat, d_pa) = astro::lunar::topocent_libr_by_diff_corrections(
+            observer_lat_rad,
+            moon_dec,
+            hour_angle,
+            parallax,
+            axis_pa,
+            libr_lat,
+        );
+        out[4] = wrap_pi(libr_long + d_long);
+        out[5] = libr_lat + d_lat;
+        out[6] = axis_pa + d_pa;
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_libration_meeus_example_53a() {
+        // Meeus, Example 53.a: 1992 April 12, 0h TD
+        // l = -1.206° (optical) - 0.025° (physical), b = +4.194° + 0.006°, P = 15.08°
+        let eph = lunar_physical_ephemeris(2448724.5, f64::NAN, f64::NAN);
+        let l = eph[0].to_degrees();
+        let b = eph[1].to_degrees();
+        let p = eph[2].to_degrees();
+        assert!((l - -1.23).abs() < 0.02, "l = {}", l);
+        assert!((b - 4.20).abs() < 0.02, "b = {}", b);
+        assert!((p - 15.08).abs() < 0.05, "P = {}", p);
+        // Without an observer the topocentric slots mirror the geocentric ones
+        assert_eq!(eph[4], eph[0]);
+        assert_eq!(eph[5], eph[1]);
+        assert_eq!(eph[6], eph[2]);
+    }
+
+    #[test]
+    fn test_bright_limb_faces_sun() {
+        // Meeus, Example 48.a: 1992 April 12, 0h TD, χ = 285.0°
+        let eph = lunar_physical_ephemeris(2448724.5, f64::NAN, f64::NAN);
+        let chi = eph[3].to_degrees();
+        assert!((chi - 285.0).abs() < 0.2, "χ = {}", chi);
+    }
+
+    #[test]
+    fn test_topocentric_libration_bounded() {
+        // Diurnal libration never exceeds the horizontal parallax (~1°)
+        let eph = lunar_physical_ephemeris(2460000.5, 0.9, 0.5);
+        assert!((eph[4] - eph[0]).abs() < 1.1_f64.to_radians());
+        assert!((eph[5] - eph[1]).abs() < 1.1_f64.to_radians());
+        assert!(eph[4] != eph[0] || eph[5] != eph[1]);
+    }
+}
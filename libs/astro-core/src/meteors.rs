@@ -0,0 +1,422 @@
+//! Meteor shower calendar
+//!
+//! Embedded IMO working list of the major annual showers (radiant at peak
+//! with daily drift, solar longitude of peak, ZHR, velocity, population
+//! index). For a year and an observer it gives the peak instant, the radiant
+//! altitude through the night around the peak, the Moon's illumination and
+//! altitude, and an estimate of the hourly rate the observer can expect.
+//!
+//! ## Conventions
+//! - Solar longitudes are referred to the mean equinox of J2000.0, as in the
+//!   IMO tables; the peak is solved with `next_solar_longitude_tt`.
+//! - Radiant drift is per degree of solar longitude (≈ per day).
+//! - Altitudes are geometric; the Moon is geocentric (parallax ≤ 1°).
+//!
+//! ## Observable rate
+//! `HR = ZHR · sin(h_radiant) · r^(LM − 6.5)`, zero with the radiant below the
+//! horizon or the Sun above -12°. The limiting magnitude `LM` starts from the
+//! observer's dark-sky value and loses up to 3 mag in nautical twilight and
+//! up to 2 mag (scaled by illumination) with the Moon above the horizon. The
+//! peak ZHR is held over the whole night.
+
+use crate::bodies::moon_apparent_equatorial;
+use crate::horizon::{
+    ASTRONOMICAL_TWILIGHT_RAD, NAUTICAL_TWILIGHT_RAD, altitude, local_noon, sun_place_utc,
+};
+use crate::longitude::next_solar_longitude_tt;
+use crate::moon_phase::moon_illuminated_fraction;
+use crate::timescales::{tt_to_utc_jd, utc_to_tt_jd};
+use crate::wrap_pi;
+
+/// Julian Day of J2000.0 (TT)
+const J2000_JD: f64 = 2451545.0; // @allow-wasm-const
+
+/// Reference limiting magnitude of the ZHR definition
+pub const ZHR_LIMITING_MAGNITUDE: f64 = 6.5; // @allow-wasm-const
+
+/// Limiting-magnitude loss at the end of nautical twilight (Sun at -12°)
+const TWILIGHT_MAGNITUDE_LOSS: f64 = 3.0; // @allow-wasm-const
+
+/// Limiting-magnitude loss under a full Moon above the horizon
+const FULL_MOON_MAGNITUDE_LOSS: f64 = 2.0; // @allow-wasm-const
+
+/// Number of showers in the embedded list
+pub const METEOR_SHOWER_COUNT: usize = 17;
+
+/// Shower summary buffer length
+pub const METEOR_SHOWER_LEN: usize = 14;
+
+/// Hourly samples of the night profile (local midnight ± 12 h)
+pub const NIGHT_PROFILE_SAMPLES: usize = 25;
+
+/// Values per night profile sample
+pub const NIGHT_PROFILE_STRIDE: usize = 5;
+
+/// Shower from the IMO working list
+#[derive(Debug, Clone, Copy)]
+pub struct MeteorShower {
+    /// IAU three-letter code
+    pub code: &'static str,
+    /// Shower name
+    pub name: &'static str,
+    /// Solar longitude of the peak, J2000.0 (rad)
+    pub peak_solar_long: f64,
+    /// Radiant right ascension at peak, J2000.0 (rad)
+    pub radiant_ra: f64,
+    /// Radiant declination at peak, J2000.0 (rad)
+    pub radiant_dec: f64,
+    /// Radiant drift in right ascension per unit of solar longitude
+    pub drift_ra: f64,
+    /// Radiant drift in declination per unit of solar longitude
+    pub drift_dec: f64,
+    /// Geocentric entry velocity (km/s)
+    pub velocity_km_s: f64,
+    /// Population index r
+    pub population_index: f64,
+    /// Zenithal hourly rate at peak
+    pub zhr: f64,
+}
+
+/// Shower entry from the working-list columns (angles in degrees)
+macro_rules! shower {
+    ($code:expr, $name:expr, $peak:expr, $ra:expr, $dec:expr, $dra:expr, $ddec:expr,
+     $v:expr, $r:expr, $zhr:expr) => {
+        MeteorShower {
+            code: $code,
+            name: $name,
+            peak_solar_long: f64::to_radians($peak),
+            radiant_ra: f64::to_radians($ra),
+            radiant_dec: f64::to_radians($dec),
+            drift_ra: $dra,
+            drift_dec: $ddec,
+            velocity_km_s: $v,
+            population_index: $r,
+            zhr: $zhr,
+        }
+    };
+}
+
+/// IMO working list of the major showers, ordered by solar longitude of peak
+///
+/// Columns: code, name, λ☉ peak (°), radiant α, δ at peak (°), drift Δα, Δδ
+/// (° per ° of λ☉), V∞ (km/s), r, ZHR.
+#[rustfmt::skip]
+pub const METEOR_SHOWERS: [MeteorShower; METEOR_SHOWER_COUNT] = [
+    shower!("LYR", "Lyrids",                  32.32, 271.0,  34.0, 1.1,  0.0, 49.0, 2.1,  18.0),
+    shower!("ETA", "eta Aquariids",           45.5,  338.0,  -1.0, 0.9,  0.4, 66.0, 2.4,  50.0),
+    shower!("SDA", "Southern delta Aquariids", 127.0, 340.0, -16.0, 0.8,  0.2, 41.0, 2.5,  25.0),
+    shower!("CAP", "alpha Capricornids",      127.0, 307.0, -10.0, 0.9,  0.3, 23.0, 2.5,   5.0),
+    shower!("PER", "Perseids",                140.0,  48.0,  58.0, 1.4,  0.2, 59.0, 2.2, 100.0),
+    shower!("KCG", "kappa Cygnids",           145.0, 286.0,  59.0, 0.3,  0.1, 23.0, 3.0,   3.0),
+    shower!("AUR", "Aurigids",                158.6,  91.0,  39.0, 1.1,  0.0, 66.0, 2.5,   6.0),
+    shower!("SPE", "September epsilon Perseids", 167.2, 48.0, 40.0, 1.0, 0.2, 64.0, 3.0,   5.0),
+    shower!("DRA", "Draconids",               195.4, 262.0,  54.0, 0.0,  0.0, 20.0, 2.6,  10.0),
+    shower!("STA", "Southern Taurids",        197.0,  32.0,   9.0, 0.8,  0.3, 27.0, 2.3,   5.0),
+    shower!("ORI", "Orionids",                208.0,  95.0,  16.0, 0.7,  0.1, 66.0, 2.5,  20.0),
+    shower!("NTA", "Northern Taurids",        230.0,  58.0,  22.0, 0.8,  0.2, 29.0, 2.3,   5.0),
+    shower!("LEO", "Leonids",                 235.27, 152.0, 22.0, 0.7, -0.4, 71.0, 2.5,  15.0),
+    shower!("MON", "Monocerotids",            257.0, 100.0,   8.0, 1.1,  0.0, 41.0, 3.0,   3.0),
+    shower!("GEM", "Geminids",                262.2, 112.0,  33.0, 1.0, -0.1, 35.0, 2.6, 150.0),
+    shower!("URS", "Ursids",                  270.7, 217.0,  76.0, 0.0,  0.0, 33.0, 3.0,  10.0),
+    shower!("QUA", "Quadrantids",             283.15, 230.0, 49.0, 0.8, -0.2, 41.0, 2.1,  80.0),
+];
+
+/// Solar longitude referred to the mean equinox of J2000.0 (TT), 0..2π
+///
+/// FK5 + annual aberration, precessed from the ecliptic of date to J2000.0.
+pub fn solar_longitude_j2000(jd_tt: f64) -> f64 {
+    let (sun_ecl, sun_dist_au) = astro::sun::geocent_ecl_pos(jd_tt);
+    let (long_fk5, lat_fk5) = astro::sun::ecl_coords_to_FK5(jd_tt, sun_ecl.long, sun_ecl.lat);
+    let long = long_fk5 + astro::aberr::sol_aberr(sun_dist_au);
+    let (long_j2000, _) = astro::precess::precess_ecl_coords(long, lat_fk5, jd_tt, J2000_JD);
+    astro::angle::limit_to_two_PI(long_j2000)
+}
+
+impl MeteorShower {
+    /// Peak instant (JD UTC) in the given Gregorian year
+    pub fn peak_in_year(&self, year: i32) -> Option<f64> {
+        let jan_first = astro::time::julian_day(&astro::time::Date {
+            year: i16::try_from(year).ok()?,
+            month: 1,
+            decimal_day: 1.0,
+            cal_type: astro::time::CalType::Gregorian,
+        });
+        let jd_tt_start = utc_to_tt_jd(jan_first);
+        if !jd_tt_start.is_finite() {
+            return None;
+        }
+        let jd_tt =
+            next_solar_longitude_tt(solar_longitude_j2000, jd_tt_start, self.peak_solar_long);
+        let jd_utc = tt_to_utc_jd(jd_tt);
+        jd_utc.is_finite().then_some(jd_utc)
+    }
+
+    /// Radiant `(α, δ)` of date at `jd_tt`, drift applied from the peak (rad)
+    fn radiant_at(&self, jd_tt: f64) -> (f64, f64) {
+        let d_long = wrap_pi(solar_longitude_j2000(jd_tt) - self.peak_solar_long);
+        let (ra, dec) = astro::precess::precess_eq_coords(
+            self.radiant_ra + self.drift_ra * d_long,
+            self.radiant_dec + self.drift_dec * d_long,
+            J2000_JD,
+            jd_tt,
+        );
+        (astro::angle::limit_to_two_PI(ra), dec)
+    }
+
+    /// Observable hourly rate for the given sky conditions
+    fn observable_rate(
+        &self,
+        radiant_alt: f64,
+        sun_alt: f64,
+        moon_alt: f64,
+        moon_illum: f64,
+        limiting_magnitude: f64,
+    ) -> f64 {
+        if radiant_alt <= 0.0 || sun_alt > NAUTICAL_TWILIGHT_RAD {
+            return 0.0;
+        }
+        let twilight = ((sun_alt - ASTRONOMICAL_TWILIGHT_RAD)
+            / (NAUTICAL_TWILIGHT_RAD - ASTRONOMICAL_TWILIGHT_RAD))
+            .clamp(0.0, 1.0);
+        let moon = if moon_alt > 0.0 { moon_illum } else { 0.0 };
+        let lm = limiting_magnitude
+            - TWILIGHT_MAGNITUDE_LOSS * twilight
+            - FULL_MOON_MAGNITUDE_LOSS * moon;
+        self.zhr * radiant_alt.sin() * self.population_index.powf(lm - ZHR_LIMITING_MAGNITUDE)
+    }
+
+    /// Sky conditions at one instant: `[jd_utc, radiant alt, Sun alt, Moon alt,
+    /// observable HR]` plus the Moon's illuminated fraction
+    fn sample(
+        &self,
+        jd_utc: f64,
+        lat_rad: f64,
+        lon_east_rad: f64,
+        limiting_magnitude: f64,
+    ) -> Option<([f64; NIGHT_PROFILE_STRIDE], f64)> {
+        let jd_tt = utc_to_tt_jd(jd_utc);
+        if !jd_tt.is_finite() {
+            return None;
+        }
+        let radiant_alt = altitude(jd_utc, lat_rad, lon_east_rad, self.radiant_at(jd_tt));
+        let sun_alt = altitude(jd_utc, lat_rad, lon_east_rad, sun_place_utc(jd_utc)?);
+        let moon_alt = altitude(
+            jd_utc,
+            lat_rad,
+            lon_east_rad,
+            moon_apparent_equatorial(jd_tt),
+        );
+        let moon_illum = moon_illuminated_fraction(jd_tt);
+        let rate = self.observable_rate(
+            radiant_alt,
+            sun_alt,
+            moon_alt,
+            moon_illum,
+            limiting_magnitude,
+        );
+        Some(([jd_utc, radiant_alt, sun_alt, moon_alt, rate], moon_illum))
+    }
+}
+
+/// Hourly sky profile of the night nearest to the peak
+///
+/// Samples run from local midnight − 12 h to + 12 h, the midnight being the
+/// one closest to the peak. Layout [25 × 5 f64], per sample: [JD UTC,
+/// radiant altitude (rad), Sun altitude (rad), Moon altitude (rad),
+/// observable hourly rate].
+pub fn meteor_night_profile(
+    shower: &MeteorShower,
+    peak_jd_utc: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    limiting_magnitude: f64,
+) -> Option<[f64; NIGHT_PROFILE_SAMPLES * NIGHT_PROFILE_STRIDE]> {
+    let noon = local_noon(peak_jd_utc, lon_east_rad);
+    let midnight = if peak_jd_utc >= noon {
+        noon + 0.5
+    } else {
+        noon - 0.5
+    };
+    let first = midnight - 0.5;
+
+    let mut out = [0.0; NIGHT_PROFILE_SAMPLES * NIGHT_PROFILE_STRIDE];
+    let (rows, _) = out.as_chunks_mut::<NIGHT_PROFILE_STRIDE>();
+    for (hour, row) in (0u32..).zip(rows.iter_mut()) {
+        let (sample, _) = shower.sample(
+            first + f64::from(hour) / 24.0,
+            lat_rad,
+            lon_east_rad,
+            limiting_magnitude,
+        )?;
+        *row = sample;
+    }
+    Some(out)
+}
+
+/// Shower summary for a year and an observer
+///
+/// Layout [14 f64]:
+/// - 0: peak JD UTC
+/// - 1: solar longitude of peak, J2000.0 (rad)
+/// - 2: ZHR
+/// - 3: velocity (km/s)
+/// - 4: population index r
+/// - 5, 6: radiant α, δ of date at peak (rad)
+/// - 7: radiant altitude at peak (rad)
+/// - 8: Moon illuminated fraction at peak
+/// - 9: Moon altitude at peak (rad)
+/// - 10: Sun altitude at peak (rad)
+/// - 11: observable hourly rate at peak
+/// - 12: JD UTC of the best hour of the peak night (max observable rate;
+///   NaN if the shower is not observable that night)
+/// - 13: observable hourly rate at that hour
+pub fn meteor_shower(
+    shower: &MeteorShower,
+    year: i32,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    limiting_magnitude: f64,
+) -> Option<[f64; METEOR_SHOWER_LEN]> {
+    let peak = shower.peak_in_year(year)?;
+    let (at_peak, moon_illum) = shower.sample(peak, lat_rad, lon_east_rad, limiting_magnitude)?;
+    let (radiant_ra, radiant_dec) = shower.radiant_at(utc_to_tt_jd(peak));
+
+    let profile = meteor_night_profile(shower, peak, lat_rad, lon_east_rad, limiting_magnitude)?;
+    let (rows, _) = profile.as_chunks::<NIGHT_PROFILE_STRIDE>();
+    let (best_jd, best_rate) = rows
+        .iter()
+        .filter(|row| row[4] > 0.0)
+        .map(|row| (row[0], row[4]))
+        .fold(
+            (f64::NAN, 0.0),
+            |best, cur| if cur.1 > best.1 { cur } else { best },
+        );
+
+    Some([
+        peak,
+        shower.peak_solar_long,
+        shower.zhr,
+        shower.velocity_km_s,
+        shower.population_index,
+        radiant_ra,
+        radiant_dec,
+        at_peak[1],
+        moon_illum,
+        at_peak[3],
+        at_peak[2],
+        at_peak[4],
+        best_jd,
+        best_rate,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shower(code: &str) -> Option<&'static MeteorShower> {
+        METEOR_SHOWERS.iter().find(|s| s.code == code)
+    }
+
+    #[test]
+    fn test_list_sorted_by_peak() {
+        assert!(
+            METEOR_SHOWERS
+                .windows(2)
+                .all(|w| w[0].peak_solar_long <= w[1].peak_solar_long)
+        );
+    }
+
+    #[test]
+    fn test_peaks_2025() {
+        // IMO 2025 calendar: Perseids λ☉ 140.0° on Aug 12/13,
+        // Geminids λ☉ 262.2° on Dec 14, Quadrantids λ☉ 283.15° on Jan 3
+        let per = shower("PER").and_then(|s| s.peak_in_year(2025));
+        assert!(
+            matches!(per, Some(t) if t > 2460899.5 && t < 2460900.5),
+            "{:?}",
+            per
+        );
+        let gem = shower("GEM").and_then(|s| s.peak_in_year(2025));
+        assert!(
+            matches!(gem, Some(t) if t > 2461023.5 && t < 2461024.5),
+            "{:?}",
+            gem
+        );
+        let qua = shower("QUA").and_then(|s| s.peak_in_year(2025));
+        assert!(
+            matches!(qua, Some(t) if t > 2460678.5 && t < 2460679.5),
+            "{:?}",
+            qua
+        );
+
+        // The solver lands on the J2000 longitude
+        let Some(t) = per else {
+            panic!("no Perseid peak in 2025");
+        };
+        let lambda = solar_longitude_j2000(utc_to_tt_jd(t)).to_degrees();
+        assert!((lambda - 140.0).abs() < 1.0e-6, "λ = {}", lambda);
+    }
+
+    #[test]
+    fn test_moon_interference_2025() {
+        // Full Moon on 2025-08-09 spoils the Perseids; the Geminids get a
+        // waning crescent (last quarter 2025-12-11)
+        let site = (45.0_f64.to_radians(), 10.0_f64.to_radians());
+        let per = shower("PER").and_then(|s| meteor_shower(s, 2025, site.0, site.1, 6.5));
+        let gem = shower("GEM").and_then(|s| meteor_shower(s, 2025, site.0, site.1, 6.5));
+        let (Some(per), Some(gem)) = (per, gem) else {
+            panic!("missing 2025 shower: {:?} {:?}", per, gem);
+        };
+        assert!(per[8] > 0.75 && per[8] < 0.95, "Perseids Moon {}", per[8]);
+        assert!(gem[8] > 0.15 && gem[8] < 0.45, "Geminids Moon {}", gem[8]);
+        // Best hour is dark with the radiant up, and below the ZHR
+        assert!(per[12].is_finite() && per[13] > 0.0 && per[13] < per[2]);
+        assert!(gem[13] > 0.0 && gem[13] < gem[2]);
+    }
+
+    #[test]
+    fn test_radiant_altitude_through_the_night() {
+        // Perseid radiant (δ ≈ +58°) is circumpolar at 50°N and climbs
+        // towards dawn; it never rises at 40°S
+        let Some(per) = shower("PER") else {
+            panic!("Perseids missing from the catalog");
+        };
+        let Some(peak) = per.peak_in_year(2025) else {
+            panic!("no Perseid peak in 2025");
+        };
+
+        let Some(north) = meteor_night_profile(per, peak, 0.87, 0.0, 6.5) else {
+            panic!("no night profile at 50°N");
+        };
+        let (rows, _) = north.as_chunks::<NIGHT_PROFILE_STRIDE>();
+        assert!(rows.iter().all(|row| row[1] > 0.0));
+        let max_alt = rows.iter().map(|row| row[1]).fold(f64::MIN, f64::max);
+        assert!(max_alt.to_degrees() > 60.0);
+        // Hourly spacing
+        assert!((rows[1][0] - rows[0][0] - 1.0 / 24.0).abs() < 1.0e-9);
+
+        let Some(south) = meteor_night_profile(per, peak, -0.7, 2.5, 6.5) else {
+            panic!("no night profile at 40°S");
+        };
+        let (rows, _) = south.as_chunks::<NIGHT_PROFILE_STRIDE>();
+        assert!(rows.iter().all(|row| row[1] < 0.0 && row[4] == 0.0));
+    }
+
+    #[test]
+    fn test_observable_rate_model() {
+        let Some(gem) = shower("GEM") else {
+            panic!("Geminids missing from the catalog");
+        };
+        let zenith = std::f64::consts::FRAC_PI_2;
+        let dark = -0.5;
+        // Radiant at zenith, dark moonless sky at LM 6.5: rate = ZHR
+        assert!((gem.observable_rate(zenith, dark, -0.1, 1.0, 6.5) - gem.zhr).abs() < 1.0e-9);
+        // A full Moon above the horizon costs two magnitudes
+        let moonlit = gem.observable_rate(zenith, dark, 0.5, 1.0, 6.5);
+        assert!((moonlit - gem.zhr / gem.population_index.powi(2)).abs() < 1.0e-9);
+        // Daylight or radiant below the horizon: nothing
+        assert_eq!(gem.observable_rate(zenith, 0.1, -0.1, 0.0, 6.5), 0.0);
+        assert_eq!(gem.observable_rate(-0.1, dark, -0.1, 0.0, 6.5), 0.0);
+    }
+}
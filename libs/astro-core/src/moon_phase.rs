@@ -0,0 +1,142 @@
+//! Moon phase, lunar age and the next principal phase
+//!
+//! The phase follows the apparent Moon-Sun elongation in longitude (0 at new
+//! moon, π at full moon). The 8 named phases are 45° sectors centred on the
+//! principal phases, so "New Moon" covers elongations within ±22.5°; indices
+//! follow `CulturalAdaptations::moon_phase_names` of the dioxus-app i18n
+//! layer (0 New Moon … 7 Waning Crescent). Lunar age is counted from the
+//! last true new moon, not from the mean synodic month.
+
+use starscalendars_domain::spiritual::MoonPhase;
+
+use crate::longitude::{moon_longitude_apparent, next_longitude_tt, solar_longitude_apparent};
+use crate::timescales::{tt_to_utc_jd, utc_to_tt_jd};
+use crate::{AU_KM, JulianDay};
+
+/// Mean motion of the elongation (rad/day, 360° / 29.530589 d)
+pub const SYNODIC_MEAN_RATE: f64 = 0.212_768_710; // @allow-wasm-const
+
+/// Width of a named phase sector (rad)
+const PHASE_SECTOR_RAD: f64 = std::f64::consts::FRAC_PI_4; // @allow-wasm-const
+
+/// Margin before the mean-rate estimate of the last new moon (days)
+///
+/// Covers the ±14 h spread of true new moons around the mean ones.
+const NEW_MOON_MARGIN_DAYS: f64 = 2.0; // @allow-wasm-const
+
+/// Apparent Moon-Sun elongation in longitude at a Julian Ephemeris Day, 0..2π
+pub fn elongation(jd_tt: f64) -> f64 {
+    astro::angle::limit_to_two_PI(moon_longitude_apparent(jd_tt) - solar_longitude_apparent(jd_tt))
+}
+
+/// Illuminated fraction of the Moon's disk (0..1) at a Julian Ephemeris Day
+pub fn moon_illuminated_fraction(jd_tt: f64) -> f64 {
+    let (sun_ecl, sun_dist_au) = astro::sun::geocent_ecl_pos(jd_tt);
+    let (moon_ecl, moon_dist_km) = astro::lunar::geocent_ecl_pos(jd_tt);
+    astro::lunar::illum_frac_frm_ecl_coords(
+        moon_ecl.long,
+        moon_ecl.lat,
+        sun_ecl.long,
+        moon_dist_km,
+        sun_dist_au * AU_KM,
+    )
+}
+
+/// Phase sector index (0..7) of an elongation, or `None` when not finite
+fn sector_index(elongation_rad: f64, sector_rad: f64, sectors: u8) -> Option<u8> {
+    let index = (elongation_rad / sector_rad)
+        .floor()
+        .rem_euclid(f64::from(sectors));
+    (0..sectors).find(|&i| f64::from(i) == index)
+}
+
+/// TT instant of the first principal phase (`quarter` 0 new, 1 first
+/// quarter, 2 full, 3 last quarter) at or after `jd_tt_start`
+pub fn next_principal_phase_tt(jd_tt_start: f64, quarter: u8) -> f64 {
+    let target = f64::from(quarter % 4) * std::f64::consts::FRAC_PI_2;
+    next_longitude_tt(elongation, jd_tt_start, target, SYNODIC_MEAN_RATE)
+}
+
+/// Moon phase at `jd_utc`; `None` on an invalid Julian Day or when the
+/// ephemeris fails
+pub fn moon_phase(jd_utc: f64) -> Option<MoonPhase> {
+    let jd_utc = JulianDay::new(jd_utc).ok()?.as_f64();
+    let jd_tt = utc_to_tt_jd(jd_utc);
+    let elong = elongation(jd_tt);
+    // Sectors are centred on the principal phases
+    let phase_index = sector_index(
+        elong + PHASE_SECTOR_RAD / 2.0,
+        PHASE_SECTOR_RAD,
+        MoonPhase::PHASE_COUNT,
+    )?;
+    let quarter = sector_index(elong, std::f64::consts::FRAC_PI_2, 4)?;
+    let next_quarter = (quarter + 1) % 4;
+
+    let last_new_moon =
+        next_principal_phase_tt(jd_tt - elong / SYNODIC_MEAN_RATE - NEW_MOON_MARGIN_DAYS, 0);
+    let next_phase_tt = next_principal_phase_tt(jd_tt, next_quarter);
+    if !last_new_moon.is_finite() || !next_phase_tt.is_finite() {
+        return None;
+    }
+    Some(MoonPhase {
+        age_days: (jd_tt - last_new_moon).max(0.0),
+        phase_index,
+        illuminated_fraction: moon_illuminated_fraction(jd_tt),
+        waxing: elong < std::f64::consts::PI,
+        next_phase_jd: tt_to_utc_jd(next_phase_tt),
+        next_phase_index: 2 * next_quarter,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2025-01-29 12:36 UTC new moon
+    const NEW_MOON: f64 = 2460705.025;
+
+    /// 2025-02-12 13:53 UTC full moon
+    const FULL_MOON: f64 = 2460719.0785;
+
+    #[test]
+    fn test_principal_phases_2025() {
+        let new_moon = tt_to_utc_jd(next_principal_phase_tt(utc_to_tt_jd(2460700.5), 0));
+        assert!((new_moon - NEW_MOON).abs() < 2.0 / 1440.0, "{}", new_moon);
+        let full_moon = tt_to_utc_jd(next_principal_phase_tt(utc_to_tt_jd(NEW_MOON), 2));
+        assert!(
+            (full_moon - FULL_MOON).abs() < 2.0 / 1440.0,
+            "{}",
+            full_moon
+        );
+    }
+
+    #[test]
+    fn test_waxing_gibbous() {
+        // 2025-02-09 17:00 UTC: 11.2 days after the new moon
+        let phase = moon_phase(NEW_MOON + 11.2);
+        let Some(phase) = phase else {
+            panic!("no Moon phase");
+        };
+        assert!((phase.age_days - 11.2).abs() < 1.0e-3, "{}", phase.age_days);
+        assert_eq!(phase.phase_index, 3);
+        assert!(phase.waxing);
+        assert!((0.8..0.95).contains(&phase.illuminated_fraction));
+        assert_eq!(phase.next_phase_index, 4);
+        assert!((phase.next_phase_jd - FULL_MOON).abs() < 2.0 / 1440.0);
+    }
+
+    #[test]
+    fn test_phase_sectors() {
+        // Just before the new moon: age is nearly a full month, sector 0
+        let phase = moon_phase(NEW_MOON - 0.1);
+        assert!(matches!(phase, Some(p) if p.phase_index == 0 && !p.waxing && p.age_days > 29.0));
+        assert!(matches!(phase, Some(p) if (p.next_phase_jd - NEW_MOON).abs() < 2.0 / 1440.0));
+        // Just after it: age restarts
+        let phase = moon_phase(NEW_MOON + 0.1);
+        assert!(matches!(phase, Some(p) if p.phase_index == 0 && p.waxing && p.age_days < 0.2));
+        // Three days after the full moon
+        let phase = moon_phase(FULL_MOON + 3.0);
+        assert!(matches!(phase, Some(p) if p.phase_index == 5 && p.next_phase_index == 6));
+        assert!(moon_phase(f64::NAN).is_none());
+    }
+}
@@ -0,0 +1,462 @@
+//! Orion–Sun alignment event solver (Tatev)
+//!
+//! Finds the next instant at which Orion's belt reaches a configured geometry
+//! relative to the Sun for an observer. The belt is represented by Alnilam
+//! (its centre) and the direction towards Alnitak; star places come from the
+//! embedded catalog (proper motion → precession → nutation → aberration).
+//!
+//! ## Alignment definitions
+//! - `PositionAngle`: position angle of the Sun seen from Alnilam, measured
+//!   from the belt direction (Alnilam → Alnitak), equals the target. Target 0
+//!   puts the Sun on the extension of the belt beyond Alnitak, π beyond
+//!   Mintaka. Sky geometry only, so about once a year.
+//! - `RelativeAzimuth`: azimuth of Alnilam minus azimuth of the Sun equals the
+//!   target, for the observer's horizon. Daily.
+//!
+//! In both modes a root counts only while Alnilam's geometric altitude is
+//! inside the altitude window and the Sun's geometric altitude is at or below
+//! the Sun limit (e.g. -18° for astronomical night). The limits are applied to
+//! both ends of each scan bracket and again at the refined root; with limits
+//! set, the position-angle scan uses the fine azimuth step so nights are
+//! sampled.
+//!
+//! Roots are bracketed on a coarse scan and refined with a safeguarded Newton
+//! iteration (numeric derivative, bisection fallback), as in
+//! `next_winter_solstice_from`. Times are JD UTC in and out; ephemerides are
+//! evaluated in TT.
+
+use crate::bodies::sun_apparent_equatorial;
+use crate::horizon::{altitude, azimuth};
+use crate::stars::{ALNILAM_HR, ALNITAK_HR, star_apparent_place, star_by_hr};
+use crate::timescales::utc_to_tt_jd;
+use crate::wrap_pi;
+
+/// Search horizon, in days (two years plus slack)
+const SEARCH_HORIZON_DAYS: f64 = 732.0; // @allow-wasm-const
+
+/// Scan step for the yearly position-angle condition, in days
+const PA_SCAN_STEP_DAYS: f64 = 1.0; // @allow-wasm-const
+
+/// Scan step for the daily azimuth condition and the visibility limits, in
+/// days (15 min)
+const AZIMUTH_SCAN_STEP_DAYS: f64 = 1.0 / 96.0; // @allow-wasm-const
+
+/// Newton numeric-derivative half-step, in days (~8.6 s)
+const NEWTON_STEP_DAYS: f64 = 1.0e-4; // @allow-wasm-const
+
+/// Refinement tolerance on the bracket width, in days (~1 ms)
+const ROOT_TOLERANCE_DAYS: f64 = 1.0e-8; // @allow-wasm-const
+
+/// Maximum refinement iterations
+const MAX_REFINE_ITERS: u32 = 60; // @allow-wasm-const
+
+/// Which geometry defines the alignment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlignmentMode {
+    /// Sun's position angle from Alnilam relative to the belt direction
+    PositionAngle,
+    /// Alnilam's azimuth minus the Sun's azimuth
+    RelativeAzimuth,
+}
+
+impl AlignmentMode {
+    /// Decode the WASM mode code (0 position angle, 1 relative azimuth)
+    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(Self::PositionAngle),
+            1 => Some(Self::RelativeAzimuth),
+            _ => None,
+        }
+    }
+}
+
+/// Alignment definition
+#[derive(Debug, Clone, Copy)]
+pub struct AlignmentConfig {
+    /// Geometry to solve for
+    pub mode: AlignmentMode,
+    /// Target angle (rad)
+    pub target_rad: f64,
+    /// Lowest accepted altitude of Alnilam (rad)
+    pub belt_alt_min_rad: f64,
+    /// Highest accepted altitude of Alnilam (rad)
+    pub belt_alt_max_rad: f64,
+    /// Highest accepted altitude of the Sun (rad); negative = depression
+    pub sun_alt_max_rad: f64,
+}
+
+impl AlignmentConfig {
+    /// Default: Sun on the belt line beyond Alnitak, no visibility constraints
+    pub const DEFAULT: Self = Self {
+        mode: AlignmentMode::PositionAngle,
+        target_rad: 0.0,
+        belt_alt_min_rad: -std::f64::consts::FRAC_PI_2,
+        belt_alt_max_rad: std::f64::consts::FRAC_PI_2,
+        sun_alt_max_rad: std::f64::consts::FRAC_PI_2,
+    };
+
+    /// Validated alignment; `None` for non-finite angles or an empty
+    /// altitude window
+    pub fn new(
+        mode: AlignmentMode,
+        target_rad: f64,
+        belt_alt_min_rad: f64,
+        belt_alt_max_rad: f64,
+        sun_alt_max_rad: f64,
+    ) -> Option<Self> {
+        let angles = [
+            target_rad,
+            belt_alt_min_rad,
+            belt_alt_max_rad,
+            sun_alt_max_rad,
+        ];
+        if angles.iter().any(|a| !a.is_finite()) || belt_alt_min_rad > belt_alt_max_rad {
+            return None;
+        }
+        Some(Self {
+            mode,
+            target_rad,
+            belt_alt_min_rad,
+            belt_alt_max_rad,
+            sun_alt_max_rad,
+        })
+    }
+
+    /// Whether any altitude limit can reject an instant
+    fn has_visibility_limits(&self) -> bool {
+        self.belt_alt_min_rad > -std::f64::consts::FRAC_PI_2
+            || self.belt_alt_max_rad < std::f64::consts::FRAC_PI_2
+            || self.sun_alt_max_rad < std::f64::consts::FRAC_PI_2
+    }
+
+    /// Coarse scan step, in days
+    fn scan_step_days(&self) -> f64 {
+        match self.mode {
+            AlignmentMode::PositionAngle if !self.has_visibility_limits() => PA_SCAN_STEP_DAYS,
+            _ => AZIMUTH_SCAN_STEP_DAYS,
+        }
+    }
+}
+
+/// Apparent equatorial places needed by the alignment (rad)
+#[derive(Debug, Clone, Copy)]
+struct SkySnapshot {
+    sun: (f64, f64),
+    alnilam: (f64, f64),
+    alnitak: (f64, f64),
+}
+
+impl SkySnapshot {
+    /// Exact places at `jd_utc`
+    fn at(jd_utc: f64) -> Option<Self> {
+        let jd_tt = utc_to_tt_jd(jd_utc);
+        if !jd_tt.is_finite() {
+            return None;
+        }
+        Some(Self {
+            sun: sun_apparent_equatorial(jd_tt),
+            alnilam: star_apparent_place(star_by_hr(ALNILAM_HR)?, jd_tt),
+            alnitak: star_apparent_place(star_by_hr(ALNITAK_HR)?, jd_tt),
+        })
+    }
+
+    /// Linear interpolation between two snapshots (RA across 0/2π handled)
+    fn lerp(&self, other: &Self, frac: f64) -> Self {
+        let mix = |a: (f64, f64), b: (f64, f64)| {
+            (a.0 + frac * wrap_pi(b.0 - a.0), a.1 + frac * (b.1 - a.1))
+        };
+        Self {
+            sun: mix(self.sun, other.sun),
+            alnilam: mix(self.alnilam, other.alnilam),
+            alnitak: mix(self.alnitak, other.alnitak),
+        }
+    }
+}
+
+/// Position angle of point 2 seen from point 1, from north through east (rad)
+fn position_angle(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let d_ra = to.0 - from.0;
+    d_ra.sin()
+        .atan2(from.1.cos() * to.1.tan() - from.1.sin() * d_ra.cos())
+}
+
+/// Signed alignment residual in (-π, π]; zero at the event
+fn residual(
+    config: &AlignmentConfig,
+    jd_utc: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    sky: &SkySnapshot,
+) -> f64 {
+    match config.mode {
+        AlignmentMode::PositionAngle => wrap_pi(
+            position_angle(sky.alnilam, sky.sun)
+                - position_angle(sky.alnilam, sky.alnitak)
+                - config.target_rad,
+        ),
+        AlignmentMode::RelativeAzimuth => wrap_pi(
+            azimuth(jd_utc, lat_rad, lon_east_rad, sky.alnilam)
+                - azimuth(jd_utc, lat_rad, lon_east_rad, sky.sun)
+                - config.target_rad,
+        ),
+    }
+}
+
+/// Altitude window and Sun limit at the candidate instant
+fn visibility_ok(
+    config: &AlignmentConfig,
+    jd_utc: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    sky: &SkySnapshot,
+) -> bool {
+    let belt_alt = altitude(jd_utc, lat_rad, lon_east_rad, sky.alnilam);
+    let sun_alt = altitude(jd_utc, lat_rad, lon_east_rad, sky.sun);
+    (config.belt_alt_min_rad..=config.belt_alt_max_rad).contains(&belt_alt)
+        && sun_alt <= config.sun_alt_max_rad
+}
+
+/// Refine a bracketed root with Newton steps, falling back to bisection
+fn refine_root(
+    config: &AlignmentConfig,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    mut lo: f64,
+    mut hi: f64,
+) -> Option<f64> {
+    let f = |t: f64| -> Option<f64> {
+        Some(residual(
+            config,
+            t,
+            lat_rad,
+            lon_east_rad,
+            &SkySnapshot::at(t)?,
+        ))
+    };
+
+    let mut f_lo = f(lo)?;
+    let mut t = 0.5 * (lo + hi);
+    for _ in 0..MAX_REFINE_ITERS {
+        let f_t = f(t)?;
+        if f_t == 0.0 || hi - lo < ROOT_TOLERANCE_DAYS {
+            return Some(t);
+        }
+        // Shrink the bracket around the sign change
+        if (f_t < 0.0) == (f_lo < 0.0) {
+            lo = t;
+            f_lo = f_t;
+        } else {
+            hi = t;
+        }
+        let slope =
+            wrap_pi(f(t + NEWTON_STEP_DAYS)? - f(t - NEWTON_STEP_DAYS)?) / (2.0 * NEWTON_STEP_DAYS);
+        let newton = t - f_t / slope;
+        t = if slope.is_finite() && slope != 0.0 && newton > lo && newton < hi {
+            newton
+        } else {
+            0.5 * (lo + hi)
+        };
+    }
+    Some(t)
+}
+
+/// Next alignment after `jd_utc_start` (JD UTC), if any within the search
+/// horizon
+pub fn next_orion_alignment(
+    config: &AlignmentConfig,
+    jd_utc_start: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+) -> Option<f64> {
+    let step = config.scan_step_days();
+    let end = jd_utc_start + SEARCH_HORIZON_DAYS;
+
+    // Daily exact nodes, linearly interpolated during the scan
+    let mut node_t = jd_utc_start;
+    let mut node_a = SkySnapshot::at(node_t)?;
+    let mut node_b = SkySnapshot::at(node_t + 1.0)?;
+    let mut sky_at = |t: f64| -> Option<SkySnapshot> {
+        while t > node_t + 1.0 {
+            node_t += 1.0;
+            node_a = node_b;
+            node_b = SkySnapshot::at(node_t + 1.0)?;
+        }
+        Some(node_a.lerp(&node_b, t - node_t))
+    };
+
+    let mut t_prev = jd_utc_start;
+    let sky_prev = sky_at(t_prev)?;
+    let mut f_prev = residual(config, t_prev, lat_rad, lon_east_rad, &sky_prev);
+    let mut visible_prev = visibility_ok(config, t_prev, lat_rad, lon_east_rad, &sky_prev);
+    while t_prev < end {
+        let t = t_prev + step;
+        let sky = sky_at(t)?;
+        let f = residual(config, t, lat_rad, lon_east_rad, &sky);
+        let visible = visibility_ok(config, t, lat_rad, lon_east_rad, &sky);
+        // Sign change away from the ±π wrap-around, visible at both ends
+        let bracketed = (f_prev < 0.0) != (f < 0.0)
+            && f_prev.abs() < std::f64::consts::FRAC_PI_2
+            && f.abs() < std::f64::consts::FRAC_PI_2
+            && visible_prev
+            && visible;
+        if bracketed {
+            let root = refine_root(config, lat_rad, lon_east_rad, t_prev, t)?;
+            let sky = SkySnapshot::at(root)?;
+            if root > jd_utc_start && visibility_ok(config, root, lat_rad, lon_east_rad, &sky) {
+                return Some(root);
+            }
+        }
+        t_prev = t;
+        f_prev = f;
+        visible_prev = visible;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tatev monastery, Armenia
+    const TATEV_LAT_DEG: f64 = 39.3797;
+    const TATEV_LON_DEG: f64 = 46.2500;
+
+    fn tatev() -> (f64, f64) {
+        (TATEV_LAT_DEG.to_radians(), TATEV_LON_DEG.to_radians())
+    }
+
+    #[test]
+    fn test_position_angle_alignment_puts_sun_on_belt_line() {
+        let (lat, lon) = tatev();
+        // 2025-01-01 0h UTC
+        let event = next_orion_alignment(&AlignmentConfig::DEFAULT, 2460676.5, lat, lon);
+        let (Some(t), Some(sky)) = (event, event.and_then(SkySnapshot::at)) else {
+            panic!("no alignment found");
+        };
+        // Sun lies on the great circle through Alnilam and Alnitak
+        let unit = |(ra, dec): (f64, f64)| [dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin()];
+        let (a, b, s) = (unit(sky.alnilam), unit(sky.alnitak), unit(sky.sun));
+        let n = [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ];
+        let n_len = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+        let off_circle = ((n[0] * s[0] + n[1] * s[1] + n[2] * s[2]) / n_len).asin();
+        assert!(
+            off_circle.abs() < 1.0e-6,
+            "Sun off belt circle: {}",
+            off_circle
+        );
+        // ... on the Alnitak side of Alnilam
+        let along = (b[0] - a[0]) * s[0] + (b[1] - a[1]) * s[1] + (b[2] - a[2]) * s[2];
+        assert!(along > 0.0);
+        // Hand check: the J2000 belt circle beyond Alnitak meets the ecliptic
+        // at λ ≈ 233.5° (233.8° of date), the Sun's longitude on 2025-11-16
+        assert!(t > 2460994.5 && t < 2460996.5, "event JD {}", t);
+    }
+
+    #[test]
+    fn test_relative_azimuth_alignment_on_winter_night() {
+        let (lat, lon) = tatev();
+        // Belt opposite the Sun in azimuth, Sun below -18°, belt 10°..60° high
+        let config = AlignmentConfig {
+            mode: AlignmentMode::RelativeAzimuth,
+            target_rad: std::f64::consts::PI,
+            belt_alt_min_rad: 10.0_f64.to_radians(),
+            belt_alt_max_rad: 60.0_f64.to_radians(),
+            sun_alt_max_rad: -18.0_f64.to_radians(),
+        };
+        // 2024-12-13 12h UTC; Sun at RA ≈ 17.4h, opposite the belt (5.6h), so
+        // both cross the meridian near local midnight (~20h UTC at Tatev)
+        let start = 2460658.0;
+        let event = next_orion_alignment(&config, start, lat, lon);
+        assert!(
+            matches!(event, Some(t) if t > start && t < start + 0.5),
+            "{:?}",
+            event
+        );
+
+        if let (Some(t), Some(sky)) = (event, event.and_then(SkySnapshot::at)) {
+            let r = residual(&config, t, lat, lon, &sky);
+            assert!(r.abs() < 1.0e-7, "residual {}", r);
+            assert!(visibility_ok(&config, t, lat, lon, &sky));
+            // Brute-force cross-check: no earlier accepted crossing at 2-minute resolution
+            let mut probe = start;
+            let Some(sky_start) = SkySnapshot::at(start) else {
+                panic!("no sky at JD {}", start);
+            };
+            let mut f_prev = residual(&config, probe, lat, lon, &sky_start);
+            while probe + 2.0 / 1440.0 < t - 2.0 / 1440.0 {
+                let next = probe + 2.0 / 1440.0;
+                let sky_next = SkySnapshot::at(next);
+                let Some(sky_next) = sky_next else {
+                    panic!("no sky snapshot at JD {}", next);
+                };
+                let f = residual(&config, next, lat, lon, &sky_next);
+                let crossing = (f < 0.0) != (f_prev < 0.0) && f.abs() < 1.0;
+                assert!(
+                    !(crossing && visibility_ok(&config, next, lat, lon, &sky_next)),
+                    "earlier alignment near JD {}",
+                    next
+                );
+                f_prev = f;
+                probe = next;
+            }
+        }
+    }
+
+    #[test]
+    fn test_position_angle_alignment_with_sun_depression() {
+        let (lat, lon) = tatev();
+        let night = AlignmentConfig {
+            sun_alt_max_rad: -18.0_f64.to_radians(),
+            ..AlignmentConfig::DEFAULT
+        };
+        let sun_alt = |t: f64| {
+            let Some(sky) = SkySnapshot::at(t) else {
+                panic!("no sky at JD {}", t);
+            };
+            altitude(t, lat, lon, sky.sun)
+        };
+
+        // The 2025 event falls at night at Tatev (Sun ≈ -50°) and passes the limit
+        let Some(t) = next_orion_alignment(&night, 2460676.5, lat, lon) else {
+            panic!("no night alignment in 2025");
+        };
+        assert!(t > 2460994.5 && t < 2460996.5, "event JD {}", t);
+        assert!(sun_alt(t) <= night.sun_alt_max_rad);
+
+        // The 2026 event falls in daylight (Sun ≈ +16°) and is skipped
+        let start = 2460996.5;
+        let Some(day) = next_orion_alignment(&AlignmentConfig::DEFAULT, start, lat, lon) else {
+            panic!("no alignment in 2026");
+        };
+        assert!(
+            sun_alt(day) > 0.0,
+            "2026 event at Sun altitude {}",
+            sun_alt(day)
+        );
+        let event = next_orion_alignment(&night, start, lat, lon);
+        assert!(
+            event.is_none_or(|t| t > day + 1.0 && sun_alt(t) <= night.sun_alt_max_rad),
+            "{:?}",
+            event
+        );
+    }
+
+    #[test]
+    fn test_config_validation() {
+        let config = |mode, target, min, max, sun| {
+            AlignmentMode::from_code(mode)
+                .and_then(|mode| AlignmentConfig::new(mode, target, min, max, sun))
+        };
+        assert!(config(2, 0.0, 0.0, 1.0, 0.0).is_none());
+        assert!(config(0, f64::NAN, 0.0, 1.0, 0.0).is_none());
+        assert!(config(1, 0.0, 1.0, 0.0, 0.0).is_none());
+        assert!(matches!(
+            config(1, 0.5, 0.0, 1.0, -0.1),
+            Some(c) if c.mode == AlignmentMode::RelativeAzimuth && c.target_rad == 0.5
+        ));
+    }
+}
@@ -0,0 +1,180 @@
+//! Physical ephemerides for disk orientation
+//!
+//! One call for the Sun, Mars and Jupiter returning what a texture-mapped
+//! mesh needs to be oriented as seen from Earth: the body-centric declination
+//! of the Earth (sub-Earth latitude) and of the Sun, the longitude of the
+//! central meridian and the position angle of the rotation axis. For the Sun
+//! the Carrington rotation number is added.
+//!
+//! Body indices follow the planet indices used elsewhere (3 = Mars,
+//! 4 = Jupiter); the Sun uses `PHYSICAL_BODY_SUN`.
+
+/// Body index of the Sun for `physical_ephemeris` (planets keep 0..8)
+pub const PHYSICAL_BODY_SUN: usize = 9; // @allow-wasm-const
+
+/// Physical ephemeris layout length
+pub const PHYSICAL_EPHEMERIS_LEN: usize = 6;
+
+/// Largest Carrington rotation reachable by the rotation search (~year 3050)
+const CARRINGTON_SEARCH_SPAN: i32 = 16384; // @allow-wasm-const
+
+/// Fractional Carrington rotation number for a Julian day
+///
+/// The integer part is the rotation in progress (astro::sun::synodic_rot gives
+/// the start of each rotation), the fraction is the elapsed part of it.
+/// `None` before rotation 1 (1853-11-09) or beyond the search span.
+pub fn carrington_rotation(julian_day: f64) -> Option<f64> {
+    let start_of = |c: i32| astro::sun::synodic_rot(i64::from(c));
+
+    if julian_day < start_of(1) {
+        return None;
+    }
+
+    // Largest c with start_of(c) <= julian_day, by binary lifting
+    let mut c = 1;
+    let mut step = CARRINGTON_SEARCH_SPAN / 2;
+    while step > 0 {
+        if start_of(c + step) <= julian_day {
+            c += step;
+        }
+        step /= 2;
+    }
+    if c >= CARRINGTON_SEARCH_SPAN - 1 {
+        return None;
+    }
+
+    let start = start_of(c);
+    let next = start_of(c + 1);
+    Some(f64::from(c) + (julian_day - start) / (next - start))
+}
+
+/// Physical ephemeris of the Sun, Mars or Jupiter
+///
+/// Layout [6 f64]:
+/// - 0: De — body-centric declination of the Earth (Sun: B0, heliographic
+///   latitude of the disk centre) (rad)
+/// - 1: Ds — body-centric declination of the Sun (Sun: NaN) (rad)
+/// - 2: central meridian longitude (Mars: ω, Jupiter: System II ω2,
+///   Sun: heliographic L0) (rad)
+/// - 3: P — position angle of the northern rotation pole (rad)
+/// - 4: Carrington rotation number, fractional (Sun only, else NaN)
+/// - 5: secondary central meridian (Jupiter: System I ω1, else NaN) (rad)
+///
+/// Returns `None` for bodies without a physical ephemeris.
+pub fn physical_ephemeris(
+    body_index: usize,
+    julian_day: f64,
+) -> Option<[f64; PHYSICAL_EPHEMERIS_LEN]> {
+    let (nut_long, nut_oblq) = astro::nutation::nutation(julian_day);
+    let mean_oblq = astro::ecliptic::mn_oblq_IAU(julian_day);
+
+    match body_index {
+        3 => {
+            let pole =
+                astro::planet::mars::north_pol_ecl_coords(astro::time::julian_cent(julian_day));
+            let eph =
+                astro::planet::mars::ephemeris(julian_day, &pole, mean_oblq, nut_long, nut_oblq);
+            Some([
+                eph.De,
+                eph.Ds,
+                astro::angle::limit_to_two_PI(eph.w),
+                eph.P,
+                f64::NAN,
+                f64::NAN,
+            ])
+        }
+        4 => {
+            let eph = astro::planet::jupiter::ephemeris(julian_day, mean_oblq, nut_long, nut_oblq);
+            Some([
+                eph.De,
+                eph.Ds,
+                astro::angle::limit_to_two_PI(eph.w2),
+                eph.P,
+                f64::NAN,
+                astro::angle::limit_to_two_PI(eph.w1),
+            ])
+        }
+        PHYSICAL_BODY_SUN => {
+            let (sun_ecl, sun_dist_au) = astro::sun::geocent_ecl_pos(julian_day);
+            let (sun_long_fk5, _) =
+                astro::sun::ecl_coords_to_FK5(julian_day, sun_ecl.long, sun_ecl.lat);
+            let app_long = sun_long_fk5 + astro::aberr::sol_aberr(sun_dist_au);
+            let (p, b0, l0) = astro::sun::ephemeris(
+                julian_day,
+                app_long,
+                app_long + nut_long,
+                mean_oblq + nut_oblq,
+            );
+            Some([
+                b0,
+                f64::NAN,
+                l0,
+                p,
+                carrington_rotation(julian_day).unwrap_or(f64::NAN),
+                f64::NAN,
+            ])
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sun_ephemeris_meeus_example_29a() {
+        // Meeus, Example 29.a: 1992 October 13.0 TD
+        // P = 26.27°, B0 = +5.99°, L0 = 238.63°; rotation 1861
+        // (162 synodic rotations after 1699, see Example 29.b)
+        let eph = physical_ephemeris(PHYSICAL_BODY_SUN, 2448908.50068);
+        let Some(eph) = eph else {
+            panic!("no solar ephemeris for 1992 October 13");
+        };
+        assert!(
+            (eph[3].to_degrees() - 26.27).abs() < 0.02,
+            "P = {}",
+            eph[3].to_degrees()
+        );
+        assert!(
+            (eph[0].to_degrees() - 5.99).abs() < 0.02,
+            "B0 = {}",
+            eph[0].to_degrees()
+        );
+        assert!(
+            (eph[2].to_degrees() - 238.63).abs() < 0.05,
+            "L0 = {}",
+            eph[2].to_degrees()
+        );
+        assert!(eph[4].floor() == 1861.0, "Carrington = {}", eph[4]);
+    }
+
+    #[test]
+    fn test_carrington_rotation_start() {
+        // Meeus, Example 29.b: rotation 1699 starts on JD 2444480.7233
+        let c = carrington_rotation(2444480.75);
+        assert!(matches!(c, Some(c) if c.floor() == 1699.0), "{:?}", c);
+        assert!(carrington_rotation(2398000.0).is_none());
+    }
+
+    #[test]
+    fn test_mars_and_jupiter_ephemeris() {
+        // Meeus, Example 42.a: 1992 November 9.0 TD, Mars De = +12.44°, P = 347.64°
+        let mars = physical_ephemeris(3, 2448935.500683);
+        let Some(mars) = mars else {
+            panic!("no Mars ephemeris for 1992 November 9");
+        };
+        assert!(
+            (mars[0].to_degrees() - 12.44).abs() < 0.05,
+            "De = {}",
+            mars[0].to_degrees()
+        );
+        let p = astro::angle::limit_to_two_PI(mars[3]).to_degrees();
+        assert!((p - 347.64).abs() < 0.1, "P = {}", p);
+        assert!(mars[4].is_nan());
+
+        let jupiter = physical_ephemeris(4, 2451545.0);
+        assert!(matches!(jupiter, Some(j) if j[0].abs() < 4.0_f64.to_radians()));
+        assert!(physical_ephemeris(5, 2451545.0).is_none());
+    }
+}
@@ -0,0 +1,193 @@
+//! Traditional planetary hours and the ruler of the day
+//!
+//! The planetary day runs from local sunrise to the next sunrise. Daylight
+//! and night are each split into 12 unequal hours; the first hour belongs to
+//! the ruler of the weekday and the following hours cycle through the
+//! Chaldean order (Saturn, Jupiter, Mars, Sun, Venus, Mercury, Moon).
+//!
+//! Rulers are encoded by their Chaldean index: 0 Saturn, 1 Jupiter, 2 Mars,
+//! 3 Sun, 4 Venus, 5 Mercury, 6 Moon. The weekday is taken from the
+//! observer's local date at sunrise, which is the civil date for any
+//! timezone within ±6 h of local mean time; the frontend formats the
+//! returned JD UTC values in the profile timezone.
+
+use crate::horizon::{Crossing, SUNRISE_ALTITUDE_RAD, local_noon, sun_altitude_crossing};
+
+/// Number of rulers in the Chaldean order
+pub const CHALDEAN_RULERS: u8 = 7;
+
+/// Chaldean index of the day ruler by weekday (0 = Sunday … 6 = Saturday)
+const WEEKDAY_RULERS: [u8; 7] = [3, 6, 2, 5, 1, 4, 0];
+
+/// Planetary hours in a planetary day
+pub const PLANETARY_HOURS_PER_DAY: usize = 24;
+
+/// Planetary hours in daylight (and in the night)
+const HOURS_PER_HALF: u8 = 12;
+
+/// Planetary hours buffer length
+pub const PLANETARY_HOURS_LEN: usize = 3 + (PLANETARY_HOURS_PER_DAY + 1) + PLANETARY_HOURS_PER_DAY;
+
+/// Weekday of a local date given as the Julian Day Number of its noon (0 = Sunday)
+fn weekday(day_number: f64) -> u8 {
+    // JD 2451545 (2000-01-01) was a Saturday
+    let weekday = (day_number + 1.0).rem_euclid(7.0);
+    (0..CHALDEAN_RULERS)
+        .find(|&day| f64::from(day) == weekday)
+        .unwrap_or(0)
+}
+
+/// Ruler of hour `hour` (0..23) of a day ruled by `day_ruler`
+fn hour_ruler(day_ruler: u8, hour: u8) -> u8 {
+    (day_ruler + hour % CHALDEAN_RULERS) % CHALDEAN_RULERS
+}
+
+/// Sunrise, sunset and next sunrise of the local day around `noon`
+fn solar_day(noon: f64, lat_rad: f64, lon_east_rad: f64) -> Option<(f64, f64, f64)> {
+    let crossing = |guess: f64, direction: Crossing| {
+        sun_altitude_crossing(
+            guess,
+            lat_rad,
+            lon_east_rad,
+            SUNRISE_ALTITUDE_RAD,
+            direction,
+        )
+    };
+    let sunrise = crossing(noon - 0.25, Crossing::Rising)?;
+    let sunset = crossing(noon + 0.25, Crossing::Setting)?;
+    let next_sunrise = crossing(noon + 0.75, Crossing::Rising)?;
+    // Reject crossings that slipped to a neighbouring day (near polar day/night)
+    let ordered = noon - 0.5 < sunrise
+        && sunrise < noon
+        && noon < sunset
+        && sunset < next_sunrise
+        && next_sunrise < noon + 1.0;
+    ordered.then_some((sunrise, sunset, next_sunrise))
+}
+
+/// Planetary hours of the planetary day containing `jd_utc`
+///
+/// Layout [52 f64]:
+/// - 0: ruler of the day (Chaldean index)
+/// - 1: index of the current planetary hour (0..11 day, 12..23 night)
+/// - 2: ruler of the current hour
+/// - 3..=27: 25 hour boundaries, JD UTC (3 = sunrise, 15 = sunset,
+///   27 = next sunrise)
+/// - 28..=51: ruler of each of the 24 hours
+///
+/// `None` when the Sun does not rise or set (polar day or night).
+pub fn planetary_hours(
+    jd_utc: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+) -> Option<[f64; PLANETARY_HOURS_LEN]> {
+    let mut noon = local_noon(jd_utc, lon_east_rad);
+    let mut day = solar_day(noon, lat_rad, lon_east_rad)?;
+    if jd_utc < day.0 {
+        // Before sunrise: still the previous planetary day
+        noon -= 1.0;
+        day = solar_day(noon, lat_rad, lon_east_rad)?;
+    }
+    let (sunrise, sunset, next_sunrise) = day;
+
+    let day_number = (noon + lon_east_rad / std::f64::consts::TAU).round();
+    let day_ruler = WEEKDAY_RULERS[usize::from(weekday(day_number))];
+
+    let mut out = [0.0; PLANETARY_HOURS_LEN];
+    let (header, rest) = out.split_at_mut(3);
+    let (boundaries, rulers) = rest.split_at_mut(PLANETARY_HOURS_PER_DAY + 1);
+
+    let day_hour = (sunset - sunrise) / f64::from(HOURS_PER_HALF);
+    let night_hour = (next_sunrise - sunset) / f64::from(HOURS_PER_HALF);
+    for (hour, boundary) in (0u8..).zip(boundaries.iter_mut()) {
+        *boundary = if hour < HOURS_PER_HALF {
+            sunrise + f64::from(hour) * day_hour
+        } else {
+            sunset + f64::from(hour - HOURS_PER_HALF) * night_hour
+        };
+    }
+    // Exact crossing at the end of the night
+    if let Some(last) = boundaries.last_mut() {
+        *last = next_sunrise;
+    }
+
+    let mut current = 0;
+    for (hour, ruler) in (0u8..).zip(rulers.iter_mut()) {
+        *ruler = f64::from(hour_ruler(day_ruler, hour));
+        if boundaries
+            .get(usize::from(hour))
+            .is_some_and(|&start| jd_utc >= start)
+        {
+            current = hour;
+        }
+    }
+
+    header[0] = f64::from(day_ruler);
+    header[1] = f64::from(current);
+    header[2] = f64::from(hour_ruler(day_ruler, current));
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LONDON: (f64, f64) = (0.899_189_5, -0.002_239_8); // 51.5194°N, 0.1283°W
+
+    #[test]
+    fn test_sunday_in_london() {
+        // 2025-01-05 (Sunday) 12:00 UTC: day of the Sun; sunrise ~08:05,
+        // sunset ~16:07 UTC
+        let hours = planetary_hours(2460681.0, LONDON.0, LONDON.1);
+        let Some(h) = hours else {
+            panic!("no planetary hours");
+        };
+        assert_eq!(h[0], 3.0);
+        let sunrise = (h[3] - 2460680.5) * 24.0;
+        let sunset = (h[15] - 2460680.5) * 24.0;
+        assert!((sunrise - 8.07).abs() < 0.1, "sunrise {}", sunrise);
+        assert!((sunset - 16.1).abs() < 0.1, "sunset {}", sunset);
+        // 12:00 UTC falls in the 5th/6th day hour; short winter hours (~40 min)
+        assert!(h[1] == 4.0 || h[1] == 5.0, "hour {}", h[1]);
+        assert!(((h[4] - h[3]) * 1440.0 - 40.0).abs() < 2.0);
+        // First hour belongs to the day ruler, then the Chaldean order
+        assert_eq!(h[28], 3.0);
+        assert_eq!(h[29], 4.0);
+        assert_eq!(h[30], 5.0);
+        assert_eq!(h[31], 6.0);
+        assert_eq!(h[32], 0.0);
+    }
+
+    #[test]
+    fn test_before_sunrise_is_previous_planetary_day() {
+        // 2025-01-05 05:00 UTC is still Saturday's night (Saturn's day)
+        let hours = planetary_hours(2460680.5 + 5.0 / 24.0, LONDON.0, LONDON.1);
+        assert!(
+            matches!(hours, Some(h) if h[0] == 0.0 && h[1] >= 12.0),
+            "{:?}",
+            hours.map(|h| h[..3].to_vec())
+        );
+        // The last hour of each day hands over to the next day's ruler:
+        // Saturday's 24th hour is followed by the Sun on Sunday
+        if let Some(h) = hours {
+            assert_eq!((h[51] + 1.0) % 7.0, 3.0);
+        }
+    }
+
+    #[test]
+    fn test_week_cycle() {
+        // Consecutive days follow Sun, Moon, Mars, Mercury, Jupiter, Venus, Saturn
+        let expected = [3.0, 6.0, 2.0, 5.0, 1.0, 4.0, 0.0];
+        for (offset, ruler) in (0u32..).zip(expected) {
+            let h = planetary_hours(2460681.0 + f64::from(offset), LONDON.0, LONDON.1);
+            assert!(matches!(h, Some(h) if h[0] == ruler), "day {}", offset);
+        }
+    }
+
+    #[test]
+    fn test_polar_night_has_no_hours() {
+        // Tromsø (69.65°N) at the December solstice
+        let hours = planetary_hours(2461031.0, 69.65_f64.to_radians(), 18.96_f64.to_radians());
+        assert!(hours.is_none());
+    }
+}
@@ -0,0 +1,137 @@
+//! Quantum Time (NT) support
+//!
+//! 1:1 перенос семантики из фронта (без строк): NT days of constant length
+//! from the NT epoch, 365 days per NT year, with one split day in year 11.
+//! Returns numbers only; formatting stays on the JS side.
+
+use std::sync::OnceLock;
+
+const QT_CONST_NT_MS: f64 = 1344643200000.0; // constNT @allow-wasm-const
+// Digits as in the frontend constants
+#[allow(clippy::excessive_precision)]
+const QT_CONST_D_MS: f64 = 86459178.082191780821918_f64; // constD @allow-wasm-const
+#[allow(clippy::excessive_precision)]
+const QT_CONST_D_EXTRA_MS: f64 = 43229589.41095890410959_f64; // constDExtra @allow-wasm-const
+const QT_MAX_TIME_MS: f64 = 4090089600000.0; // maxTime @allow-wasm-const
+const QT_SPECIAL_YEAR: i32 = 11; // specialYear @allow-wasm-const
+const QT_SPECIAL_DAY: i32 = 121; // specialDay @allow-wasm-const
+
+#[derive(Clone, Copy)]
+struct QuantumEntry {
+    u_ms: f64, // Unix ms
+    d: i32,    // day in year [0..365)
+    y: i32,    // year index starting at 0
+}
+
+/// Day starts, built once per process and shared by all threads
+static QUANTUM_TABLE: OnceLock<Vec<QuantumEntry>> = OnceLock::new();
+
+/// Quantum Time components of an instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuantumTime {
+    /// Day within the NT decade (0..9)
+    pub day_in_decade: i32,
+    /// Decade within the NT year (0..36)
+    pub decade: i32,
+    /// NT year index, starting at 0
+    pub year: i32,
+}
+
+fn quantum_table() -> &'static [QuantumEntry] {
+    QUANTUM_TABLE.get_or_init(|| {
+        let mut v = Vec::with_capacity(32000);
+        let mut u = QT_CONST_NT_MS;
+        let mut d: i32 = 0;
+        let mut y: i32 = 0;
+        while u < QT_MAX_TIME_MS {
+            v.push(QuantumEntry { u_ms: u, d, y });
+            if y == QT_SPECIAL_YEAR && d == QT_SPECIAL_DAY {
+                u += QT_CONST_D_EXTRA_MS;
+                d += 1;
+                v.push(QuantumEntry { u_ms: u, d, y });
+                u += QT_CONST_D_EXTRA_MS;
+                d += 1;
+                v.push(QuantumEntry { u_ms: u, d, y });
+            } else {
+                u += QT_CONST_D_MS;
+                d += 1;
+            }
+            if d == 365 {
+                d = 0;
+                y += 1;
+            }
+        }
+        v
+    })
+}
+
+#[inline]
+fn floor_div(a: f64, b: f64) -> f64 {
+    (a / b).floor()
+}
+
+fn adjust_ms_like_js(epoch_ms: f64, tz_offset_min: f64) -> f64 {
+    // JS: setHours(24 - (getTimezoneOffset()/60 + 4)); minutes=seconds=ms=0
+    // Воспроизводим локальную нормализацию суток и переносим к UTC.
+    let local_ms = epoch_ms - tz_offset_min * 60_000.0;
+    let day_ms = 86_400_000.0;
+    let hour_ms = 3_600_000.0;
+    let tz_hours = tz_offset_min / 60.0;
+    let target_h_real = 24.0 - (tz_hours + 4.0);
+    let target_h_floor = target_h_real.floor();
+    // Нормализуем часы и сдвиг дня
+    let h_norm = ((target_h_floor % 24.0) + 24.0) % 24.0;
+    let day_offset = ((target_h_floor - h_norm) / 24.0).floor();
+    let local_day_idx = floor_div(local_ms, day_ms);
+    let adjusted_local_ms = (local_day_idx + day_offset) * day_ms + h_norm * hour_ms;
+    // Обратно в UTC
+    adjusted_local_ms + tz_offset_min * 60_000.0
+}
+
+/// Last table entry starting at or before `target_u_ms`
+fn find_entry(table: &[QuantumEntry], target_u_ms: f64) -> Option<QuantumEntry> {
+    let after = table.partition_point(|e| e.u_ms <= target_u_ms);
+    after.checked_sub(1).and_then(|i| table.get(i)).copied()
+}
+
+/// Quantum Time components of `epoch_ms` (Unix ms)
+///
+/// `timezone_offset_minutes` is like JS `Date.getTimezoneOffset()`. `None`
+/// on non-finite input or outside the NT table.
+pub fn quantum_time(epoch_ms: f64, timezone_offset_minutes: f64) -> Option<QuantumTime> {
+    if !epoch_ms.is_finite() || !timezone_offset_minutes.is_finite() {
+        return None;
+    }
+    let adjusted_ms = adjust_ms_like_js(epoch_ms, timezone_offset_minutes);
+    let e = find_entry(quantum_table(), adjusted_ms)?;
+    Some(QuantumTime {
+        day_in_decade: e.d % 10,
+        decade: e.d / 10,
+        year: e.y,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantum_time_epoch_and_bounds() {
+        // The NT epoch with a UTC+4 browser (offset -240) lands on day 0
+        let start = quantum_time(QT_CONST_NT_MS, -240.0);
+        assert_eq!(
+            start,
+            Some(QuantumTime {
+                day_in_decade: 0,
+                decade: 0,
+                year: 0
+            })
+        );
+        // Eleven NT days later: decade 1, day 1
+        let later = quantum_time(QT_CONST_NT_MS + 11.0 * QT_CONST_D_MS, -240.0);
+        assert!(matches!(later, Some(t) if t.decade == 1 && t.day_in_decade == 1 && t.year == 0));
+
+        assert!(quantum_time(QT_CONST_NT_MS - 2.0 * 86_400_000.0, 0.0).is_none());
+        assert!(quantum_time(f64::NAN, 0.0).is_none());
+    }
+}
@@ -0,0 +1,125 @@
+//! Solar and lunar returns
+//!
+//! A solar (lunar) return is the instant at which the Sun (Moon) comes back
+//! to the apparent longitude it had at birth. Both are solved with
+//! `next_longitude_tt`, the solver behind `next_winter_solstice_from`, in
+//! the chosen zodiac (a sidereal solar return falls ~20 min later per year
+//! of age than the tropical one). The return chart is cast for the place
+//! where the native is at the return moment.
+
+use crate::chart::{CHART_LEN, cast_chart};
+use crate::houses::HouseSystem;
+use crate::longitude::{next_longitude_tt, next_solar_longitude_tt};
+use crate::timescales::{tt_to_utc_jd, utc_to_tt_jd};
+use crate::zodiac::{MOON, SUN, Zodiac, zodiac_longitude};
+
+/// Mean sidereal motion of the Moon (rad/day, 360° / 27.321661 d)
+const LUNAR_MEAN_RATE: f64 = 0.229_971_502; // @allow-wasm-const
+
+/// Body whose return is solved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnBody {
+    /// Solar return, about once a year
+    Sun,
+    /// Lunar return, once a sidereal month
+    Moon,
+}
+
+/// JD UTC of the first return at or after `jd_utc_start`
+pub fn next_return(
+    body: ReturnBody,
+    natal_jd_utc: f64,
+    jd_utc_start: f64,
+    zodiac: Zodiac,
+) -> Option<f64> {
+    let code = match body {
+        ReturnBody::Sun => SUN,
+        ReturnBody::Moon => MOON,
+    };
+    let target = zodiac_longitude(code, zodiac, utc_to_tt_jd(natal_jd_utc))?;
+    let longitude_at = |t: f64| zodiac_longitude(code, zodiac, t).unwrap_or(f64::NAN);
+    let jd_tt_start = utc_to_tt_jd(jd_utc_start);
+    let jd_tt = match body {
+        ReturnBody::Sun => next_solar_longitude_tt(longitude_at, jd_tt_start, target),
+        ReturnBody::Moon => next_longitude_tt(longitude_at, jd_tt_start, target, LUNAR_MEAN_RATE),
+    };
+    jd_tt.is_finite().then(|| tt_to_utc_jd(jd_tt))
+}
+
+/// Return chart (layout of `cast_chart`, slot 0 is the return instant)
+pub fn return_chart(
+    body: ReturnBody,
+    natal_jd_utc: f64,
+    jd_utc_start: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    system: HouseSystem,
+    zodiac: Zodiac,
+) -> Option<[f64; CHART_LEN]> {
+    let jd_utc = next_return(body, natal_jd_utc, jd_utc_start, zodiac)?;
+    cast_chart(jd_utc, lat_rad, lon_east_rad, system, zodiac)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::CHART_LONGITUDES;
+    use crate::wrap_pi;
+
+    /// 1990-06-15 12:00 UTC
+    const NATAL: f64 = 2448058.0;
+
+    /// 2025-01-01 00:00 UTC
+    const JAN_2025: f64 = 2460676.5;
+
+    #[test]
+    fn test_solar_return() {
+        let tropical = next_return(ReturnBody::Sun, NATAL, JAN_2025, Zodiac::Tropical);
+        // Birthday 2025-06-15, within the quarter-day drift of the calendar
+        assert!(
+            matches!(tropical, Some(t) if (t - 2460842.0).abs() < 1.0),
+            "{:?}",
+            tropical
+        );
+        if let Some(t) = tropical {
+            let natal = zodiac_longitude(SUN, Zodiac::Tropical, utc_to_tt_jd(NATAL));
+            let now = zodiac_longitude(SUN, Zodiac::Tropical, utc_to_tt_jd(t));
+            assert!(matches!((natal, now), (Some(a), Some(b)) if wrap_pi(a - b).abs() < 1.0e-9));
+        }
+
+        // 35 years of precession (~0.49°) delay the sidereal return by ~12 h
+        let sidereal = next_return(ReturnBody::Sun, NATAL, JAN_2025, Zodiac::Sidereal);
+        if let (Some(t), Some(s)) = (tropical, sidereal) {
+            assert!((0.4..0.6).contains(&(s - t)), "{}", s - t);
+        }
+    }
+
+    #[test]
+    fn test_lunar_return() {
+        let t = next_return(ReturnBody::Moon, NATAL, JAN_2025, Zodiac::Tropical);
+        assert!(matches!(t, Some(t) if (JAN_2025..JAN_2025 + 27.4).contains(&t)));
+        let next = t.and_then(|t| next_return(ReturnBody::Moon, NATAL, t + 1.0, Zodiac::Tropical));
+        if let (Some(a), Some(b)) = (t, next) {
+            // One sidereal month, ±7 h from the varying lunar speed
+            assert!(((b - a) - 27.32).abs() < 0.3, "{}", b - a);
+        }
+    }
+
+    #[test]
+    fn test_return_chart() {
+        // Return cast in Paris; the Sun sits at the natal longitude
+        let chart = return_chart(
+            ReturnBody::Sun,
+            NATAL,
+            JAN_2025,
+            48.85_f64.to_radians(),
+            2.35_f64.to_radians(),
+            HouseSystem::Placidus,
+            Zodiac::Tropical,
+        );
+        let natal = zodiac_longitude(SUN, Zodiac::Tropical, utc_to_tt_jd(NATAL));
+        assert!(
+            matches!((chart, natal), (Some(c), Some(n)) if wrap_pi(c[CHART_LONGITUDES] - n).abs() < 1.0e-8)
+        );
+    }
+}
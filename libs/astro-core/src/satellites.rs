@@ -0,0 +1,145 @@
+//! Planetary satellite systems for the planet "zoom" mode
+//!
+//! Pure astro-rust computations for the Galilean moons, the eight major moons of
+//! Saturn, Saturn's ring geometry and Jupiter's physical ephemeris.
+//!
+//! ## Frame
+//! Moon coordinates are apparent, as seen from Earth, in units of the parent
+//! planet's equatorial radius: X positive to the west along the planet's
+//! equator, Y positive to the north along its rotation axis. Rotate the XY
+//! plane by the returned position angle `P` to align it with celestial north.
+
+/// Galilean moons in output order (Io, Europa, Ganymede, Callisto)
+const GALILEAN_MOONS: [astro::planet::jupiter::moon::Moon; 4] = [
+    astro::planet::jupiter::moon::Moon::Io,
+    astro::planet::jupiter::moon::Moon::Europa,
+    astro::planet::jupiter::moon::Moon::Ganymede,
+    astro::planet::jupiter::moon::Moon::Callisto,
+];
+
+/// Saturn moons in output order (Mimas … Iapetus)
+const SATURN_MOONS: [astro::planet::saturn::moon::Moon; 8] = [
+    astro::planet::saturn::moon::Moon::Mimas,
+    astro::planet::saturn::moon::Moon::Enceladus,
+    astro::planet::saturn::moon::Moon::Tethys,
+    astro::planet::saturn::moon::Moon::Dione,
+    astro::planet::saturn::moon::Moon::Rhea,
+    astro::planet::saturn::moon::Moon::Titan,
+    astro::planet::saturn::moon::Moon::Hyperion,
+    astro::planet::saturn::moon::Moon::Iapetus,
+];
+
+/// Jupiter system layout length: 4 moons × (X, Y) + [De, Ds, P, ω1, ω2]
+pub const JUPITER_SYSTEM_LEN: usize = 13;
+
+/// Saturn system layout length: 8 moons × (X, Y, Z) + [B, B1, P, ΔU, a, b]
+pub const SATURN_SYSTEM_LEN: usize = 30;
+
+/// Jupiter system snapshot
+///
+/// Layout [13 f64]:
+/// - 0..7: Io, Europa, Ganymede, Callisto apparent (X, Y) in Jupiter radii
+/// - 8: De — Jovicentric declination of the Earth (rad)
+/// - 9: Ds — Jovicentric declination of the Sun (rad)
+/// - 10: P — position angle of the northern rotation pole (rad)
+/// - 11: ω1 — central meridian longitude, System I (rad)
+/// - 12: ω2 — central meridian longitude, System II (rad)
+pub fn jupiter_system(julian_day: f64) -> [f64; JUPITER_SYSTEM_LEN] {
+    let mut out = [0.0; JUPITER_SYSTEM_LEN];
+
+    for (i, moon) in GALILEAN_MOONS.iter().enumerate() {
+        let (x, y) = astro::planet::jupiter::moon::apprnt_rect_coords(julian_day, moon);
+        out[2 * i] = x;
+        out[2 * i + 1] = y;
+    }
+
+    let (nut_long, nut_oblq) = astro::nutation::nutation(julian_day);
+    let mean_oblq = astro::ecliptic::mn_oblq_IAU(julian_day);
+    let eph = astro::planet::jupiter::ephemeris(julian_day, mean_oblq, nut_long, nut_oblq);
+    out[8] = eph.De;
+    out[9] = eph.Ds;
+    out[10] = eph.P;
+    out[11] = eph.w1;
+    out[12] = eph.w2;
+
+    out
+}
+
+/// Saturn system snapshot
+///
+/// Layout [30 f64]:
+/// - 0..23: Mimas, Enceladus, Tethys, Dione, Rhea, Titan, Hyperion, Iapetus
+///   apparent (X, Y, Z) in Saturn radii; only the sign of Z is meaningful
+///   (positive = moon behind Saturn)
+/// - 24: B — Saturnicentric latitude of the Earth on the ring plane (ring tilt, rad)
+/// - 25: B1 — Saturnicentric latitude of the Sun on the ring plane (rad)
+/// - 26: P — position angle of the ring's northern semiminor axis (rad)
+/// - 27: ΔU — Sun–Earth Saturnicentric longitude difference in the ring plane (rad)
+/// - 28: a — major axis of the outer ring edge (rad)
+/// - 29: b — minor axis of the outer ring edge (rad)
+pub fn saturn_system(julian_day: f64) -> [f64; SATURN_SYSTEM_LEN] {
+    let mut out = [0.0; SATURN_SYSTEM_LEN];
+
+    for (i, moon) in SATURN_MOONS.iter().enumerate() {
+        let (x, y, z) = astro::planet::saturn::moon::apprnt_rect_coords(julian_day, moon);
+        out[3 * i] = x;
+        out[3 * i + 1] = y;
+        out[3 * i + 2] = z;
+    }
+
+    let (nut_long, nut_oblq) = astro::nutation::nutation(julian_day);
+    let true_oblq = astro::ecliptic::mn_oblq_IAU(julian_day) + nut_oblq;
+    let ring = astro::planet::saturn::ring::elements(julian_day, nut_long, true_oblq);
+    out[24] = ring.B;
+    out[25] = ring.B1;
+    out[26] = ring.P;
+    out[27] = ring.deltaU;
+    out[28] = ring.a;
+    out[29] = ring.b;
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_galilean_moons_within_orbits() {
+        // Callisto's apparent elongation never exceeds ~27 Jupiter radii
+        let sys = jupiter_system(2451545.0);
+        let mut i = 0;
+        while i < 4 {
+            let (x, y) = (sys[2 * i], sys[2 * i + 1]);
+            assert!(x.is_finite() && y.is_finite(), "Moon {} not finite", i);
+            assert!(x.abs() < 27.0, "Moon {} X out of range: {}", i, x);
+            assert!(y.abs() < 2.0, "Moon {} Y out of range: {}", i, y);
+            i += 1;
+        }
+        // Jovicentric declinations stay within ±4° (axial tilt ~3.1°)
+        assert!(sys[8].abs() < 4.0_f64.to_radians(), "De: {}", sys[8]);
+        assert!(sys[9].abs() < 4.0_f64.to_radians(), "Ds: {}", sys[9]);
+    }
+
+    #[test]
+    fn test_saturn_ring_tilt_range() {
+        // Ring tilt B is bounded by Saturn's obliquity (~27°)
+        let sys = saturn_system(2451545.0);
+        assert!(sys[24].abs() < 28.0_f64.to_radians(), "B: {}", sys[24]);
+        assert!(sys[28] > 0.0 && sys[29] >= 0.0 && sys[29] <= sys[28]);
+        // Titan orbits at ~20.3 Saturn radii
+        let titan_x = sys[15];
+        assert!(titan_x.abs() < 21.5, "Titan X out of range: {}", titan_x);
+    }
+
+    #[test]
+    fn test_saturn_ring_edge_on_2025() {
+        // Ring-plane crossing for Earth: 2025-03-23 (JD ≈ 2460757.5)
+        let sys = saturn_system(2460757.5);
+        assert!(
+            sys[24].abs() < 0.5_f64.to_radians(),
+            "Rings should be nearly edge-on, B = {}",
+            sys[24].to_degrees()
+        );
+    }
+}
@@ -0,0 +1,315 @@
+//! Comets and asteroids from osculating orbital elements
+//!
+//! Pure astro-rust two-body propagation for MPC-style element sets:
+//! elliptic orbits via astro::orbit::elliptic (Kepler's equation),
+//! parabolic via astro::orbit::parabolic and near-parabolic/hyperbolic via
+//! astro::orbit::near_parabolic. Positions are turned into heliocentric and
+//! geocentric apparent coordinates plus an H/G magnitude.
+//!
+//! ## Elements
+//! Angles (i, Ω, ω) are referred to the J2000 ecliptic and equinox, as
+//! published by the MPC. All times are Julian Ephemeris Days (TT).
+
+use crate::state::earth_heliocentric_cartesian;
+use crate::{Cartesian, LIGHT_TIME_DAYS_PER_AU, ecliptic_to_cartesian, wrap_pi};
+
+/// J2000.0 epoch of the element frame
+const J2000_JD: f64 = 2451545.0; // @allow-wasm-const
+
+/// Kepler solver accuracy (rad / AU)
+const KEPLER_ACCURACY: f64 = 1.0e-10; // @allow-wasm-const
+
+/// Eccentricity from which the near-parabolic solver replaces Kepler's equation
+const NEAR_PARABOLIC_ECC: f64 = 0.98; // @allow-wasm-const
+
+/// Small-body state layout length
+pub const SMALL_BODY_STATE_LEN: usize = 10;
+
+/// Osculating elements normalized to perihelion form (q, e, T)
+#[derive(Debug, Clone, Copy)]
+pub struct OrbitalElements {
+    /// Perihelion distance q (AU)
+    pub perihelion_dist_au: f64,
+    /// Eccentricity e
+    pub eccentricity: f64,
+    /// Inclination i (rad, J2000 ecliptic)
+    pub inclination: f64,
+    /// Longitude of the ascending node Ω (rad, J2000 equinox)
+    pub ascending_node: f64,
+    /// Argument of perihelion ω (rad)
+    pub arg_perihelion: f64,
+    /// Time of perihelion passage T (JDE)
+    pub perihelion_jd: f64,
+}
+
+impl OrbitalElements {
+    /// Comet-style elements: q, e, i, Ω, ω and the perihelion time T
+    pub fn from_perihelion(
+        perihelion_dist_au: f64,
+        eccentricity: f64,
+        inclination: f64,
+        ascending_node: f64,
+        arg_perihelion: f64,
+        perihelion_jd: f64,
+    ) -> Option<Self> {
+        let values = [
+            perihelion_dist_au,
+            eccentricity,
+            inclination,
+            ascending_node,
+            arg_perihelion,
+            perihelion_jd,
+        ];
+        if values.iter().any(|v| !v.is_finite()) || perihelion_dist_au <= 0.0 || eccentricity < 0.0
+        {
+            return None;
+        }
+        Some(Self {
+            perihelion_dist_au,
+            eccentricity,
+            inclination,
+            ascending_node,
+            arg_perihelion,
+            perihelion_jd,
+        })
+    }
+
+    /// Asteroid-style elements: a, e, i, Ω, ω and the mean anomaly M at `epoch_jd`
+    pub fn from_mean_anomaly(
+        epoch_jd: f64,
+        semimajor_axis_au: f64,
+        eccentricity: f64,
+        inclination: f64,
+        ascending_node: f64,
+        arg_perihelion: f64,
+        mean_anomaly: f64,
+    ) -> Option<Self> {
+        if !(0.0..1.0).contains(&eccentricity) || semimajor_axis_au <= 0.0 {
+            return None;
+        }
+        let mean_motion = astro::orbit::elliptic::mn_motion(semimajor_axis_au);
+        let m = wrap_pi(mean_anomaly);
+        Self::from_perihelion(
+            semimajor_axis_au * (1.0 - eccentricity),
+            eccentricity,
+            inclination,
+            ascending_node,
+            arg_perihelion,
+            epoch_jd - m / mean_motion,
+        )
+    }
+
+    /// True anomaly (rad) and radius vector (AU) at `jd` by two-body motion
+    pub fn true_anomaly_and_radius(&self, jd: f64) -> Option<(f64, f64)> {
+        let (q, e) = (self.perihelion_dist_au, self.eccentricity);
+        if e < NEAR_PARABOLIC_ECC {
+            let a = q / (1.0 - e);
+            let mean_anom =
+                wrap_pi(astro::orbit::elliptic::mn_motion(a) * (jd - self.perihelion_jd));
+            let ecc_anom = astro::orbit::elliptic::ecc_anom(mean_anom, e, KEPLER_ACCURACY);
+            Some((
+                astro::orbit::elliptic::true_anom(ecc_anom, e),
+                astro::orbit::elliptic::rad_vec_frm_ecc_anom(ecc_anom, a, e),
+            ))
+        } else if e == 1.0 {
+            Some(astro::orbit::parabolic::true_anom_and_rad_vec(
+                jd,
+                self.perihelion_jd,
+                q,
+            ))
+        } else {
+            astro::orbit::near_parabolic::true_anom_and_rad_vec(
+                jd,
+                self.perihelion_jd,
+                e,
+                q,
+                KEPLER_ACCURACY,
+            )
+            .ok()
+        }
+    }
+
+    /// Heliocentric ecliptic Cartesian (AU), mean ecliptic and equinox of `jd`
+    pub fn heliocentric_cartesian(&self, jd: f64) -> Option<Cartesian> {
+        let (v, r) = self.true_anomaly_and_radius(jd)?;
+        let (long_j2000, lat_j2000) = astro::planet::heliocent_coords_frm_orb_elements(
+            self.inclination,
+            self.ascending_node,
+            self.arg_perihelion,
+            v,
+            r,
+        );
+        let (long, lat) = astro::precess::precess_ecl_coords(long_j2000, lat_j2000, J2000_JD, jd);
+        Some(ecliptic_to_cartesian(long, lat, r))
+    }
+}
+
+/// Apparent magnitude in the IAU H/G system (Bowell et al. 1989)
+pub fn hg_magnitude(
+    abs_mag: f64,
+    slope: f64,
+    sun_dist_au: f64,
+    earth_dist_au: f64,
+    phase_angle: f64,
+) -> f64 {
+    let tan_half = (phase_angle / 2.0).tan().abs();
+    let phi1 = (-3.33 * tan_half.powf(0.63)).exp();
+    let phi2 = (-1.87 * tan_half.powf(1.22)).exp();
+    abs_mag + 5.0 * (sun_dist_au * earth_dist_au).log10()
+        - 2.5 * ((1.0 - slope) * phi1 + slope * phi2).log10()
+}
+
+/// Heliocentric and apparent geocentric state of a small body
+///
+/// Layout [10 f64]:
+/// - 0..2: heliocentric ecliptic x, y, z (AU, ecliptic of date; same frame as
+///   `get_planet_state`)
+/// - 3: r — Sun distance (AU)
+/// - 4: apparent geocentric right ascension (rad, 0..2π)
+/// - 5: apparent geocentric declination (rad)
+/// - 6: Δ — Earth distance (AU)
+/// - 7: phase angle Sun–body–Earth (rad)
+/// - 8: apparent magnitude (H/G)
+/// - 9: light-time τ (days)
+pub fn small_body_state(
+    elements: &OrbitalElements,
+    julian_day: f64,
+    abs_mag: f64,
+    slope: f64,
+) -> Option<[f64; SMALL_BODY_STATE_LEN]> {
+    let helio = elements.heliocentric_cartesian(julian_day)?;
+    let earth = earth_heliocentric_cartesian(julian_day);
+
+    // Light-time: body at jd - τ, Earth at jd
+    let mut tau = 0.0;
+    let mut geo = Cartesian::new(0.0, 0.0, 0.0);
+    let mut delta = 0.0;
+    for _ in 0..3 {
+        let retarded = elements.heliocentric_cartesian(julian_day - tau)?;
+        geo = Cartesian::new(
+            retarded.x - earth.x,
+            retarded.y - earth.y,
+            retarded.z - earth.z,
+        );
+        delta = (geo.x * geo.x + geo.y * geo.y + geo.z * geo.z).sqrt();
+        tau = delta * LIGHT_TIME_DAYS_PER_AU;
+    }
+
+    // Apparent place: nutation in longitude, true obliquity, annual aberration
+    let (nut_long, nut_oblq) = astro::nutation::nutation(julian_day);
+    let true_oblq = astro::ecliptic::mn_oblq_IAU(julian_day) + nut_oblq;
+    let geo_long = geo.y.atan2(geo.x) + nut_long;
+    let geo_lat = geo.z.atan2((geo.x * geo.x + geo.y * geo.y).sqrt());
+    let eq = astro::coords::EqPoint {
+        asc: astro::coords::asc_frm_ecl(geo_long, geo_lat, true_oblq),
+        dec: astro::coords::dec_frm_ecl(geo_long, geo_lat, true_oblq),
+    };
+    let (aberr_asc, aberr_dec) = astro::aberr::stell_aberr_in_eq_coords(&eq, julian_day);
+
+    let r = (helio.x * helio.x + helio.y * helio.y + helio.z * helio.z).sqrt();
+    let earth_r = (earth.x * earth.x + earth.y * earth.y + earth.z * earth.z).sqrt();
+    let cos_phase =
+        ((r * r + delta * delta - earth_r * earth_r) / (2.0 * r * delta)).clamp(-1.0, 1.0);
+    let phase = cos_phase.acos();
+
+    Some([
+        helio.x,
+        helio.y,
+        helio.z,
+        r,
+        astro::angle::limit_to_two_PI(eq.asc + aberr_asc),
+        eq.dec + aberr_dec,
+        delta,
+        phase,
+        hg_magnitude(abs_mag, slope, r, delta, phase),
+        tau,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Comet Encke, Meeus Example 33.b (elements J2000)
+    fn encke() -> Option<OrbitalElements> {
+        let a = 2.2091404;
+        let e = 0.8502196;
+        OrbitalElements::from_perihelion(
+            a * (1.0 - e),
+            e,
+            11.94524_f64.to_radians(),
+            334.75006_f64.to_radians(),
+            186.23352_f64.to_radians(),
+            2448193.04502,
+        )
+    }
+
+    #[test]
+    fn test_encke_meeus_example_33b() {
+        // 1990 October 6.0 TD: r = 0.6525 AU, Δ = 0.8242 AU,
+        // astrometric J2000 α = 158.558°, δ = +19.159°
+        let state = encke().and_then(|el| small_body_state(&el, 2448170.5, 10.0, 0.15));
+        let Some(s) = state else {
+            panic!("no state of Encke for 1990 October 6");
+        };
+        assert!((s[3] - 0.6525).abs() < 0.001, "r = {}", s[3]);
+        assert!((s[6] - 0.8242).abs() < 0.002, "Δ = {}", s[6]);
+        // Apparent place of date differs from J2000 by ~9 years of precession
+        assert!(
+            (s[4].to_degrees() - 158.558).abs() < 0.2,
+            "α = {}",
+            s[4].to_degrees()
+        );
+        assert!(
+            (s[5].to_degrees() - 19.159).abs() < 0.1,
+            "δ = {}",
+            s[5].to_degrees()
+        );
+        assert!(s[9] > 0.0 && s[9] < 0.01);
+    }
+
+    #[test]
+    fn test_mean_anomaly_and_perihelion_forms_agree() {
+        // Same Encke orbit expressed with M at an epoch 20 days after perihelion
+        let (a, e) = (2.2091404_f64, 0.8502196_f64);
+        let epoch = 2448193.04502 + 20.0;
+        let mean_anom = astro::orbit::elliptic::mn_motion(a) * 20.0;
+        let from_m = OrbitalElements::from_mean_anomaly(
+            epoch,
+            a,
+            e,
+            11.94524_f64.to_radians(),
+            334.75006_f64.to_radians(),
+            186.23352_f64.to_radians(),
+            mean_anom,
+        );
+        let p1 = from_m.and_then(|el| el.heliocentric_cartesian(2448170.5));
+        let p2 = encke().and_then(|el| el.heliocentric_cartesian(2448170.5));
+        assert!(matches!((p1, p2), (Some(p1), Some(p2))
+            if (p1.x - p2.x).abs() < 1e-8 && (p1.y - p2.y).abs() < 1e-8 && (p1.z - p2.z).abs() < 1e-8));
+    }
+
+    #[test]
+    fn test_parabolic_and_near_parabolic_at_perihelion() {
+        for e in [1.0, 0.995, 1.002] {
+            let el = OrbitalElements::from_perihelion(0.5, e, 1.0, 0.5, 0.3, 2460000.5);
+            let state = el.and_then(|el| el.true_anomaly_and_radius(2460000.5));
+            assert!(matches!(state, Some((v, r)) if v.abs() < 1e-9 && (r - 0.5).abs() < 1e-9));
+            let later = el.and_then(|el| el.true_anomaly_and_radius(2460030.5));
+            assert!(
+                matches!(later, Some((v, r)) if v > 0.0 && r > 0.5),
+                "e = {}",
+                e
+            );
+        }
+    }
+
+    #[test]
+    fn test_hg_magnitude_at_zero_phase() {
+        // At zero phase the phase function is 1: m = H + 5 log10(r Δ)
+        let m = hg_magnitude(3.34, 0.12, 2.0, 1.0, 0.0);
+        assert!((m - (3.34 + 5.0 * 2.0_f64.log10())).abs() < 1e-12);
+        // Larger phase angles make the body fainter
+        assert!(hg_magnitude(3.34, 0.12, 2.0, 1.0, 0.3) > m);
+    }
+}
@@ -0,0 +1,262 @@
+//! Embedded bright-star catalog and apparent places
+//!
+//! The 80 named stars of the Yale Bright Star Catalogue the app labels, all
+//! brighter than V = 3.4, in the row format of `scripts/gen-bright-stars.py`.
+//! The full naked-eye catalogue (V ≤ 6, about 5000 stars) is left out of the
+//! WASM binary; the script generates it when a fainter sky is needed.
+//! Positions and proper motions are J2000.0, proper motion in right ascension
+//! is μα* = μα·cos δ.
+//!
+//! ## Apparent place pipeline
+//! 1. Proper motion, linear in α and δ from J2000.0 (Meeus ch. 21)
+//! 2. Precession J2000.0 → date, astro::precess::precess_eq_coords (IAU 1976;
+//!    `precess_eq_coords_FK5` expects FK4/B1950 input despite its name)
+//! 3. Nutation, astro::nutation::nutation_in_eq_coords (first order)
+//! 4. Annual aberration, astro::aberr::stell_aberr_in_eq_coords (Ron–Vondrák)
+
+/// J2000.0 catalog epoch
+const J2000_JD: f64 = 2451545.0; // @allow-wasm-const
+
+/// Days per Julian year
+const DAYS_PER_JULIAN_YEAR: f64 = 365.25; // @allow-wasm-const
+
+/// Milliarcseconds per radian
+const MAS_PER_RAD: f64 = 206_264_806.247; // @allow-wasm-const
+
+/// Catalog entry (J2000.0)
+#[derive(Debug, Clone, Copy)]
+pub struct CatalogStar {
+    /// Harvard Revised (Bright Star Catalogue) number
+    pub hr: u16,
+    /// Proper name
+    pub name: &'static str,
+    /// Right ascension J2000.0 (rad)
+    pub ra: f64,
+    /// Declination J2000.0 (rad)
+    pub dec: f64,
+    /// Proper motion μα* = μα·cos δ (mas/yr)
+    pub pm_ra_mas: f64,
+    /// Proper motion μδ (mas/yr)
+    pub pm_dec_mas: f64,
+    /// Visual magnitude
+    pub vmag: f64,
+}
+
+/// Right ascension from hours, minutes, seconds to radians
+const fn hms(h: f64, m: f64, s: f64) -> f64 {
+    (h + m / 60.0 + s / 3600.0) * 15.0 * std::f64::consts::PI / 180.0
+}
+
+/// Declination from sign, degrees, minutes, seconds to radians
+const fn dms(sign: f64, d: f64, m: f64, s: f64) -> f64 {
+    sign * (d + m / 60.0 + s / 3600.0) * std::f64::consts::PI / 180.0
+}
+
+/// Catalog entry from sexagesimal J2000.0 coordinates
+macro_rules! star {
+    ($hr:expr, $name:expr, $rh:expr, $rm:expr, $rs:expr, $sign:expr, $dd:expr, $dm:expr, $ds:expr,
+     $pmra:expr, $pmdec:expr, $vmag:expr) => {
+        CatalogStar {
+            hr: $hr,
+            name: $name,
+            ra: hms($rh, $rm, $rs),
+            dec: dms($sign, $dd, $dm, $ds),
+            pm_ra_mas: $pmra,
+            pm_dec_mas: $pmdec,
+            vmag: $vmag,
+        }
+    };
+}
+
+/// HR number of Alnitak (ζ Ori), eastern star of the Orion belt
+pub const ALNITAK_HR: u16 = 1948;
+
+/// HR number of Alnilam (ε Ori), middle star of the Orion belt
+pub const ALNILAM_HR: u16 = 1903;
+
+/// HR number of Mintaka (δ Ori), western star of the Orion belt
+pub const MINTAKA_HR: u16 = 1852;
+
+/// Faintest visual magnitude in the embedded table, rounded up (Meissa, V = 3.39)
+pub const LIMITING_MAGNITUDE: f64 = 3.4; // @allow-wasm-const
+
+/// Number of catalog stars
+pub const STAR_COUNT: usize = BRIGHT_STARS.len();
+
+/// Values per star in the batched output: [α, δ, V, HR]
+pub const STAR_STRIDE: usize = 4;
+
+/// Embedded catalog, ordered by right ascension
+///
+/// Named Bright Star Catalogue stars, as written by
+/// `scripts/gen-bright-stars.py --named`, down to [`LIMITING_MAGNITUDE`].
+#[rustfmt::skip]
+pub const BRIGHT_STARS: &[CatalogStar] = &include!("bright_stars.rs");
+
+/// Catalog entry by HR number
+pub fn star_by_hr(hr: u16) -> Option<&'static CatalogStar> {
+    BRIGHT_STARS.iter().find(|star| star.hr == hr)
+}
+
+/// Apparent place of a catalog star at `julian_day` (TT): `(α, δ)` in radians
+pub fn star_apparent_place(star: &CatalogStar, julian_day: f64) -> (f64, f64) {
+    let (nut_long, nut_oblq) = astro::nutation::nutation(julian_day);
+    let true_oblq = astro::ecliptic::mn_oblq_IAU(julian_day) + nut_oblq;
+    apparent_place_with_nutation(star, julian_day, nut_long, nut_oblq, true_oblq)
+}
+
+/// Apparent place with precomputed nutation, shared by the batched call
+fn apparent_place_with_nutation(
+    star: &CatalogStar,
+    julian_day: f64,
+    nut_long: f64,
+    nut_oblq: f64,
+    true_oblq: f64,
+) -> (f64, f64) {
+    // 1. Proper motion (linear from J2000.0)
+    let years = (julian_day - J2000_JD) / DAYS_PER_JULIAN_YEAR;
+    let ra_pm = star.ra + years * star.pm_ra_mas / MAS_PER_RAD / star.dec.cos();
+    let dec_pm = star.dec + years * star.pm_dec_mas / MAS_PER_RAD;
+
+    // 2. Precession to the mean equinox of date
+    let (ra_mean, dec_mean) =
+        astro::precess::precess_eq_coords(ra_pm, dec_pm, J2000_JD, julian_day);
+    let mean = astro::coords::EqPoint {
+        asc: ra_mean,
+        dec: dec_mean,
+    };
+
+    // 3. Nutation and 4. annual aberration, both evaluated at the mean place
+    let (nut_ra, nut_dec) =
+        astro::nutation::nutation_in_eq_coords(&mean, nut_long, nut_oblq, true_oblq);
+    let (aberr_ra, aberr_dec) = astro::aberr::stell_aberr_in_eq_coords(&mean, julian_day);
+
+    (
+        astro::angle::limit_to_two_PI(ra_mean + nut_ra + aberr_ra),
+        dec_mean + nut_dec + aberr_dec,
+    )
+}
+
+/// Apparent places of the whole catalog, written into `out`
+///
+/// Per star: [α (rad), δ (rad), V mag, HR number], in catalog order; `out`
+/// holds [`STAR_COUNT`] rows (extra rows are left untouched).
+pub fn star_apparent_places(julian_day: f64, out: &mut [[f64; STAR_STRIDE]]) {
+    let (nut_long, nut_oblq) = astro::nutation::nutation(julian_day);
+    let true_oblq = astro::ecliptic::mn_oblq_IAU(julian_day) + nut_oblq;
+
+    for (slot, star) in out.iter_mut().zip(BRIGHT_STARS.iter()) {
+        let (ra, dec) =
+            apparent_place_with_nutation(star, julian_day, nut_long, nut_oblq, true_oblq);
+        slot[0] = ra;
+        slot[1] = dec;
+        slot[2] = star.vmag;
+        slot[3] = f64::from(star.hr);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apparent_place_meeus_example_23a() {
+        // Meeus, Example 23.a: θ Persei on 2028 Nov 13.19 TD
+        // μα = +0.03425 s/yr → μα* = 335.45 mas/yr, μδ = -89.5 mas/yr
+        let theta_persei = star!(
+            868,
+            "θ Persei",
+            2.0,
+            44.0,
+            11.986,
+            1.0,
+            49.0,
+            13.0,
+            42.48,
+            335.45,
+            -89.5,
+            4.12
+        );
+        let (ra, dec) = star_apparent_place(&theta_persei, 2462088.69);
+        // α = 2h46m14.390s, δ = +49°21'07.45"
+        let ra_err_arcsec = (ra - hms(2.0, 46.0, 14.390)).to_degrees() * 3600.0 * dec.cos();
+        let dec_err_arcsec = (dec - dms(1.0, 49.0, 21.0, 7.45)).to_degrees() * 3600.0;
+        assert!(ra_err_arcsec.abs() < 2.0, "Δα·cosδ = {}\"", ra_err_arcsec);
+        assert!(dec_err_arcsec.abs() < 2.0, "Δδ = {}\"", dec_err_arcsec);
+    }
+
+    #[test]
+    fn test_orion_belt_present() {
+        for hr in [ALNITAK_HR, ALNILAM_HR, MINTAKA_HR] {
+            let star = star_by_hr(hr);
+            assert!(
+                matches!(star, Some(s) if s.dec.abs() < 2.0_f64.to_radians()),
+                "HR {}",
+                hr
+            );
+        }
+        // Belt spans ~2.7° from Mintaka to Alnitak
+        if let (Some(a), Some(m)) = (star_by_hr(ALNITAK_HR), star_by_hr(MINTAKA_HR)) {
+            let cos_sep =
+                a.dec.sin() * m.dec.sin() + a.dec.cos() * m.dec.cos() * (a.ra - m.ra).cos();
+            let sep = cos_sep.acos().to_degrees();
+            assert!((sep - 2.74).abs() < 0.05, "belt length = {}", sep);
+        }
+    }
+
+    #[test]
+    fn test_batched_places_layout() {
+        let mut places = vec![[0.0; STAR_STRIDE]; STAR_COUNT];
+        star_apparent_places(2460000.5, &mut places);
+        let mut hrs = Vec::with_capacity(STAR_COUNT);
+        for (slot, star) in places.iter().zip(BRIGHT_STARS.iter()) {
+            assert!(
+                (0.0..std::f64::consts::TAU).contains(&slot[0]),
+                "{}",
+                star.name
+            );
+            assert!(slot[1].abs() <= std::f64::consts::FRAC_PI_2);
+            assert_eq!(slot[3], f64::from(star.hr));
+            // Apparent place stays within a degree of J2000 for 2023 (precession ~0.32°)
+            let cos_sep = slot[1].sin() * star.dec.sin()
+                + slot[1].cos() * star.dec.cos() * (slot[0] - star.ra).cos();
+            assert!(
+                cos_sep.clamp(-1.0, 1.0).acos().to_degrees() < 1.0,
+                "{}",
+                star.name
+            );
+            hrs.push(star.hr);
+        }
+        hrs.sort_unstable();
+        hrs.dedup();
+        assert_eq!(hrs.len(), STAR_COUNT, "duplicate HR numbers");
+    }
+
+    #[test]
+    fn test_catalog_limiting_magnitude() {
+        // The named stars only, not the full naked-eye catalogue
+        assert_eq!(STAR_COUNT, 80);
+        for star in BRIGHT_STARS {
+            assert!(
+                star.vmag <= LIMITING_MAGNITUDE,
+                "{} V = {}",
+                star.name,
+                star.vmag
+            );
+            assert!(!star.name.starts_with("HR "), "HR {} is unnamed", star.hr);
+        }
+        let faintest = BRIGHT_STARS
+            .iter()
+            .map(|star| star.vmag)
+            .fold(f64::NEG_INFINITY, f64::max);
+        assert!(
+            LIMITING_MAGNITUDE - faintest < 0.05,
+            "faintest V = {}",
+            faintest
+        );
+        assert!(
+            BRIGHT_STARS.windows(2).all(|pair| pair[0].ra <= pair[1].ra),
+            "catalog not ordered by right ascension"
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Per-frame ephemeris state: Moon, Earth, solar zenith and velocities
+//!
+//! The scene is heliocentric with the Sun fixed at the origin, so no solar
+//! position is computed. `EphemerisState::to_array` gives the 17-slot layout
+//! of the WASM `compute_state` buffer.
+
+use crate::bodies::planet_heliocentric_cartesian;
+use crate::{AU_KM, Cartesian, JulianDay, LIGHT_TIME_DAYS_PER_AU, ecliptic_to_cartesian};
+
+/// Half-step for symmetric differencing, in days (~86.4 s)
+pub const VELOCITY_STEP_DAYS: f64 = 1.0e-3; // @allow-wasm-const
+
+/// Length of the flat state layout
+pub const STATE_LEN: usize = 17;
+
+/// Ephemeris state at one instant
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EphemerisState {
+    /// Moon geocentric ecliptic position (AU), nutation applied in longitude
+    pub moon: Cartesian,
+    /// Earth heliocentric ecliptic position (AU), VSOP87
+    pub earth: Cartesian,
+    /// Solar zenith longitude, east-positive (rad, -π..π)
+    pub zenith_lon_east_rad: f64,
+    /// Solar zenith latitude, north-positive (rad)
+    pub zenith_lat_rad: f64,
+    /// Moon geocentric ecliptic velocity (AU/day)
+    pub moon_velocity: Cartesian,
+    /// Earth heliocentric ecliptic velocity (AU/day)
+    pub earth_velocity: Cartesian,
+}
+
+impl EphemerisState {
+    /// Flat layout [17 f64]:
+    /// - Sun(0..2): zeros by design (Sun fixed at scene origin)
+    /// - Moon(3..5): geocentric ecliptic Cartesian (x,y,z) in AU
+    /// - Earth(6..8): heliocentric ecliptic Cartesian (x,y,z) in AU
+    /// - Zenith(9..10): Solar zenith [lon_east_rad, lat_rad]
+    /// - Moon velocity(11..13): geocentric ecliptic (vx,vy,vz) in AU/day
+    /// - Earth velocity(14..16): heliocentric ecliptic (vx,vy,vz) in AU/day
+    pub fn to_array(&self) -> [f64; STATE_LEN] {
+        let (m, e, mv, ev) = (
+            self.moon,
+            self.earth,
+            self.moon_velocity,
+            self.earth_velocity,
+        );
+        [
+            0.0,
+            0.0,
+            0.0,
+            m.x,
+            m.y,
+            m.z,
+            e.x,
+            e.y,
+            e.z,
+            self.zenith_lon_east_rad,
+            self.zenith_lat_rad,
+            mv.x,
+            mv.y,
+            mv.z,
+            ev.x,
+            ev.y,
+            ev.z,
+        ]
+    }
+}
+
+/// Compute the state at a Julian Day; `None` on an invalid Julian Day
+pub fn compute_state(julian_day: f64) -> Option<EphemerisState> {
+    let jd = JulianDay::new(julian_day).ok()?.as_f64();
+    let (zenith_lon_east_rad, zenith_lat_rad) = crate::zenith::solar_zenith_position_rad(jd);
+    Some(EphemerisState {
+        moon: moon_geocentric_cartesian(jd),
+        earth: earth_heliocentric_cartesian(jd),
+        zenith_lon_east_rad,
+        zenith_lat_rad,
+        // Velocities by symmetric differencing (frame interpolation, motion direction)
+        moon_velocity: symmetric_velocity(moon_geocentric_cartesian, jd),
+        earth_velocity: symmetric_velocity(earth_heliocentric_cartesian, jd),
+    })
+}
+
+/// Moon geocentric ecliptic Cartesian (AU), nutation applied in longitude
+#[inline]
+pub fn moon_geocentric_cartesian(jd: f64) -> Cartesian {
+    let (nut_long, _nut_oblq) = astro::nutation::nutation(jd);
+    let (moon_ecl, moon_dist_km) = astro::lunar::geocent_ecl_pos(jd);
+    ecliptic_to_cartesian(moon_ecl.long + nut_long, moon_ecl.lat, moon_dist_km / AU_KM)
+}
+
+/// Earth heliocentric ecliptic Cartesian (AU), VSOP87
+#[inline]
+pub fn earth_heliocentric_cartesian(jd: f64) -> Cartesian {
+    let (long, lat, r) = astro::planet::heliocent_coords(&astro::planet::Planet::Earth, jd);
+    ecliptic_to_cartesian(long, lat, r)
+}
+
+/// Velocity (AU/day) by symmetric differencing of a position function
+#[inline]
+pub fn symmetric_velocity<F: Fn(f64) -> Cartesian>(position_at: F, jd: f64) -> Cartesian {
+    let p = position_at(jd + VELOCITY_STEP_DAYS);
+    let m = position_at(jd - VELOCITY_STEP_DAYS);
+    let inv = 1.0 / (2.0 * VELOCITY_STEP_DAYS);
+    Cartesian::new((p.x - m.x) * inv, (p.y - m.y) * inv, (p.z - m.z) * inv)
+}
+
+/// Geocentric ecliptic position of a planet (AU), optionally corrected for light-time.
+///
+/// With correction the planet is taken at `jd - τ` (τ iterated from the distance)
+/// while Earth stays at `jd`. Returns `(position, τ_days)`.
+fn planet_geocentric_cartesian(
+    planet_index: usize,
+    jd: f64,
+    apply_light_time: bool,
+) -> Option<(Cartesian, f64)> {
+    let earth = earth_heliocentric_cartesian(jd);
+    let mut tau = 0.0;
+    let mut geo = Cartesian::new(0.0, 0.0, 0.0);
+    // Three passes converge well below a millisecond for all planets
+    let passes = if apply_light_time { 3 } else { 1 };
+    for _ in 0..passes {
+        let helio = planet_heliocentric_cartesian(planet_index, jd - tau)?;
+        geo = Cartesian::new(helio.x - earth.x, helio.y - earth.y, helio.z - earth.z);
+        let dist = (geo.x * geo.x + geo.y * geo.y + geo.z * geo.z).sqrt();
+        if apply_light_time {
+            tau = dist * LIGHT_TIME_DAYS_PER_AU;
+        }
+    }
+    Some((geo, tau))
+}
+
+/// Geocentric state of a planet by wrapper index: `[x, y, z, vx, vy, vz,
+/// light_time_days]`, ecliptic Cartesian of date in AU and AU/day
+///
+/// With `apply_light_time` the planet is placed where it was when the
+/// observed light left it; otherwise τ is 0. `None` for an unknown index.
+pub fn planet_geocentric_state(
+    planet_index: usize,
+    jd: f64,
+    apply_light_time: bool,
+) -> Option<[f64; 7]> {
+    let (pos, tau) = planet_geocentric_cartesian(planet_index, jd, apply_light_time)?;
+    let (p, _) =
+        planet_geocentric_cartesian(planet_index, jd + VELOCITY_STEP_DAYS, apply_light_time)?;
+    let (m, _) =
+        planet_geocentric_cartesian(planet_index, jd - VELOCITY_STEP_DAYS, apply_light_time)?;
+    let inv = 1.0 / (2.0 * VELOCITY_STEP_DAYS);
+    Some([
+        pos.x,
+        pos.y,
+        pos.z,
+        (p.x - m.x) * inv,
+        (p.y - m.y) * inv,
+        (p.z - m.z) * inv,
+        tau,
+    ])
+}
+
+/// Heliocentric state of a planet by wrapper index: `[x, y, z, vx, vy, vz]`,
+/// ecliptic Cartesian of date in AU and AU/day; `None` for an unknown index
+pub fn planet_heliocentric_state(planet_index: usize, jd: f64) -> Option<[f64; 6]> {
+    let pos = planet_heliocentric_cartesian(planet_index, jd)?;
+    let p = planet_heliocentric_cartesian(planet_index, jd + VELOCITY_STEP_DAYS)?;
+    let m = planet_heliocentric_cartesian(planet_index, jd - VELOCITY_STEP_DAYS)?;
+    let inv = 1.0 / (2.0 * VELOCITY_STEP_DAYS);
+    Some([
+        pos.x,
+        pos.y,
+        pos.z,
+        (p.x - m.x) * inv,
+        (p.y - m.y) * inv,
+        (p.z - m.z) * inv,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_at_j2000() {
+        let state = compute_state(2451545.0);
+        let Some(state) = state else {
+            panic!("no state at J2000");
+        };
+        let earth_r = state.earth.x.hypot(state.earth.y).hypot(state.earth.z);
+        assert!((earth_r - 0.9833).abs() < 1.0e-3, "{}", earth_r);
+        let moon_speed = state
+            .moon_velocity
+            .x
+            .hypot(state.moon_velocity.y)
+            .hypot(state.moon_velocity.z);
+        assert!((0.00050..0.00068).contains(&moon_speed), "{}", moon_speed);
+
+        let flat = state.to_array();
+        assert_eq!(flat[..3], [0.0; 3]);
+        assert_eq!(flat[6], state.earth.x);
+        assert_eq!(flat[10], state.zenith_lat_rad);
+        assert_eq!(flat[16], state.earth_velocity.z);
+
+        assert!(compute_state(f64::NAN).is_none());
+        assert!(compute_state(-1.0).is_none());
+    }
+
+    #[test]
+    fn test_earth_heliocentric_velocity_magnitude() {
+        // Earth orbital speed ≈ 0.0172 AU/day (29.8 km/s)
+        let state = planet_heliocentric_state(2, 2451545.0);
+        let Some(s) = state else {
+            panic!("Earth state should be available");
+        };
+        let speed = (s[3] * s[3] + s[4] * s[4] + s[5] * s[5]).sqrt();
+        assert!(
+            (speed - 0.0172).abs() < 0.0005,
+            "Earth speed out of range: {}",
+            speed
+        );
+    }
+
+    #[test]
+    fn test_planet_light_time_correction() {
+        // Jupiter: Δ ≈ 4–6.5 AU → τ ≈ 0.023–0.038 days
+        let corrected = planet_geocentric_state(4, 2451545.0, true);
+        let geometric = planet_geocentric_state(4, 2451545.0, false);
+        let (Some(c), Some(g)) = (corrected, geometric) else {
+            panic!("no light-time corrected or geometric state");
+        };
+        assert!(
+            c[6] > 0.02 && c[6] < 0.04,
+            "Light time out of range: {}",
+            c[6]
+        );
+        assert_eq!(g[6], 0.0);
+        // Shift ≈ heliocentric speed × τ (≈ 0.007 AU/day × 0.03 d)
+        let shift = ((c[0] - g[0]).powi(2) + (c[1] - g[1]).powi(2)).sqrt();
+        assert!(
+            shift > 1.0e-5 && shift < 1.0e-3,
+            "Light-time shift: {}",
+            shift
+        );
+        assert!(planet_geocentric_state(9, 2451545.0, true).is_none());
+    }
+
+    #[test]
+    fn test_pluto_geocentric_matches_meeus() {
+        // Meeus, Example 37.a: Pluto on 1992 Oct 13.0 TD, astrometric J2000
+        // α = 15h31m43.8s, δ = -4°27'29"
+        let jd = 2448908.5;
+        let ra = (15.0 + 31.0 / 60.0 + 43.8 / 3600.0_f64) * 15.0;
+        let dec = -(4.0 + 27.0 / 60.0 + 29.0 / 3600.0_f64);
+        let oblq_j2000 = astro::ecliptic::mn_oblq_IAU(2451545.0);
+        let long = astro::coords::ecl_long_frm_eq(ra.to_radians(), dec.to_radians(), oblq_j2000);
+        let lat = astro::coords::ecl_lat_frm_eq(ra.to_radians(), dec.to_radians(), oblq_j2000);
+        let (long, lat) = astro::precess::precess_ecl_coords(long, lat, 2451545.0, jd);
+
+        let Some(state) = planet_geocentric_state(8, jd, true) else {
+            panic!("no Pluto state");
+        };
+        let ours_long = state[1].atan2(state[0]);
+        let ours_lat = state[2].atan2(state[0].hypot(state[1]));
+        let d_long = crate::wrap_pi(ours_long - long).to_degrees() * 3600.0 * lat.cos();
+        let d_lat = (ours_lat - lat).to_degrees() * 3600.0;
+        // Frame mix-up (J2000 Pluto vs Earth of date) would be ~6′ here
+        assert!(d_long.abs() < 5.0, "Δλ·cosβ = {}\"", d_long);
+        assert!(d_lat.abs() < 5.0, "Δβ = {}\"", d_lat);
+        // Meeus: Δ = 30.528758 AU
+        let dist = state[0].hypot(state[1]).hypot(state[2]);
+        assert!((dist - 30.528758).abs() < 1.0e-4, "Δ = {}", dist);
+        assert!((state[6] - dist * crate::LIGHT_TIME_DAYS_PER_AU).abs() < 1.0e-6);
+    }
+}
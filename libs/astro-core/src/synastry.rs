@@ -0,0 +1,433 @@
+//! Synastry and composite charts of two natal charts
+//!
+//! Synastry lists the major aspects between the points of two charts (Sun …
+//! Pluto, Ascendant, Midheaven) and scores each one as the aspect weight
+//! times its closeness (1 when exact, 0 at the edge of the orb). Orbs and
+//! weights are configurable per aspect.
+//!
+//! Composite charts follow either the midpoint method (near midpoint of each
+//! pair of natal points and cusps) or the Davison method (a real chart for
+//! the time midpoint and the geographic midpoint of the two births).
+//!
+//! Natal data comes as `BirthChart` from the domain: Western charts use the
+//! tropical zodiac, Vedic charts the Lahiri sidereal one.
+
+use starscalendars_domain::spiritual::{AstrologicalSystem, BirthChart};
+use starscalendars_domain::{DomainError, DomainResult};
+
+use crate::chart::{CHART_LEN, CHART_LONGITUDES, cast_chart};
+use crate::houses::{HouseSystem, chart_angles};
+use crate::timescales::utc_to_tt_jd;
+use crate::wrap_pi;
+use crate::zodiac::{Aspect, BODY_COUNT, Zodiac, apparent_longitude, aspect_between};
+
+/// Chart points compared in synastry: 10 bodies, Ascendant, Midheaven
+pub const SYNASTRY_POINTS: usize = 12;
+
+/// Bound on an orb, exclusive: the sextile and the square are only 30°
+/// apart, so orbs below 15° never let one separation match two aspects
+pub const MAX_ORB_RAD: f64 = 0.261_799_387_799_149_4; // 15° @allow-wasm-const
+
+/// Orbs and weights per aspect, indexed by aspect code
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SynastryConfig {
+    /// Orb per aspect (rad)
+    pub orbs: [f64; 5],
+    /// Score of an exact aspect; negative for challenging ones
+    pub weights: [f64; 5],
+}
+
+impl SynastryConfig {
+    /// Default orbs (`Aspect::default_orb`); trines, sextiles and conjunctions
+    /// count as supportive, squares and oppositions as challenging
+    pub const DEFAULT: Self = Self {
+        orbs: [
+            Aspect::Conjunction.default_orb(),
+            Aspect::Sextile.default_orb(),
+            Aspect::Square.default_orb(),
+            Aspect::Trine.default_orb(),
+            Aspect::Opposition.default_orb(),
+        ],
+        weights: [2.0, 1.0, -1.5, 2.0, -1.0],
+    };
+
+    /// Copy with the orb (rad, 0..`MAX_ORB_RAD`) and weight of one aspect
+    /// replaced; `None` on an unknown aspect code or invalid value
+    ///
+    /// `aspect`: 0 conjunction, 1 sextile, 2 square, 3 trine, 4 opposition.
+    pub fn with_aspect(self, aspect: u32, orb_rad: f64, weight: f64) -> Option<Self> {
+        let index = Aspect::MAJOR
+            .into_iter()
+            .position(|candidate| u32::from(candidate.code()) == aspect)?;
+        if !(0.0..MAX_ORB_RAD).contains(&orb_rad) || !weight.is_finite() {
+            return None;
+        }
+        let mut updated = self;
+        updated.orbs[index] = orb_rad;
+        updated.weights[index] = weight;
+        Some(updated)
+    }
+}
+
+/// Aspect between a point of chart A and a point of chart B
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrossAspect {
+    /// Point of chart A (0..9 body codes, 10 Ascendant, 11 Midheaven)
+    pub point_a: u8,
+    /// Point of chart B
+    pub point_b: u8,
+    /// Aspect formed
+    pub aspect: Aspect,
+    /// Deviation from exact (rad, ≥ 0)
+    pub deviation: f64,
+    /// Weight × closeness
+    pub score: f64,
+}
+
+/// Synastry of two charts
+#[derive(Debug, Clone, PartialEq)]
+pub struct Synastry {
+    /// Cross-aspects, tightest first
+    pub aspects: Vec<CrossAspect>,
+    /// Sum of the aspect scores
+    pub total_score: f64,
+}
+
+/// Composite chart method
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompositeMethod {
+    /// Near midpoints of the natal points and cusps
+    Midpoint,
+    /// Real chart for the time and geographic midpoint of the births
+    Davison,
+}
+
+impl CompositeMethod {
+    /// Decode the WASM method code (0 midpoint, 1 Davison)
+    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(Self::Midpoint),
+            1 => Some(Self::Davison),
+            _ => None,
+        }
+    }
+}
+
+/// Zodiac of an astrological system
+fn zodiac_of(system: &AstrologicalSystem) -> DomainResult<Zodiac> {
+    match system {
+        AstrologicalSystem::Western => Ok(Zodiac::Tropical),
+        AstrologicalSystem::Vedic => Ok(Zodiac::Sidereal),
+        other => Err(DomainError::UnsupportedAstrologicalSystem(format!(
+            "{:?}",
+            other
+        ))),
+    }
+}
+
+/// Birth place in radians `(lat, lon_east)`
+fn birth_place_rad(birth: &BirthChart) -> (f64, f64) {
+    (birth.latitude.to_radians(), birth.longitude.to_radians())
+}
+
+/// Validate both charts and return their common zodiac
+fn common_zodiac(a: &BirthChart, b: &BirthChart) -> DomainResult<Zodiac> {
+    a.validate()?;
+    b.validate()?;
+    let zodiac = zodiac_of(&a.system)?;
+    if zodiac_of(&b.system)? != zodiac {
+        return Err(DomainError::InvalidBirthData(
+            "both charts must use the same astrological system".to_string(),
+        ));
+    }
+    Ok(zodiac)
+}
+
+/// Tropical longitudes of the synastry points of a birth chart
+fn synastry_points(birth: &BirthChart) -> DomainResult<[f64; SYNASTRY_POINTS]> {
+    let (lat, lon) = birth_place_rad(birth);
+    let jd_tt = utc_to_tt_jd(birth.birth_time);
+    let mut points = [0.0; SYNASTRY_POINTS];
+    for (body, slot) in (0..BODY_COUNT).zip(points.iter_mut()) {
+        *slot = apparent_longitude(body, jd_tt)
+            .ok_or_else(|| DomainError::InvalidBirthData("birth time out of range".to_string()))?;
+    }
+    let angles = chart_angles(birth.birth_time, lat, lon);
+    points[10] = angles.ascendant;
+    points[11] = angles.midheaven;
+    Ok(points)
+}
+
+/// Cross-aspects between two natal charts
+///
+/// Aspects do not depend on the zodiac; both charts must still share an
+/// astrological system.
+pub fn synastry(a: &BirthChart, b: &BirthChart, config: &SynastryConfig) -> DomainResult<Synastry> {
+    common_zodiac(a, b)?;
+    let points_a = synastry_points(a)?;
+    let points_b = synastry_points(b)?;
+
+    let mut aspects = Vec::with_capacity(SYNASTRY_POINTS * SYNASTRY_POINTS);
+    for (point_a, &long_a) in (0u8..).zip(points_a.iter()) {
+        for (point_b, &long_b) in (0u8..).zip(points_b.iter()) {
+            let Some((aspect, deviation)) = aspect_between(long_a, long_b, &config.orbs) else {
+                continue;
+            };
+            let code = usize::from(aspect.code());
+            let orb = config.orbs[code];
+            let closeness = if orb > 0.0 {
+                1.0 - deviation / orb
+            } else {
+                1.0
+            };
+            aspects.push(CrossAspect {
+                point_a,
+                point_b,
+                aspect,
+                deviation,
+                score: config.weights[code] * closeness,
+            });
+        }
+    }
+    aspects.sort_by(|x, y| x.deviation.total_cmp(&y.deviation));
+    let total_score = aspects.iter().map(|aspect| aspect.score).sum();
+    Ok(Synastry {
+        aspects,
+        total_score,
+    })
+}
+
+/// Near midpoint of two longitudes (rad, 0..2π)
+fn longitude_midpoint(a: f64, b: f64) -> f64 {
+    astro::angle::limit_to_two_PI(a + 0.5 * wrap_pi(b - a))
+}
+
+/// Geographic midpoint on the sphere `(lat, lon_east)` in radians
+fn geographic_midpoint(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let to_vector =
+        |(lat, lon): (f64, f64)| (lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin());
+    let (ax, ay, az) = to_vector(a);
+    let (bx, by, bz) = to_vector(b);
+    let (x, y, z) = (ax + bx, ay + by, az + bz);
+    if x.hypot(y).hypot(z) < 1.0e-12 {
+        // Antipodal places: no unique midpoint, keep the first longitude
+        return (0.5 * (a.0 + b.0), a.1);
+    }
+    (z.atan2(x.hypot(y)), y.atan2(x))
+}
+
+/// Composite chart (layout of `cast_chart`)
+///
+/// Midpoint: slot 0 is NaN (no real instant), angles, cusps and longitudes
+/// are near midpoints and speeds are averaged. Davison: the chart of the
+/// time and geographic midpoint. `None` inside `Ok` when Placidus is
+/// undefined at a birth (or the Davison) latitude.
+pub fn composite_chart(
+    a: &BirthChart,
+    b: &BirthChart,
+    system: HouseSystem,
+    method: CompositeMethod,
+) -> DomainResult<Option<[f64; CHART_LEN]>> {
+    let zodiac = common_zodiac(a, b)?;
+    let place_a = birth_place_rad(a);
+    let place_b = birth_place_rad(b);
+    match method {
+        CompositeMethod::Davison => {
+            let (lat, lon) = geographic_midpoint(place_a, place_b);
+            let jd_utc = 0.5 * (a.birth_time + b.birth_time);
+            Ok(cast_chart(jd_utc, lat, lon, system, zodiac))
+        }
+        CompositeMethod::Midpoint => {
+            let chart_a = cast_chart(a.birth_time, place_a.0, place_a.1, system, zodiac);
+            let chart_b = cast_chart(b.birth_time, place_b.0, place_b.1, system, zodiac);
+            let (Some(chart_a), Some(chart_b)) = (chart_a, chart_b) else {
+                return Ok(None);
+            };
+            let mut out = [0.0; CHART_LEN];
+            out[0] = f64::NAN;
+            for (i, slot) in out.iter_mut().enumerate().skip(1) {
+                *slot = if i < CHART_LONGITUDES + usize::from(BODY_COUNT) {
+                    longitude_midpoint(chart_a[i], chart_b[i])
+                } else {
+                    0.5 * (chart_a[i] + chart_b[i])
+                };
+            }
+            Ok(Some(out))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chart::{CHART_CUSPS, CHART_SPEEDS};
+
+    fn paris_1990() -> BirthChart {
+        BirthChart {
+            birth_time: 2448058.0, // 1990-06-15 12:00 UTC
+            latitude: 48.85,
+            longitude: 2.35,
+            system: AstrologicalSystem::Western,
+        }
+    }
+
+    fn new_york_1992() -> BirthChart {
+        BirthChart {
+            birth_time: 2448900.25, // 1992-10-04 18:00 UTC
+            latitude: 40.71,
+            longitude: -74.01,
+            system: AstrologicalSystem::Western,
+        }
+    }
+
+    #[test]
+    fn test_synastry_with_itself() {
+        // Every point is conjunct its own copy, exactly
+        let chart = paris_1990();
+        let synastry = synastry(&chart, &chart, &SynastryConfig::DEFAULT);
+        let Ok(synastry) = synastry else {
+            panic!("synastry of a chart with itself failed");
+        };
+        for point in 0..12u8 {
+            assert!(synastry.aspects.iter().any(|a| a.point_a == point
+                && a.point_b == point
+                && a.aspect == Aspect::Conjunction
+                && a.deviation < 1.0e-12
+                && (a.score - 2.0).abs() < 1.0e-9));
+        }
+        let sum: f64 = synastry.aspects.iter().map(|a| a.score).sum();
+        assert!((synastry.total_score - sum).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_synastry_scores_and_config() {
+        let (a, b) = (paris_1990(), new_york_1992());
+        let Ok(found) = synastry(&a, &b, &SynastryConfig::DEFAULT) else {
+            panic!("synastry of the Paris and New York charts failed");
+        };
+        assert!(!found.aspects.is_empty());
+        assert!(
+            found
+                .aspects
+                .windows(2)
+                .all(|w| w[0].deviation <= w[1].deviation)
+        );
+        for aspect in &found.aspects {
+            let code = usize::from(aspect.aspect.code());
+            assert!(aspect.deviation <= SynastryConfig::DEFAULT.orbs[code]);
+            assert!(aspect.score.abs() <= SynastryConfig::DEFAULT.weights[code].abs());
+        }
+
+        // Zero weights give a zero score; zero orbs leave no aspects
+        let silent = SynastryConfig {
+            weights: [0.0; 5],
+            ..SynastryConfig::DEFAULT
+        };
+        assert!(matches!(synastry(&a, &b, &silent), Ok(s) if s.total_score == 0.0));
+        let strict = SynastryConfig {
+            orbs: [0.0; 5],
+            ..SynastryConfig::DEFAULT
+        };
+        assert!(matches!(synastry(&a, &b, &strict), Ok(s) if s.aspects.is_empty()));
+
+        let config = SynastryConfig::DEFAULT.with_aspect(3, 0.1, 3.0);
+        assert!(matches!(config, Some(c) if c.orbs[3] == 0.1 && c.weights[3] == 3.0));
+        assert!(SynastryConfig::DEFAULT.with_aspect(5, 0.1, 1.0).is_none());
+        assert!(SynastryConfig::DEFAULT.with_aspect(0, 1.0, 1.0).is_none());
+        assert!(
+            SynastryConfig::DEFAULT
+                .with_aspect(0, 0.1, f64::NAN)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_widest_orbs_do_not_overlap() {
+        assert!(
+            SynastryConfig::DEFAULT
+                .with_aspect(1, MAX_ORB_RAD, 1.0)
+                .is_none()
+        );
+        let widest = MAX_ORB_RAD - 1.0e-9;
+        let Some(config) = SynastryConfig::DEFAULT
+            .with_aspect(1, widest, 1.0)
+            .and_then(|c| c.with_aspect(2, widest, -1.5))
+        else {
+            panic!("orbs just below the bound rejected");
+        };
+
+        // Sextile (60°) and square (90°) meet at 75°
+        let near = |deg: f64| aspect_between(deg.to_radians(), 0.0, &config.orbs);
+        assert!(matches!(near(74.9), Some((Aspect::Sextile, _))));
+        assert!(matches!(near(75.1), Some((Aspect::Square, _))));
+        assert!(near(75.0).is_none());
+    }
+
+    #[test]
+    fn test_systems_must_match() {
+        let vedic = BirthChart {
+            system: AstrologicalSystem::Vedic,
+            ..new_york_1992()
+        };
+        assert!(matches!(
+            synastry(&paris_1990(), &vedic, &SynastryConfig::DEFAULT),
+            Err(DomainError::InvalidBirthData(_))
+        ));
+        let mayan = BirthChart {
+            system: AstrologicalSystem::Mayan,
+            ..paris_1990()
+        };
+        assert!(matches!(
+            composite_chart(
+                &mayan,
+                &mayan,
+                HouseSystem::Equal,
+                CompositeMethod::Midpoint
+            ),
+            Err(DomainError::UnsupportedAstrologicalSystem(_))
+        ));
+    }
+
+    #[test]
+    fn test_composite_charts() {
+        let (a, b) = (paris_1990(), new_york_1992());
+        let system = HouseSystem::Placidus;
+        let midpoint = composite_chart(&a, &b, system, CompositeMethod::Midpoint);
+        let (place_a, place_b) = (birth_place_rad(&a), birth_place_rad(&b));
+        let chart_a = cast_chart(a.birth_time, place_a.0, place_a.1, system, Zodiac::Tropical);
+        let chart_b = cast_chart(b.birth_time, place_b.0, place_b.1, system, Zodiac::Tropical);
+        assert!(matches!(midpoint, Ok(Some(_))));
+        if let (Ok(Some(m)), Some(ca), Some(cb)) = (midpoint, chart_a, chart_b) {
+            assert!(m[0].is_nan());
+            // Points sit halfway along the shorter arc between the natal ones
+            for i in 1..CHART_SPEEDS {
+                let half = wrap_pi(cb[i] - ca[i]) / 2.0;
+                assert!(wrap_pi(m[i] - ca[i] - half).abs() < 1.0e-12, "slot {}", i);
+            }
+            assert!(
+                (m[CHART_SPEEDS] - 0.5 * (ca[CHART_SPEEDS] + cb[CHART_SPEEDS])).abs() < 1.0e-15
+            );
+            assert!(
+                m[CHART_CUSPS..CHART_LONGITUDES]
+                    .iter()
+                    .all(|c| (0.0..std::f64::consts::TAU).contains(c))
+            );
+        }
+
+        // Davison: the chart of the midpoint in time and space
+        let davison = composite_chart(&a, &b, system, CompositeMethod::Davison);
+        assert!(matches!(davison, Ok(Some(d)) if (d[0] - 2448479.125).abs() < 1.0e-9));
+        let (lat, lon) = geographic_midpoint(birth_place_rad(&a), birth_place_rad(&b));
+        // Great-circle midpoint Paris–New York lies over the North Atlantic
+        assert!(
+            (51.0..52.0).contains(&lat.to_degrees()),
+            "{}",
+            lat.to_degrees()
+        );
+        assert!(
+            (-39.5..-38.5).contains(&lon.to_degrees()),
+            "{}",
+            lon.to_degrees()
+        );
+    }
+}
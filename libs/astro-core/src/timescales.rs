@@ -0,0 +1,119 @@
+//! Reusable time scale utilities (UTC↔TT) with leap seconds support
+//!
+//! (TT − UTC) = (TAI − UTC) + 32.184 s, with TAI − UTC from the leap second
+//! table; saturates at the last known value for future dates. The override
+//! is per thread, as in the WASM module.
+
+use std::cell::RefCell;
+
+thread_local! {
+    static TAI_MINUS_UTC_OVERRIDE: RefCell<Option<f64>> = const { RefCell::new(None) };
+}
+
+const LEAP_SECONDS: &[(i32, u8, f64)] = &[
+    (1972, 1, 10.0),
+    (1972, 7, 11.0),
+    (1973, 1, 12.0),
+    (1974, 1, 13.0),
+    (1975, 1, 14.0),
+    (1976, 1, 15.0),
+    (1977, 1, 16.0),
+    (1978, 1, 17.0),
+    (1979, 1, 18.0),
+    (1980, 1, 19.0),
+    (1981, 7, 20.0),
+    (1982, 7, 21.0),
+    (1983, 7, 22.0),
+    (1985, 7, 23.0),
+    (1988, 1, 24.0),
+    (1990, 1, 25.0),
+    (1991, 1, 26.0),
+    (1992, 7, 27.0),
+    (1993, 7, 28.0),
+    (1994, 7, 29.0),
+    (1996, 1, 30.0),
+    (1997, 7, 31.0),
+    (1999, 1, 32.0),
+    (2006, 1, 33.0),
+    (2009, 1, 34.0),
+    (2013, 1, 35.0),
+    (2015, 7, 36.0),
+    (2017, 1, 37.0),
+];
+
+fn tai_minus_utc_seconds_for_year_month(year: i32, month: u8) -> f64 {
+    let override_val = TAI_MINUS_UTC_OVERRIDE.with(|c| *c.borrow());
+    if let Some(v) = override_val {
+        return v;
+    }
+    // Saturate to last known (37 s since 2017-01) for future years until updated
+    let mut val = if year < 1972 { 0.0 } else { 10.0 };
+    for &(y, m, v) in LEAP_SECONDS {
+        if year > y || (year == y && month >= m) {
+            val = v;
+        }
+    }
+    val
+}
+
+/// TT − UTC in seconds for the calendar month of a Julian Day, NaN when the
+/// date cannot be computed
+fn tt_minus_utc_seconds(jd: f64) -> f64 {
+    match astro::time::date_frm_julian_day(jd) {
+        Ok((y, m, _d)) => tai_minus_utc_seconds_for_year_month(i32::from(y), m) + 32.184,
+        Err(_) => f64::NAN,
+    }
+}
+
+/// Julian Day UTC → Julian Ephemeris Day (TT); NaN on invalid input
+pub fn utc_to_tt_jd(jd_utc: f64) -> f64 {
+    jd_utc + tt_minus_utc_seconds(jd_utc) / 86400.0
+}
+
+/// Julian Ephemeris Day (TT) → Julian Day UTC; NaN on invalid input
+///
+/// The leap second count is taken at the TT date.
+pub fn tt_to_utc_jd(jd_tt: f64) -> f64 {
+    jd_tt - tt_minus_utc_seconds(jd_tt) / 86400.0
+}
+
+/// Use a fixed TAI − UTC (seconds) on this thread instead of the table;
+/// negative or non-finite values clear the override
+pub fn set_tai_minus_utc_override(seconds: f64) {
+    TAI_MINUS_UTC_OVERRIDE.with(|c| {
+        *c.borrow_mut() = if seconds.is_finite() && seconds >= 0.0 {
+            Some(seconds)
+        } else {
+            None
+        };
+    });
+}
+
+/// Return to the leap second table on this thread
+pub fn clear_tai_minus_utc_override() {
+    TAI_MINUS_UTC_OVERRIDE.with(|c| {
+        *c.borrow_mut() = None;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utc_tt_round_trip() {
+        // 2025-01-01 00:00 UTC: TT − UTC = 37 + 32.184 s
+        let jd_utc = 2460676.5;
+        let jd_tt = utc_to_tt_jd(jd_utc);
+        assert!(((jd_tt - jd_utc) * 86400.0 - 69.184).abs() < 1.0e-3);
+        assert!((tt_to_utc_jd(jd_tt) - jd_utc).abs() < 1.0e-8);
+        // Before 1972 only the 32.184 s TT − TAI offset remains
+        assert!(((utc_to_tt_jd(2440000.5) - 2440000.5) * 86400.0 - 32.184).abs() < 1.0e-3);
+        assert!(utc_to_tt_jd(-1.0).is_nan());
+
+        set_tai_minus_utc_override(40.0);
+        assert!(((utc_to_tt_jd(jd_utc) - jd_utc) * 86400.0 - 72.184).abs() < 1.0e-3);
+        clear_tai_minus_utc_override();
+        assert_eq!(utc_to_tt_jd(jd_utc), jd_tt);
+    }
+}
@@ -0,0 +1,250 @@
+//! Moon sign ingresses and void-of-course windows
+//!
+//! The Moon is void of course from its last exact major aspect (conjunction,
+//! sextile, square, trine, opposition) to a planet until it enters the next
+//! sign. Aspects to the Sun and to all planets through Pluto are considered;
+//! aspects do not depend on the zodiac, ingresses do (tropical or Lahiri
+//! sidereal, see `zodiac`).
+//!
+//! When the Moon makes no aspect at all during a sign, the window started at
+//! the last aspect in an earlier sign and the void start precedes the ingress.
+
+use crate::wrap_pi;
+use crate::zodiac::{
+    Aspect, BODY_COUNT, Ingress, MOON, Zodiac, apparent_longitude, refine_bracketed_root,
+    sign_ingresses,
+};
+
+/// Scan step of the Moon's sign changes, in days (≤ 4° of lunar motion)
+const MOON_SCAN_STEP_DAYS: f64 = 0.25; // @allow-wasm-const
+
+/// Margin around the range covering a full sign stay, in days (> 2.8 d)
+const STAY_MARGIN_DAYS: f64 = 3.0; // @allow-wasm-const
+
+/// Exact major aspect of the Moon to another body
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LunarAspect {
+    /// Instant of exactness (JD TT)
+    pub jd_tt: f64,
+    /// Body code of the aspected body
+    pub body: u8,
+    /// Aspect formed
+    pub aspect: Aspect,
+}
+
+/// Sign stay of the Moon with its void-of-course window
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoonSignStay {
+    /// Ingress into the sign
+    pub ingress: Ingress,
+    /// Ingress into the next sign (end of the void), JD TT
+    pub exit_jd_tt: f64,
+    /// Last aspect before the exit; `None` when none was found
+    pub last_aspect: Option<LunarAspect>,
+}
+
+/// Apparent longitudes of all body codes (rad)
+fn body_longitudes(jd_tt: f64) -> Option<[f64; 10]> {
+    let mut longitudes = [0.0; 10];
+    for (body, slot) in (0..BODY_COUNT).zip(longitudes.iter_mut()) {
+        *slot = apparent_longitude(body, jd_tt)?;
+    }
+    Some(longitudes)
+}
+
+/// Quadratic interpolation of an unwrapped longitude through three equally
+/// spaced samples at `t0`, `t0 + h`, `t0 + 2h`
+fn quadratic_longitude(samples: [f64; 3], t0: f64, h: f64, t: f64) -> f64 {
+    let v1 = samples[0] + wrap_pi(samples[1] - samples[0]);
+    let v2 = v1 + wrap_pi(samples[2] - samples[1]);
+    let s = (t - t0) / h;
+    samples[0] + s * (v1 - samples[0]) + 0.5 * s * (s - 1.0) * (v2 - 2.0 * v1 + samples[0])
+}
+
+/// Last exact major aspect of the Moon inside `[t_in, t_out]` (JD TT)
+///
+/// Planet longitudes are interpolated quadratically through the endpoints
+/// and the midpoint (error far below 1″ over a sign stay); the Moon is
+/// evaluated exactly. The Moon outpaces every planet, so each aspect phase
+/// is crossed at most once per stay.
+fn last_aspect_in(
+    t_in: f64,
+    t_out: f64,
+    at_in: &[f64; 10],
+    at_out: &[f64; 10],
+) -> Option<Option<LunarAspect>> {
+    let h = 0.5 * (t_out - t_in);
+    let at_mid = body_longitudes(t_in + h)?;
+    let moon_at = |t: f64| apparent_longitude(MOON, t).unwrap_or(f64::NAN);
+    let moon_index = usize::from(MOON);
+
+    let mut last: Option<LunarAspect> = None;
+    for body in (0..BODY_COUNT).filter(|&body| body != MOON) {
+        let i = usize::from(body);
+        let samples = [at_in[i], at_mid[i], at_out[i]];
+        let relative_in = at_in[moon_index] - at_in[i];
+        let relative_out = at_out[moon_index] - at_out[i];
+        for aspect in Aspect::MAJOR {
+            for &phase in aspect.phases() {
+                let g_in = wrap_pi(relative_in - phase);
+                let g_out = g_in + wrap_pi(relative_out - relative_in);
+                if !(g_in < 0.0 && g_out >= 0.0) {
+                    continue;
+                }
+                let residual =
+                    |t: f64| wrap_pi(moon_at(t) - quadratic_longitude(samples, t_in, h, t) - phase);
+                let jd_tt = refine_bracketed_root(residual, t_in, t_out);
+                if last.is_none_or(|previous| jd_tt > previous.jd_tt) {
+                    last = Some(LunarAspect {
+                        jd_tt,
+                        body,
+                        aspect,
+                    });
+                }
+            }
+        }
+    }
+    Some(last)
+}
+
+/// Moon sign stays with an ingress in `[jd_tt_start, jd_tt_end)`
+///
+/// Also solves the stay before the range so that a stay without aspects can
+/// inherit the void start from it.
+pub fn moon_sign_stays(
+    zodiac: Zodiac,
+    jd_tt_start: f64,
+    jd_tt_end: f64,
+) -> Option<Vec<MoonSignStay>> {
+    let ingresses = sign_ingresses(
+        MOON,
+        zodiac,
+        jd_tt_start - STAY_MARGIN_DAYS,
+        jd_tt_end + STAY_MARGIN_DAYS,
+        MOON_SCAN_STEP_DAYS,
+    )?;
+
+    let mut stays = Vec::with_capacity(ingresses.len());
+    let mut carried: Option<LunarAspect> = None;
+    let mut longitudes_in: Option<[f64; 10]> = None;
+    for pair in ingresses.windows(2) {
+        let [ingress, exit] = pair else { continue };
+        if ingress.jd_tt >= jd_tt_end {
+            break;
+        }
+        let at_in = match longitudes_in {
+            Some(longitudes) => longitudes,
+            None => body_longitudes(ingress.jd_tt)?,
+        };
+        let at_out = body_longitudes(exit.jd_tt)?;
+        longitudes_in = Some(at_out);
+
+        let last_aspect = last_aspect_in(ingress.jd_tt, exit.jd_tt, &at_in, &at_out)?.or(carried);
+        carried = last_aspect;
+        if ingress.jd_tt >= jd_tt_start {
+            stays.push(MoonSignStay {
+                ingress: *ingress,
+                exit_jd_tt: exit.jd_tt,
+                last_aspect,
+            });
+        }
+    }
+    Some(stays)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zodiac::{SIGN_WIDTH_RAD, zodiac_longitude};
+
+    /// 2025-01-01 00:00 TT
+    const JAN_2025: f64 = 2460676.5;
+
+    #[test]
+    fn test_ingresses_follow_the_zodiac() {
+        for zodiac in [Zodiac::Tropical, Zodiac::Sidereal] {
+            let stays = moon_sign_stays(zodiac, JAN_2025, JAN_2025 + 30.0);
+            assert!(matches!(&stays, Some(s) if (13..=14).contains(&s.len())));
+            let Some(stays) = stays else { continue };
+            for pair in stays.windows(2) {
+                let [a, b] = pair else { continue };
+                assert_eq!((a.ingress.to + 1) % 12, b.ingress.to);
+                assert!((a.exit_jd_tt - b.ingress.jd_tt).abs() < 1.0e-9);
+                // 2.0–2.8 days per sign
+                let days = a.exit_jd_tt - a.ingress.jd_tt;
+                assert!((1.9..2.9).contains(&days), "{}", days);
+            }
+            for stay in &stays {
+                let long = zodiac_longitude(MOON, zodiac, stay.ingress.jd_tt).unwrap_or(f64::NAN);
+                let boundary = f64::from(stay.ingress.to) * SIGN_WIDTH_RAD;
+                assert!(wrap_pi(long - boundary).abs() < 1.0e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sidereal_ingress_lags_tropical() {
+        // Ayanamsa ~24.2° at ~13.2°/day: each sidereal ingress ~1.8 days later
+        let tropical = moon_sign_stays(Zodiac::Tropical, JAN_2025, JAN_2025 + 10.0);
+        let sidereal = moon_sign_stays(Zodiac::Sidereal, JAN_2025, JAN_2025 + 10.0);
+        if let (Some(tropical), Some(sidereal)) = (tropical, sidereal) {
+            let first = tropical.first().map(|stay| stay.ingress);
+            let same_sign = first.and_then(|first| {
+                sidereal
+                    .iter()
+                    .find(|stay| stay.ingress.to == first.to)
+                    .map(|stay| stay.ingress.jd_tt - first.jd_tt)
+            });
+            assert!(
+                matches!(same_sign, Some(lag) if (1.5..2.3).contains(&lag)),
+                "{:?}",
+                same_sign
+            );
+        }
+    }
+
+    #[test]
+    fn test_void_starts_at_the_last_aspect() {
+        let stays = moon_sign_stays(Zodiac::Tropical, JAN_2025, JAN_2025 + 6.0);
+        let Some(stay) = stays.as_ref().and_then(|s| s.first()) else {
+            panic!("no Moon sign stays in early 2025");
+        };
+        let Some(last) = stay.last_aspect else {
+            panic!("first stay has no last aspect");
+        };
+        assert!(last.jd_tt <= stay.exit_jd_tt);
+
+        // The aspect is exact at the returned instant
+        let relative = |t: f64, body: u8| {
+            apparent_longitude(MOON, t).unwrap_or(f64::NAN)
+                - apparent_longitude(body, t).unwrap_or(f64::NAN)
+        };
+        let exact = last
+            .aspect
+            .phases()
+            .iter()
+            .map(|&phase| wrap_pi(relative(last.jd_tt, last.body) - phase).abs())
+            .fold(f64::INFINITY, f64::min);
+        assert!(exact < 1.0e-6, "{}", exact);
+
+        // Brute force: no phase is crossed between the void start and the exit
+        let step = 10.0 / 1440.0;
+        for body in (0..BODY_COUNT).filter(|&body| body != MOON) {
+            let mut t = last.jd_tt + step;
+            let mut previous = relative(t, body);
+            while t + step < stay.exit_jd_tt {
+                t += step;
+                let current = relative(t, body);
+                for aspect in Aspect::MAJOR {
+                    for &phase in aspect.phases() {
+                        let crossed = wrap_pi(previous - phase) < 0.0
+                            && wrap_pi(current - phase) >= 0.0
+                            && wrap_pi(current - phase) < 0.5;
+                        assert!(!crossed, "body {} aspect {:?} at {}", body, aspect, t);
+                    }
+                }
+                previous = current;
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Subsolar point: where the Sun is directly overhead (zenith)
+
+/// Solar zenith position in radians `(lon_east, lat)` at a Julian Day
+///
+/// **Algorithm using pure astro-rust:**
+/// 1. Get Sun's geocentric ecliptic position (VSOP87 theory)
+/// 2. Apply FK5, annual aberration and nutation corrections
+/// 3. Convert to equatorial coordinates with the true obliquity
+/// 4. Apparent sidereal time at Greenwich
+/// 5. Zenith longitude = apparent sidereal time − right ascension, in (-π, π]
+/// 6. Zenith latitude = declination
+#[inline]
+pub fn solar_zenith_position_rad(julian_day: f64) -> (f64, f64) {
+    // Geocentric solar ecliptic position with high-precision corrections
+    let (sun_ecl, sun_dist_au) = astro::sun::geocent_ecl_pos(julian_day);
+    // FK5 conversion (longitude/latitude)
+    let (sun_long_fk5, sun_lat_fk5) =
+        astro::sun::ecl_coords_to_FK5(julian_day, sun_ecl.long, sun_ecl.lat);
+    // Annual aberration in ecliptic longitude
+    let ab_long = astro::aberr::sol_aberr(sun_dist_au);
+    // Nutation and true obliquity
+    let (nut_long, nut_oblq) = astro::nutation::nutation(julian_day);
+    let mean_oblq = astro::ecliptic::mn_oblq_IAU(julian_day);
+    let true_oblq = mean_oblq + nut_oblq;
+    let sun_corrected_long = sun_long_fk5 + ab_long + nut_long;
+    // Equatorial coordinates (apparent)
+    let sun_right_ascension =
+        astro::coords::asc_frm_ecl(sun_corrected_long, sun_lat_fk5, true_oblq);
+    let sun_declination = astro::coords::dec_frm_ecl(sun_corrected_long, sun_lat_fk5, true_oblq);
+    // Sidereal time (apparent)
+    let mean_sidereal_time = astro::time::mn_sidr(julian_day);
+    let apparent_sidereal_time = astro::time::apprnt_sidr(mean_sidereal_time, nut_long, true_oblq);
+    // Zenith longitude east-positive
+    let mut zenith_longitude_rad = apparent_sidereal_time - sun_right_ascension;
+    let two_pi_limited = astro::angle::limit_to_two_PI(zenith_longitude_rad);
+    zenith_longitude_rad = if two_pi_limited > std::f64::consts::PI {
+        two_pi_limited - 2.0 * std::f64::consts::PI
+    } else {
+        two_pi_limited
+    };
+    (zenith_longitude_rad, sun_declination)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solar_zenith_position_calculation() {
+        let (lon_east_rad, lat_rad) = solar_zenith_position_rad(2451545.0);
+        assert!(
+            (-std::f64::consts::PI..=std::f64::consts::PI).contains(&lon_east_rad),
+            "Solar zenith longitude out of bounds: {}",
+            lon_east_rad
+        );
+        // Early January: the subsolar point is near the Tropic of Capricorn
+        // (δ ≈ -23.0°), and around local noon near Greenwich at 12:00 TT
+        assert!((lat_rad.to_degrees() + 23.0).abs() < 0.2, "{}", lat_rad);
+        assert!(lon_east_rad.to_degrees().abs() < 2.0, "{}", lon_east_rad);
+    }
+}
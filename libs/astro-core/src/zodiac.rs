@@ -0,0 +1,417 @@
+//! Zodiac signs, ayanamsa and major aspects shared by the astrology solvers
+//!
+//! Longitudes are apparent geocentric ecliptic longitudes of date (light-time,
+//! FK5, annual aberration, nutation), the frame of tropical astrology.
+//! Sidereal longitudes are referred to the mean equinox and shifted by the
+//! Lahiri (Chitrapaksha) ayanamsa. Times are JD TT unless stated otherwise.
+//!
+//! Bodies are encoded 0 Sun, 1 Moon, 2 Mercury, 3 Venus, 4 Mars, 5 Jupiter,
+//! 6 Saturn, 7 Uranus, 8 Neptune, 9 Pluto. Signs are 0 Aries … 11 Pisces.
+
+use crate::bodies::{self, Body};
+use crate::wrap_pi;
+
+/// Body code of the Sun
+pub const SUN: u8 = 0;
+
+/// Body code of the Moon
+pub const MOON: u8 = 1;
+
+/// Number of encoded bodies (Sun … Pluto)
+pub const BODY_COUNT: u8 = 10;
+
+/// Width of a zodiac sign (30°)
+pub const SIGN_WIDTH_RAD: f64 = std::f64::consts::PI / 6.0; // @allow-wasm-const
+
+/// Julian Day of J2000.0
+const J2000_JD: f64 = 2451545.0; // @allow-wasm-const
+
+/// Lahiri ayanamsa at J2000.0 (23°51′25.5″)
+const LAHIRI_J2000_RAD: f64 = 0.416_384_654_089_330_56; // @allow-wasm-const
+
+/// Convergence threshold of the root refinement, in days (~9 ms)
+const ROOT_TOLERANCE_DAYS: f64 = 1.0e-7; // @allow-wasm-const
+
+/// Maximum iterations of the root refinement
+const ROOT_MAX_ITERS: u32 = 40; // @allow-wasm-const
+
+/// Half-step of the numeric derivative in the root refinement (~86 s)
+const ROOT_DERIVATIVE_STEP_DAYS: f64 = 1.0e-3; // @allow-wasm-const
+
+/// Zodiac in which signs are counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zodiac {
+    /// Signs from the true equinox of date
+    Tropical,
+    /// Signs from the Lahiri sidereal origin
+    Sidereal,
+}
+
+impl Zodiac {
+    /// Decode the WASM zodiac code (0 tropical, 1 sidereal)
+    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(Self::Tropical),
+            1 => Some(Self::Sidereal),
+            _ => None,
+        }
+    }
+}
+
+/// Major (Ptolemaic) aspect
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aspect {
+    /// 0°
+    Conjunction,
+    /// 60°
+    Sextile,
+    /// 90°
+    Square,
+    /// 120°
+    Trine,
+    /// 180°
+    Opposition,
+}
+
+impl Aspect {
+    /// All major aspects, in code order
+    pub const MAJOR: [Self; 5] = [
+        Self::Conjunction,
+        Self::Sextile,
+        Self::Square,
+        Self::Trine,
+        Self::Opposition,
+    ];
+
+    /// WASM code (0 conjunction … 4 opposition)
+    pub fn code(self) -> u8 {
+        match self {
+            Self::Conjunction => 0,
+            Self::Sextile => 1,
+            Self::Square => 2,
+            Self::Trine => 3,
+            Self::Opposition => 4,
+        }
+    }
+
+    /// Exact separation of the aspect (rad, 0..π)
+    pub fn angle(self) -> f64 {
+        match self {
+            Self::Conjunction => 0.0,
+            Self::Sextile => std::f64::consts::FRAC_PI_3,
+            Self::Square => std::f64::consts::FRAC_PI_2,
+            Self::Trine => 2.0 * std::f64::consts::FRAC_PI_3,
+            Self::Opposition => std::f64::consts::PI,
+        }
+    }
+
+    /// Default orb (rad): 8° conjunction/opposition, 6° square/trine, 4° sextile
+    pub const fn default_orb(self) -> f64 {
+        let degrees: f64 = match self {
+            Self::Conjunction | Self::Opposition => 8.0,
+            Self::Square | Self::Trine => 6.0,
+            Self::Sextile => 4.0,
+        };
+        degrees.to_radians()
+    }
+
+    /// Signed longitude differences `λ₁ − λ₂` at which the aspect is exact
+    /// (one for conjunction and opposition, waxing and waning otherwise)
+    pub fn phases(self) -> &'static [f64] {
+        const CONJUNCTION: [f64; 1] = [0.0];
+        const SEXTILE: [f64; 2] = [std::f64::consts::FRAC_PI_3, -std::f64::consts::FRAC_PI_3];
+        const SQUARE: [f64; 2] = [std::f64::consts::FRAC_PI_2, -std::f64::consts::FRAC_PI_2];
+        const TRINE: [f64; 2] = [
+            2.0 * std::f64::consts::FRAC_PI_3,
+            -2.0 * std::f64::consts::FRAC_PI_3,
+        ];
+        const OPPOSITION: [f64; 1] = [std::f64::consts::PI];
+        match self {
+            Self::Conjunction => &CONJUNCTION,
+            Self::Sextile => &SEXTILE,
+            Self::Square => &SQUARE,
+            Self::Trine => &TRINE,
+            Self::Opposition => &OPPOSITION,
+        }
+    }
+}
+
+/// Major aspect between two longitudes within per-aspect orbs (indexed by
+/// aspect code), with its deviation from exact (rad, ≥ 0)
+///
+/// Orbs below 15° never overlap, so at most one aspect matches.
+pub fn aspect_between(long_a: f64, long_b: f64, orbs: &[f64; 5]) -> Option<(Aspect, f64)> {
+    let separation = wrap_pi(long_a - long_b).abs();
+    Aspect::MAJOR.into_iter().find_map(|aspect| {
+        let deviation = (separation - aspect.angle()).abs();
+        (deviation <= orbs[usize::from(aspect.code())]).then_some((aspect, deviation))
+    })
+}
+
+/// Apparent geocentric ecliptic longitude of a body code (rad, 0..2π)
+///
+/// See `bodies::apparent_longitude`.
+pub fn apparent_longitude(body: u8, jd_tt: f64) -> Option<f64> {
+    bodies::apparent_longitude(Body::from_code(body)?, jd_tt)
+}
+
+/// Lahiri ayanamsa from the mean equinox of date (rad)
+///
+/// J2000 value plus the IAU 1976 general precession in longitude.
+pub fn lahiri_ayanamsa(jd_tt: f64) -> f64 {
+    let t = (jd_tt - J2000_JD) / 36525.0;
+    let precession_arcsec = t * (5029.0966 + t * 1.11113);
+    LAHIRI_J2000_RAD + (precession_arcsec / 3600.0).to_radians()
+}
+
+/// Tropical longitude of date (true equinox) expressed in a zodiac (rad, 0..2π)
+pub fn to_zodiac(tropical_rad: f64, zodiac: Zodiac, jd_tt: f64) -> f64 {
+    match zodiac {
+        Zodiac::Tropical => astro::angle::limit_to_two_PI(tropical_rad),
+        Zodiac::Sidereal => {
+            let (nut_long, _nut_oblq) = astro::nutation::nutation(jd_tt);
+            astro::angle::limit_to_two_PI(tropical_rad - nut_long - lahiri_ayanamsa(jd_tt))
+        }
+    }
+}
+
+/// Ecliptic longitude of a body code in a zodiac (rad, 0..2π)
+pub fn zodiac_longitude(body: u8, zodiac: Zodiac, jd_tt: f64) -> Option<f64> {
+    Some(to_zodiac(apparent_longitude(body, jd_tt)?, zodiac, jd_tt))
+}
+
+/// Sign (0 Aries … 11 Pisces) of an ecliptic longitude
+pub fn sign_of(longitude_rad: f64) -> u8 {
+    let sector = astro::angle::limit_to_two_PI(longitude_rad) / SIGN_WIDTH_RAD;
+    (0..12u8)
+        .rev()
+        .find(|&sign| sector >= f64::from(sign))
+        .unwrap_or(0)
+}
+
+/// Refine the root of `f` inside a bracket `[lo, hi]` with a sign change
+///
+/// Newton steps with a numeric derivative, falling back to bisection when a
+/// step leaves the bracket. `f` should be a small wrapped angle residual.
+pub fn refine_bracketed_root<F: Fn(f64) -> f64>(f: F, mut lo: f64, mut hi: f64) -> f64 {
+    let f_lo_sign = f(lo) < 0.0;
+    let mut t = 0.5 * (lo + hi);
+    for _ in 0..ROOT_MAX_ITERS {
+        let f_t = f(t);
+        if f_t == 0.0 || hi - lo < ROOT_TOLERANCE_DAYS {
+            return t;
+        }
+        if (f_t < 0.0) == f_lo_sign {
+            lo = t;
+        } else {
+            hi = t;
+        }
+        let slope = wrap_pi(f(t + ROOT_DERIVATIVE_STEP_DAYS) - f(t - ROOT_DERIVATIVE_STEP_DAYS))
+            / (2.0 * ROOT_DERIVATIVE_STEP_DAYS);
+        let newton = t - f_t / slope;
+        if slope.is_finite() && slope != 0.0 && newton > lo && newton < hi {
+            if (newton - t).abs() < ROOT_TOLERANCE_DAYS {
+                return newton;
+            }
+            t = newton;
+        } else {
+            t = 0.5 * (lo + hi);
+        }
+    }
+    t
+}
+
+/// Division of the ecliptic into 12 sectors: the signs or a chart's houses
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sectors {
+    /// 30° signs from the zodiac origin
+    Signs,
+    /// Houses delimited by 12 cusp longitudes (house 1 … 12 as 0 … 11)
+    Houses([f64; 12]),
+}
+
+impl Sectors {
+    /// Sector (0..11) containing an ecliptic longitude
+    pub fn sector_of(&self, longitude_rad: f64) -> u8 {
+        match self {
+            Self::Signs => sign_of(longitude_rad),
+            Self::Houses(cusps) => (0..12u8)
+                .find(|&house| {
+                    let start = self.start(house);
+                    let width =
+                        astro::angle::limit_to_two_PI(cusps[usize::from((house + 1) % 12)] - start);
+                    astro::angle::limit_to_two_PI(longitude_rad - start) < width
+                })
+                .unwrap_or(0),
+        }
+    }
+
+    /// Longitude at which a sector starts (rad)
+    pub fn start(&self, sector: u8) -> f64 {
+        match self {
+            Self::Signs => f64::from(sector) * SIGN_WIDTH_RAD,
+            Self::Houses(cusps) => cusps[usize::from(sector % 12)],
+        }
+    }
+}
+
+/// Passage of a body from one sector (sign or house) into another
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ingress {
+    /// Instant of the crossing (JD TT)
+    pub jd_tt: f64,
+    /// Body code
+    pub body: u8,
+    /// Sector left
+    pub from: u8,
+    /// Sector entered
+    pub to: u8,
+    /// Whether the body was moving backwards along the ecliptic
+    pub retrograde: bool,
+}
+
+/// Sector crossings of a body in `[jd_tt_start, jd_tt_end)`, in time order
+///
+/// `longitude_at` returns the body's longitude at a JD TT. Scans with
+/// `step_days`, which must be short enough that the body crosses at most one
+/// boundary per step, and refines each crossing with `refine_bracketed_root`.
+/// Retrograde crossings are detected from the motion over the step, so a
+/// retrograde re-entry yields its own ingress back into the previous sector.
+pub fn ingresses<F: Fn(f64) -> Option<f64>>(
+    body: u8,
+    longitude_at: F,
+    sectors: &Sectors,
+    jd_tt_start: f64,
+    jd_tt_end: f64,
+    step_days: f64,
+) -> Option<Vec<Ingress>> {
+    let mut found = Vec::new();
+    let mut t0 = jd_tt_start;
+    let mut long0 = longitude_at(t0)?;
+    let mut sector0 = sectors.sector_of(long0);
+    while t0 < jd_tt_end {
+        let t1 = (t0 + step_days).min(jd_tt_end);
+        let long1 = longitude_at(t1)?;
+        let sector1 = sectors.sector_of(long1);
+        if sector1 != sector0 {
+            // Forward motion crosses the start of the new sector, backward
+            // motion the start of the old one
+            let retrograde = wrap_pi(long1 - long0) < 0.0;
+            let boundary = sectors.start(if retrograde { sector0 } else { sector1 });
+            let residual =
+                |t: f64| longitude_at(t).map_or(f64::NAN, |long| wrap_pi(long - boundary));
+            found.push(Ingress {
+                jd_tt: refine_bracketed_root(residual, t0, t1),
+                body,
+                from: sector0,
+                to: sector1,
+                retrograde,
+            });
+        }
+        t0 = t1;
+        long0 = long1;
+        sector0 = sector1;
+    }
+    Some(found)
+}
+
+/// Sign ingresses of a body in `[jd_tt_start, jd_tt_end)`, see `ingresses`
+pub fn sign_ingresses(
+    body: u8,
+    zodiac: Zodiac,
+    jd_tt_start: f64,
+    jd_tt_end: f64,
+    step_days: f64,
+) -> Option<Vec<Ingress>> {
+    ingresses(
+        body,
+        |t| zodiac_longitude(body, zodiac, t),
+        &Sectors::Signs,
+        jd_tt_start,
+        jd_tt_end,
+        step_days,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apparent_longitudes_meeus() {
+        // Meeus Example 33.a: Venus 1992 Dec 20.0 TD, λ = 313.08102°
+        let venus = apparent_longitude(3, 2448976.5);
+        assert!(
+            matches!(venus, Some(l) if (l.to_degrees() - 313.08102).abs() < 0.002),
+            "{:?}",
+            venus.map(f64::to_degrees)
+        );
+        // Meeus Example 47.a: Moon 1992 Apr 12.0 TD, λ = 133.167265°
+        let moon = apparent_longitude(MOON, 2448724.5);
+        assert!(matches!(moon, Some(l) if (l.to_degrees() - 133.167265).abs() < 1.0e-3));
+        assert!(apparent_longitude(BODY_COUNT, 2448724.5).is_none());
+    }
+
+    #[test]
+    fn test_pluto_of_date() {
+        // Meeus Example 37.a: Pluto 1992 Oct 13.0 TD, astrometric J2000
+        // α = 15h31m43.8s, δ = -4°27′29″ → λ = 231.695° J2000, 231.595° of date
+        let pluto = apparent_longitude(9, 2448908.5).map(f64::to_degrees);
+        assert!(
+            matches!(pluto, Some(l) if (l - 231.595).abs() < 0.02),
+            "{:?}",
+            pluto
+        );
+    }
+
+    #[test]
+    fn test_sidereal_offset_and_signs() {
+        // Lahiri ayanamsa on 2025-01-01 ≈ 24°12′
+        let ayanamsa = lahiri_ayanamsa(2460676.5).to_degrees();
+        assert!((ayanamsa - 24.2).abs() < 0.02, "{}", ayanamsa);
+
+        assert_eq!(sign_of(0.0), 0);
+        assert_eq!(sign_of(29.999_f64.to_radians()), 0);
+        assert_eq!(sign_of(30.0_f64.to_radians()), 1);
+        assert_eq!(sign_of(359.9_f64.to_radians()), 11);
+        assert_eq!(sign_of(-0.1_f64.to_radians()), 11);
+        assert_eq!(Zodiac::from_code(1), Some(Zodiac::Sidereal));
+        assert_eq!(Zodiac::from_code(2), None);
+    }
+
+    #[test]
+    fn test_aspect_between() {
+        let orbs = Aspect::MAJOR.map(Aspect::default_orb);
+        let deg = f64::to_radians;
+        assert!(aspect_between(deg(10.0), deg(355.0), &orbs).is_none());
+        assert!(
+            matches!(aspect_between(deg(10.0), deg(5.0), &orbs), Some((Aspect::Conjunction, d)) if (d - deg(5.0)).abs() < 1.0e-12)
+        );
+        assert!(matches!(
+            aspect_between(deg(350.0), deg(53.0), &orbs),
+            Some((Aspect::Sextile, _))
+        ));
+        assert!(matches!(
+            aspect_between(deg(100.0), deg(215.0), &orbs),
+            Some((Aspect::Trine, _))
+        ));
+        assert!(matches!(
+            aspect_between(deg(0.0), deg(185.0), &orbs),
+            Some((Aspect::Opposition, _))
+        ));
+        assert!(aspect_between(deg(0.0), deg(75.0), &orbs).is_none());
+    }
+
+    #[test]
+    fn test_sun_ingress_into_aries() {
+        // March equinox 2025: 2025-03-20 09:01 UTC (≈ 09:02 TT)
+        let ingresses = sign_ingresses(SUN, Zodiac::Tropical, 2460750.5, 2460760.5, 1.0);
+        assert!(
+            matches!(ingresses.as_deref(), Some([i]) if i.from == 11 && i.to == 0 && !i.retrograde)
+        );
+        if let Some([ingress]) = ingresses.as_deref() {
+            let hours = (ingress.jd_tt - 2460754.5) * 24.0;
+            assert!((hours - 9.03).abs() < 0.05, "{}", hours);
+        }
+    }
+}
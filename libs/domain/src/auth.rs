@@ -2,81 +2,11 @@
 //!
 //! JWT-based authentication with Telegram integration and subscription verification
 
+use crate::telegram::TelegramUserId;
+use crate::user::UserId;
 use serde::{Deserialize, Serialize};
-use std::fmt;
 use uuid::Uuid;
 
-/// User identifier type
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct UserId(Uuid);
-
-impl UserId {
-    /// Generate a new random user ID
-    pub fn new() -> Self {
-        Self(Uuid::new_v4())
-    }
-
-    /// Create from UUID
-    pub fn from_uuid(id: Uuid) -> Self {
-        Self(id)
-    }
-
-    /// Parse a user ID from a string
-    ///
-    /// # Errors
-    /// Returns `DomainError::InvalidUserId` if the string is not a valid UUID
-    pub fn parse(s: &str) -> crate::DomainResult<Self> {
-        Uuid::parse_str(s)
-            .map(Self)
-            .map_err(|_parse_error| crate::DomainError::InvalidUserId(s.to_string()))
-    }
-
-    /// Get the underlying UUID
-    pub fn as_uuid(&self) -> Uuid {
-        self.0
-    }
-}
-
-impl fmt::Display for UserId {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
-
-impl Default for UserId {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-/// Telegram user identifier
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct TelegramUserId(i64);
-
-impl TelegramUserId {
-    /// Create a new TelegramUserId
-    ///
-    /// # Errors
-    /// Returns `DomainError::InvalidTelegramUserId` if the ID is invalid (e.g., negative)
-    pub fn new(id: i64) -> crate::DomainResult<Self> {
-        if id <= 0 {
-            return Err(crate::DomainError::InvalidTelegramUserId(id));
-        }
-        Ok(Self(id))
-    }
-
-    /// Get the underlying i64 value
-    pub fn as_i64(self) -> i64 {
-        self.0
-    }
-}
-
-impl fmt::Display for TelegramUserId {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
-
 /// JWT token pair for authentication
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JwtTokenPair {
@@ -136,7 +66,7 @@ impl JwtClaims {
 
         Self {
             sub: user_id.to_string(),
-            user_id: user_id.clone(),
+            user_id: *user_id,
             iat: now,
             exp,
             telegram_user_id: None,
@@ -159,10 +89,10 @@ impl JwtClaims {
 
         Self {
             sub: user_id.to_string(),
-            user_id: user_id.clone(),
+            user_id: *user_id,
             iat: now,
             exp,
-            telegram_user_id: telegram_user_id.map(|id| id.as_i64()),
+            telegram_user_id: telegram_user_id.map(|id| id.value()),
             is_telegram_subscribed: is_subscribed,
             language,
             roles: roles.iter().cloned().collect(),
@@ -193,8 +123,10 @@ pub struct RefreshToken {
     pub id: Uuid,
     /// Associated user ID
     pub user_id: UserId,
-    /// Token hash (for security)
+    /// SHA-256 of the token; the token itself is never stored
     pub token_hash: String,
+    /// Rotation chain the token belongs to, started at sign-in
+    pub family_id: Uuid,
     /// Creation timestamp
     pub created_at: time::OffsetDateTime,
     /// Expiration timestamp
@@ -204,21 +136,29 @@ pub struct RefreshToken {
 }
 
 impl RefreshToken {
-    /// Create new refresh token
-    pub fn new(user_id: UserId, token_hash: String) -> Self {
+    /// Create new refresh token, starting a new token family
+    pub fn new(user_id: UserId, token_hash: String, lifetime: time::Duration) -> Self {
         let now = time::OffsetDateTime::now_utc();
-        let expires_at = now + time::Duration::days(30);
 
         Self {
             id: Uuid::new_v4(),
             user_id,
             token_hash,
+            family_id: Uuid::new_v4(),
             created_at: now,
-            expires_at,
+            expires_at: now + lifetime,
             is_revoked: false,
         }
     }
 
+    /// Successor of this token in its family after a rotation
+    pub fn rotate(&self, token_hash: String, lifetime: time::Duration) -> Self {
+        Self {
+            family_id: self.family_id,
+            ..Self::new(self.user_id, token_hash, lifetime)
+        }
+    }
+
     /// Check if refresh token is valid
     pub fn is_valid(&self) -> bool {
         !self.is_revoked && self.expires_at > time::OffsetDateTime::now_utc()
@@ -269,6 +209,15 @@ impl LinkingToken {
     pub fn mark_used(&mut self) {
         self.is_used = true;
     }
+
+    /// Telegram deep link that starts the bot with this token
+    pub fn deep_link(&self, bot_username: &str) -> String {
+        format!(
+            "https://t.me/{}?start={}",
+            bot_username.trim_start_matches('@'),
+            self.token
+        )
+    }
 }
 
 /// User authentication status
@@ -344,14 +293,32 @@ mod tests {
     #[test]
     fn test_refresh_token_validity() {
         let user_id = UserId::new();
-        let mut token = RefreshToken::new(user_id, "hash".to_string());
+        let lifetime = time::Duration::days(7);
+        let mut token = RefreshToken::new(user_id, "hash".to_string(), lifetime);
 
         assert!(token.is_valid());
+        assert_eq!(token.expires_at - token.created_at, lifetime);
 
         token.revoke();
         assert!(!token.is_valid());
     }
 
+    #[test]
+    fn test_refresh_token_rotation_keeps_family() {
+        let lifetime = time::Duration::days(7);
+        let token = RefreshToken::new(UserId::new(), "hash".to_string(), lifetime);
+        let next = token.rotate("next".to_string(), lifetime);
+
+        assert_eq!(next.family_id, token.family_id);
+        assert_eq!(next.user_id, token.user_id);
+        assert_ne!(next.id, token.id);
+        assert!(next.is_valid());
+        assert_ne!(
+            RefreshToken::new(UserId::new(), "other".to_string(), lifetime).family_id,
+            token.family_id
+        );
+    }
+
     #[test]
     fn test_linking_token_expiration() {
         let user_id = UserId::new();
@@ -367,7 +334,7 @@ mod tests {
     fn test_auth_status() {
         let user_id = UserId::new();
         let status = AuthStatus::Authenticated {
-            user_id: user_id.clone(),
+            user_id,
             telegram_linked: true,
             subscription_active: true,
         };
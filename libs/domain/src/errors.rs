@@ -31,6 +31,14 @@ pub enum DomainError {
     #[error("Authentication verification not completed")]
     AuthVerificationNotCompleted,
 
+    /// Login Widget or WebApp payload failed signature or field checks
+    #[error("Invalid Telegram authentication data: {0}")]
+    InvalidTelegramAuth(String),
+
+    /// `auth_date` of the payload is older than the allowed age
+    #[error("Telegram authentication data expired")]
+    TelegramAuthExpired,
+
     #[error("Invalid JWT token format")]
     InvalidJwtToken,
 
@@ -40,6 +48,10 @@ pub enum DomainError {
     #[error("Invalid refresh token")]
     InvalidRefreshToken,
 
+    /// Deep link token is unknown, expired or already used
+    #[error("Invalid or expired linking token")]
+    InvalidLinkingToken,
+
     #[error("User not authorized for this operation")]
     Unauthorized,
 
@@ -62,6 +74,10 @@ pub enum DomainError {
     #[error("Email already exists: {0}")]
     EmailExists(String),
 
+    /// The Telegram account belongs to a different user
+    #[error("Telegram account {0} is already linked to another user")]
+    TelegramAlreadyLinked(i64),
+
     // Spiritual/astrological errors
     #[error("Invalid birth data: {0}")]
     InvalidBirthData(String),
@@ -124,9 +140,12 @@ impl DomainError {
     pub fn is_auth_error(&self) -> bool {
         matches!(
             self,
-            Self::InvalidJwtToken
+            Self::InvalidTelegramAuth(_)
+                | Self::TelegramAuthExpired
+                | Self::InvalidJwtToken
                 | Self::JwtTokenExpired(_)
                 | Self::InvalidRefreshToken
+                | Self::InvalidLinkingToken
                 | Self::Unauthorized
                 | Self::SubscriptionRequired
         )
@@ -146,9 +165,12 @@ impl DomainError {
             | Self::InvalidTelegramChannelId(_)
             | Self::AuthSessionExpired
             | Self::AuthVerificationNotCompleted
+            | Self::InvalidTelegramAuth(_)
+            | Self::TelegramAuthExpired
             | Self::InvalidJwtToken
             | Self::JwtTokenExpired(_)
             | Self::InvalidRefreshToken
+            | Self::InvalidLinkingToken
             | Self::Unauthorized
             | Self::SubscriptionRequired => "auth",
 
@@ -156,7 +178,8 @@ impl DomainError {
             | Self::InvalidEmail(_)
             | Self::UserNotFound(_)
             | Self::UsernameExists(_)
-            | Self::EmailExists(_) => "user",
+            | Self::EmailExists(_)
+            | Self::TelegramAlreadyLinked(_) => "user",
 
             Self::InvalidBirthData(_)
             | Self::UnsupportedAstrologicalSystem(_)
@@ -73,6 +73,44 @@ pub enum UserEvent {
         telegram_user_id: TelegramId,
         feature_name: String,
     },
+
+    /// A rotated refresh token was presented again; its family was revoked
+    RefreshTokenReuseDetected {
+        /// Event identifier
+        event_id: EventId,
+        /// When the reuse was detected
+        occurred_at: OffsetDateTime,
+        /// Owner of the token family
+        user_id: crate::UserId,
+        /// Rotation family that was revoked
+        family_id: Uuid,
+        /// Tokens of the family that were still active
+        revoked_tokens: u64,
+    },
+
+    /// User changed profile fields through a merge patch
+    ProfileUpdated {
+        /// Event identifier
+        event_id: EventId,
+        /// When the patch was applied
+        occurred_at: OffsetDateTime,
+        /// Updated user
+        user_id: crate::UserId,
+        /// Names of the patched fields
+        fields: Vec<String>,
+    },
+
+    /// User deleted the account; tokens, sessions and deliveries went with it
+    AccountDeleted {
+        /// Event identifier
+        event_id: EventId,
+        /// When the account was deleted
+        occurred_at: OffsetDateTime,
+        /// Deleted user
+        user_id: crate::UserId,
+        /// Telegram account that was linked, if any
+        telegram_user_id: Option<TelegramId>,
+    },
 }
 
 impl DomainEvent for UserEvent {
@@ -83,6 +121,9 @@ impl DomainEvent for UserEvent {
             Self::SessionEstablished { event_id, .. } => *event_id,
             Self::SubscriptionStatusChanged { event_id, .. } => *event_id,
             Self::PremiumFeatureAccessed { event_id, .. } => *event_id,
+            Self::RefreshTokenReuseDetected { event_id, .. } => *event_id,
+            Self::ProfileUpdated { event_id, .. } => *event_id,
+            Self::AccountDeleted { event_id, .. } => *event_id,
         }
     }
 
@@ -93,6 +134,9 @@ impl DomainEvent for UserEvent {
             Self::SessionEstablished { occurred_at, .. } => *occurred_at,
             Self::SubscriptionStatusChanged { occurred_at, .. } => *occurred_at,
             Self::PremiumFeatureAccessed { occurred_at, .. } => *occurred_at,
+            Self::RefreshTokenReuseDetected { occurred_at, .. } => *occurred_at,
+            Self::ProfileUpdated { occurred_at, .. } => *occurred_at,
+            Self::AccountDeleted { occurred_at, .. } => *occurred_at,
         }
     }
 
@@ -103,6 +147,9 @@ impl DomainEvent for UserEvent {
             Self::SessionEstablished { .. } => "UserSessionEstablished",
             Self::SubscriptionStatusChanged { .. } => "UserSubscriptionStatusChanged",
             Self::PremiumFeatureAccessed { .. } => "UserPremiumFeatureAccessed",
+            Self::RefreshTokenReuseDetected { .. } => "UserRefreshTokenReuseDetected",
+            Self::ProfileUpdated { .. } => "UserProfileUpdated",
+            Self::AccountDeleted { .. } => "UserAccountDeleted",
         }
     }
 }
@@ -14,6 +14,7 @@
 pub mod auth;
 pub mod errors;
 pub mod events;
+pub mod notifications;
 pub mod ports;
 pub mod spiritual;
 pub mod telegram;
@@ -21,13 +22,16 @@ pub mod user;
 
 // ❌ REMOVED: astronomical types - WASM-only calculations per tz.md
 // Re-export core domain types (specific imports to avoid ambiguity)
-pub use auth::{JwtClaims, LinkingToken, RefreshToken};
+pub use auth::{JwtClaims, JwtTokenPair, LinkingToken, RefreshToken};
 pub use errors::*;
 pub use events::{AstronomicalEvent, DomainEvent, Event, EventId, EventPublisher, UserEvent};
 pub use ports::*;
 pub use spiritual::SpiritualProfile;
-pub use telegram::{SubscriptionStatus, TelegramChannelId, TelegramProfile};
-pub use user::{User, UserId};
+pub use telegram::{
+    SubscriptionStatus, TelegramAuthPayload, TelegramChannelId, TelegramLogin, TelegramProfile,
+    TelegramUserId,
+};
+pub use user::{ProfilePatch, User, UserId};
 
 // Explicit re-exports to avoid ambiguity
 pub use events::SpiritualEvent as EventSpiritualEvent;
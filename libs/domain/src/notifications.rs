@@ -0,0 +1,289 @@
+//! Scheduled event notifications
+//!
+//! Users opt in to categories of precomputed `spiritual_events`; every
+//! (event, user) pair becomes one `NotificationDelivery`, identified by an
+//! idempotency key so that replanning after a restart never sends twice.
+//! Deliveries go out at a fixed local hour on the event's local day.
+
+use crate::user::UserId;
+use crate::spiritual::SpiritualEvent;
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime, UtcOffset};
+use uuid::Uuid;
+
+/// Notification opt-ins, stored under `notification_preferences` in the
+/// user's spiritual preferences
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationPreferences {
+    /// New moons
+    pub new_moon_alerts: bool,
+    /// Full moons
+    pub full_moon_alerts: bool,
+    /// Quarter moons and any other calendar event
+    pub spiritual_events: bool,
+    /// Equinoxes and solstices
+    pub cosmic_alignments: bool,
+}
+
+impl NotificationPreferences {
+    /// Whether any category is enabled
+    pub fn any(&self) -> bool {
+        self.new_moon_alerts
+            || self.full_moon_alerts
+            || self.spiritual_events
+            || self.cosmic_alignments
+    }
+
+    /// Whether events of `event_type` should be notified
+    pub fn wants(&self, event_type: &str) -> bool {
+        match event_type {
+            SpiritualEvent::NEW_MOON => self.new_moon_alerts,
+            SpiritualEvent::FULL_MOON => self.full_moon_alerts,
+            t if SpiritualEvent::SEASONS.contains(&t) => self.cosmic_alignments,
+            _ => self.spiritual_events,
+        }
+    }
+}
+
+/// Linked Telegram user with at least one notification category enabled
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationRecipient {
+    /// Website account
+    pub user_id: UserId,
+    /// Linked Telegram account, also the private chat id
+    pub telegram_user_id: i64,
+    /// Language code of the user profile
+    pub language: String,
+    /// IANA time zone name of the user profile
+    pub timezone: String,
+    /// Enabled categories
+    pub preferences: NotificationPreferences,
+}
+
+/// Delivery state of a notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeliveryStatus {
+    /// Waiting for `deliver_at`, or for a retry
+    Pending,
+    /// Claimed by a dispatcher; never claimed again
+    Sending,
+    /// Accepted by Telegram
+    Sent,
+    /// Gave up after `NotificationDelivery::MAX_ATTEMPTS`
+    Failed,
+}
+
+impl DeliveryStatus {
+    /// Stored `status` value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Sending => "sending",
+            Self::Sent => "sent",
+            Self::Failed => "failed",
+        }
+    }
+
+    /// Parse a stored `status` value
+    pub fn parse(s: &str) -> Option<Self> {
+        [Self::Pending, Self::Sending, Self::Sent, Self::Failed]
+            .into_iter()
+            .find(|status| status.as_str() == s)
+    }
+}
+
+/// One rendered notification of one event to one user
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationDelivery {
+    /// `<event id>:<user id>`, unique per event and user
+    pub idempotency_key: String,
+    /// Recipient account
+    pub user_id: UserId,
+    /// Notified `spiritual_events` row
+    pub event_id: Uuid,
+    /// Telegram chat the message goes to
+    pub chat_id: i64,
+    /// Rendered message in the recipient's language
+    pub message: String,
+    /// When the message is due; moved forward on retries
+    pub deliver_at: OffsetDateTime,
+    /// Delivery state
+    pub status: DeliveryStatus,
+    /// Failed send attempts so far
+    pub attempts: u32,
+}
+
+impl NotificationDelivery {
+    /// Send attempts before a delivery is marked failed
+    pub const MAX_ATTEMPTS: u32 = 5;
+
+    /// First retry delay, doubled on every further attempt
+    const RETRY_BASE: Duration = Duration::seconds(30);
+
+    /// Upper bound of the retry delay
+    const RETRY_MAX: Duration = Duration::hours(1);
+
+    /// Pending delivery of `event` to `recipient`
+    pub fn new(
+        recipient: &NotificationRecipient,
+        event: &SpiritualEvent,
+        message: String,
+        deliver_at: OffsetDateTime,
+    ) -> Self {
+        Self {
+            idempotency_key: Self::idempotency_key(&event.id, &recipient.user_id),
+            user_id: recipient.user_id,
+            event_id: event.id,
+            chat_id: recipient.telegram_user_id,
+            message,
+            deliver_at,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+        }
+    }
+
+    /// Idempotency key of the delivery of an event to a user
+    pub fn idempotency_key(event_id: &Uuid, user_id: &UserId) -> String {
+        format!("{}:{}", event_id, user_id)
+    }
+
+    /// Backoff before the next attempt after `attempts` failures
+    pub fn retry_delay(attempts: u32) -> Duration {
+        let factor = 2i32.saturating_pow(attempts.saturating_sub(1).min(16));
+        Self::RETRY_BASE.saturating_mul(factor).min(Self::RETRY_MAX)
+    }
+}
+
+/// `at` in the user's time zone; unknown zone names fall back to UTC
+pub fn local_time(at: OffsetDateTime, timezone: &str) -> OffsetDateTime {
+    let offset = jiff::tz::TimeZone::get(timezone)
+        .ok()
+        .and_then(|tz| {
+            let timestamp = jiff::Timestamp::from_second(at.unix_timestamp()).ok()?;
+            UtcOffset::from_whole_seconds(tz.to_offset(timestamp).seconds()).ok()
+        })
+        .unwrap_or(UtcOffset::UTC);
+    at.to_offset(offset)
+}
+
+/// `hour` o'clock local time on the local calendar day of `occurs_at`
+///
+/// Follows the zone's rules on that day, so DST changes are respected; a
+/// local hour skipped by a DST gap moves forward. Unknown zone names fall
+/// back to UTC.
+pub fn delivery_time(occurs_at: OffsetDateTime, timezone: &str, hour: u8) -> OffsetDateTime {
+    let local = local_time(occurs_at, timezone);
+    let fallback = local
+        .replace_time(time::Time::MIDNIGHT)
+        .to_offset(UtcOffset::UTC)
+        + Duration::hours(i64::from(hour.min(23)));
+
+    let tz = jiff::tz::TimeZone::get(timezone).unwrap_or(jiff::tz::TimeZone::UTC);
+    let date = local.date();
+    let (Ok(year), Ok(month), Ok(day), Ok(hour)) = (
+        i16::try_from(date.year()),
+        i8::try_from(u8::from(date.month())),
+        i8::try_from(date.day()),
+        i8::try_from(hour.min(23)),
+    ) else {
+        return fallback;
+    };
+
+    jiff::civil::DateTime::new(year, month, day, hour, 0, 0, 0)
+        .and_then(|civil| civil.to_zoned(tz))
+        .ok()
+        .and_then(|zoned| OffsetDateTime::from_unix_timestamp(zoned.timestamp().as_second()).ok())
+        .unwrap_or(fallback)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn test_preferences_categories() {
+        let preferences = NotificationPreferences {
+            full_moon_alerts: true,
+            cosmic_alignments: true,
+            ..Default::default()
+        };
+        assert!(preferences.any());
+        assert!(preferences.wants(SpiritualEvent::FULL_MOON));
+        assert!(preferences.wants(SpiritualEvent::JUNE_SOLSTICE));
+        assert!(!preferences.wants(SpiritualEvent::NEW_MOON));
+        assert!(!preferences.wants(SpiritualEvent::FIRST_QUARTER));
+        assert!(!NotificationPreferences::default().any());
+
+        // Partial JSON from older profiles
+        let parsed: Result<NotificationPreferences, _> =
+            serde_json::from_str(r#"{"new_moon_alerts":true}"#);
+        assert!(matches!(parsed, Ok(p) if p.new_moon_alerts && !p.full_moon_alerts));
+    }
+
+    #[test]
+    fn test_delivery_time_in_local_day() {
+        // 02:30 UTC is still the previous evening in New York (UTC-4 in summer)
+        let occurs_at = datetime!(2025-07-10 02:30 UTC);
+        assert_eq!(
+            delivery_time(occurs_at, "America/New_York", 9),
+            datetime!(2025-07-09 13:00 UTC)
+        );
+        // Moscow, UTC+3 all year
+        assert_eq!(
+            delivery_time(occurs_at, "Europe/Moscow", 9),
+            datetime!(2025-07-10 06:00 UTC)
+        );
+        // Winter time in New York
+        assert_eq!(
+            delivery_time(datetime!(2025-01-10 15:00 UTC), "America/New_York", 9),
+            datetime!(2025-01-10 14:00 UTC)
+        );
+        // Unknown zones fall back to UTC
+        assert_eq!(
+            delivery_time(occurs_at, "Mars/Olympus_Mons", 9),
+            datetime!(2025-07-10 09:00 UTC)
+        );
+        assert_eq!(
+            local_time(occurs_at, "Asia/Yerevan").offset(),
+            UtcOffset::from_whole_seconds(4 * 3600).unwrap_or(UtcOffset::UTC)
+        );
+    }
+
+    #[test]
+    fn test_delivery_keys_and_backoff() {
+        let recipient = NotificationRecipient {
+            user_id: UserId::new(),
+            telegram_user_id: 42,
+            language: "en".to_string(),
+            timezone: "UTC".to_string(),
+            preferences: NotificationPreferences::default(),
+        };
+        let event = SpiritualEvent::new(
+            SpiritualEvent::NEW_MOON.to_string(),
+            "New Moon".to_string(),
+            datetime!(2025-01-29 12:36 UTC),
+        );
+        let delivery = NotificationDelivery::new(
+            &recipient,
+            &event,
+            "🌑".to_string(),
+            datetime!(2025-01-29 09:00 UTC),
+        );
+        let again = NotificationDelivery::new(
+            &recipient,
+            &event,
+            "🌑".to_string(),
+            datetime!(2025-01-29 09:00 UTC),
+        );
+        assert_eq!(delivery.idempotency_key, again.idempotency_key);
+        assert_eq!(delivery.chat_id, 42);
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        assert_eq!(DeliveryStatus::parse("sent"), Some(DeliveryStatus::Sent));
+
+        assert_eq!(NotificationDelivery::retry_delay(1), Duration::seconds(30));
+        assert_eq!(NotificationDelivery::retry_delay(3), Duration::minutes(2));
+        assert_eq!(NotificationDelivery::retry_delay(30), Duration::hours(1));
+    }
+}
@@ -3,6 +3,9 @@
 //! Defines contracts that infrastructure adapters must implement.
 //! These belong to the domain layer in Clean Architecture.
 
+use crate::notifications::{NotificationDelivery, NotificationRecipient};
+use crate::spiritual::SpiritualEvent;
+use crate::telegram::{TelegramAuthPayload, TelegramLogin};
 use crate::{
     DomainError, EventSpiritualEvent, JwtClaims, LinkingToken, RefreshToken, TelegramId, User,
     UserId,
@@ -18,6 +21,8 @@ pub trait UserRepository: Send + Sync {
     async fn create_user(&self, user: User) -> PortResult<()>;
     async fn get_user_by_id(&self, id: &UserId) -> PortResult<Option<User>>;
     async fn get_user_by_username(&self, username: &str) -> PortResult<Option<User>>;
+    /// User whose account is linked to this Telegram account
+    async fn get_user_by_telegram_id(&self, telegram_user_id: i64) -> PortResult<Option<User>>;
     async fn update_user(&self, user: &User) -> PortResult<()>;
     async fn delete_user(&self, id: &UserId) -> PortResult<()>;
 }
@@ -27,10 +32,14 @@ pub trait UserRepository: Send + Sync {
 pub trait TokenRepository: Send + Sync {
     async fn store_refresh_token(&self, token: &RefreshToken) -> PortResult<()>;
     async fn get_refresh_token(&self, token_hash: &str) -> PortResult<Option<RefreshToken>>;
-    async fn revoke_refresh_token(&self, token_hash: &str) -> PortResult<()>;
+    /// Revoke one token; `false` when it was already revoked
+    async fn revoke_refresh_token(&self, token_hash: &str) -> PortResult<bool>;
+    /// Revoke every token of a rotation family; returns how many were active
+    async fn revoke_refresh_token_family(&self, family_id: &uuid::Uuid) -> PortResult<u64>;
     async fn store_linking_token(&self, token: &LinkingToken) -> PortResult<()>;
     async fn get_linking_token(&self, token: &uuid::Uuid) -> PortResult<Option<LinkingToken>>;
-    async fn mark_linking_token_used(&self, token: &uuid::Uuid) -> PortResult<()>;
+    /// Atomically mark a valid token used; `None` when it is unknown, expired or already used
+    async fn consume_linking_token(&self, token: &uuid::Uuid) -> PortResult<Option<LinkingToken>>;
 }
 
 /// External service port for Telegram API
@@ -42,6 +51,65 @@ pub trait TelegramService: Send + Sync {
     async fn health_check(&self) -> PortResult<()>;
 }
 
+/// Verification port for Telegram Login Widget and WebApp authentication data
+pub trait TelegramAuthVerifier: Send + Sync {
+    /// Check the signature and `auth_date` freshness of `payload`
+    fn verify(&self, payload: &TelegramAuthPayload) -> PortResult<TelegramLogin>;
+}
+
+/// Read access to precomputed spiritual events (Moon phases, ingresses, ...)
+#[async_trait]
+pub trait SpiritualEventRepository: Send + Sync {
+    /// Events with `from <= occurs_at < to`, earliest first
+    async fn events_between(
+        &self,
+        from: time::OffsetDateTime,
+        to: time::OffsetDateTime,
+    ) -> PortResult<Vec<SpiritualEvent>>;
+    /// First event after `after` of one of `event_types` (any type when empty)
+    async fn next_event(
+        &self,
+        after: time::OffsetDateTime,
+        event_types: &[&str],
+    ) -> PortResult<Option<SpiritualEvent>>;
+    /// Last event at or before `at` of one of `event_types` (any type when empty)
+    async fn previous_event(
+        &self,
+        at: time::OffsetDateTime,
+        event_types: &[&str],
+    ) -> PortResult<Option<SpiritualEvent>>;
+    /// Insert events, skipping any with the same `event_type` and
+    /// `occurs_at` as a stored one; returns the number inserted
+    async fn store_events(&self, events: &[SpiritualEvent]) -> PortResult<u64>;
+}
+
+/// Repository port for scheduled event notifications
+#[async_trait]
+pub trait NotificationRepository: Send + Sync {
+    /// Active users with a linked Telegram account and any notification enabled
+    async fn recipients(&self) -> PortResult<Vec<NotificationRecipient>>;
+    /// Insert pending deliveries, skipping known idempotency keys; returns
+    /// the number inserted
+    async fn schedule_deliveries(&self, deliveries: &[NotificationDelivery]) -> PortResult<u64>;
+    /// Atomically move up to `limit` pending deliveries due at `now` to
+    /// `Sending` and return them, earliest first
+    async fn claim_due_deliveries(
+        &self,
+        now: time::OffsetDateTime,
+        limit: u32,
+    ) -> PortResult<Vec<NotificationDelivery>>;
+    /// Record a successful send
+    async fn mark_delivery_sent(&self, idempotency_key: &str) -> PortResult<()>;
+    /// Record a failed send: back to `Pending` at `retry_at`, or `Failed` when `None`
+    async fn record_delivery_failure(
+        &self,
+        idempotency_key: &str,
+        attempts: u32,
+        retry_at: Option<time::OffsetDateTime>,
+        error: &str,
+    ) -> PortResult<()>;
+}
+
 /// Cache port for performance optimization
 #[async_trait]
 pub trait CacheService: Send + Sync {
@@ -96,6 +164,8 @@ pub trait JwtService: Send + Sync {
     async fn create_access_token(&self, claims: &JwtClaims) -> PortResult<String>;
     async fn validate_access_token(&self, token: &str) -> PortResult<JwtClaims>;
     async fn create_refresh_token(&self) -> PortResult<String>;
+    /// Digest under which a refresh token is persisted and looked up
+    fn hash_refresh_token(&self, token: &str) -> String;
 }
 
 // ❌ ARCHITECTURAL VIOLATION REMOVED:
@@ -16,12 +16,37 @@ pub enum AstrologicalSystem {
 /// Birth chart information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BirthChart {
-    pub birth_time: f64, // Julian Day as f64 - calculated in WASM frontend only
+    /// Julian Day (UTC), calculated in the WASM frontend only
+    pub birth_time: f64,
+    /// Degrees, north positive
     pub latitude: f64,
+    /// Degrees, east positive
     pub longitude: f64,
     pub system: AstrologicalSystem,
 }
 
+impl BirthChart {
+    /// Check that the birth moment and place are usable for chart casting
+    pub fn validate(&self) -> crate::DomainResult<()> {
+        if !self.birth_time.is_finite() || self.birth_time <= 0.0 {
+            return Err(crate::DomainError::InvalidBirthData(
+                "birth time must be a positive Julian Day".to_string(),
+            ));
+        }
+        if !(-90.0..=90.0).contains(&self.latitude) {
+            return Err(crate::DomainError::InvalidBirthData(
+                "latitude must be within ±90°".to_string(),
+            ));
+        }
+        if !(-180.0..=180.0).contains(&self.longitude) {
+            return Err(crate::DomainError::InvalidBirthData(
+                "longitude must be within ±180°".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 /// Spiritual profile for personalized recommendations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpiritualProfile {
@@ -50,6 +75,40 @@ pub struct SpiritualEvent {
 }
 
 impl SpiritualEvent {
+    /// `event_type` of a new moon
+    pub const NEW_MOON: &'static str = "new_moon";
+    /// `event_type` of a first quarter
+    pub const FIRST_QUARTER: &'static str = "first_quarter";
+    /// `event_type` of a full moon
+    pub const FULL_MOON: &'static str = "full_moon";
+    /// `event_type` of a last quarter
+    pub const LAST_QUARTER: &'static str = "last_quarter";
+
+    /// Principal Moon phase event types, in quarter order
+    pub const MOON_PHASES: [&'static str; 4] = [
+        Self::NEW_MOON,
+        Self::FIRST_QUARTER,
+        Self::FULL_MOON,
+        Self::LAST_QUARTER,
+    ];
+
+    /// `event_type` of the March equinox (solar longitude 0°)
+    pub const MARCH_EQUINOX: &'static str = "march_equinox";
+    /// `event_type` of the June solstice (90°)
+    pub const JUNE_SOLSTICE: &'static str = "june_solstice";
+    /// `event_type` of the September equinox (180°)
+    pub const SEPTEMBER_EQUINOX: &'static str = "september_equinox";
+    /// `event_type` of the December solstice (270°)
+    pub const DECEMBER_SOLSTICE: &'static str = "december_solstice";
+
+    /// Equinox and solstice event types, in solar longitude order
+    pub const SEASONS: [&'static str; 4] = [
+        Self::MARCH_EQUINOX,
+        Self::JUNE_SOLSTICE,
+        Self::SEPTEMBER_EQUINOX,
+        Self::DECEMBER_SOLSTICE,
+    ];
+
     /// Create new spiritual event
     pub fn new(event_type: String, title: String, occurs_at: time::OffsetDateTime) -> Self {
         Self {
@@ -72,4 +131,134 @@ impl SpiritualEvent {
     pub fn is_significant(&self) -> bool {
         self.quantum_resonance.map_or(false, |r| r > 0.8)
     }
+
+    /// `MoonPhase` index (0, 2, 4 or 6) of a principal Moon phase event
+    pub fn moon_phase_index(&self) -> Option<u8> {
+        (0..4u8)
+            .zip(Self::MOON_PHASES)
+            .find(|(_, event_type)| *event_type == self.event_type)
+            .map(|(quarter, _)| 2 * quarter)
+    }
+
+    /// Season index (0 March equinox … 3 December solstice) of an equinox
+    /// or solstice event
+    pub fn season_index(&self) -> Option<u8> {
+        (0..4u8)
+            .zip(Self::SEASONS)
+            .find(|(_, event_type)| *event_type == self.event_type)
+            .map(|(season, _)| season)
+    }
+}
+
+/// Moon phase at an instant - calculated in WASM frontend only
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MoonPhase {
+    /// Lunar age: days since the last new moon
+    pub age_days: f64,
+    /// 8-way phase: 0 New Moon, 1 Waxing Crescent, 2 First Quarter,
+    /// 3 Waxing Gibbous, 4 Full Moon, 5 Waning Gibbous, 6 Last Quarter,
+    /// 7 Waning Crescent (order of `moon_phase_names` in the i18n layer)
+    pub phase_index: u8,
+    /// Illuminated fraction of the disk (0.0 - 1.0)
+    pub illuminated_fraction: f64,
+    /// Moon-Sun elongation below 180°
+    pub waxing: bool,
+    /// Julian Day (UTC) of the next principal phase
+    pub next_phase_jd: f64,
+    /// Phase index of that principal phase (0, 2, 4 or 6)
+    pub next_phase_index: u8,
+}
+
+impl MoonPhase {
+    /// Number of named phases
+    pub const PHASE_COUNT: u8 = 8;
+
+    /// Named phase at `progress` (0..1) of the way from the principal phase
+    /// `last_index` to the next one
+    ///
+    /// Named phases are 45° sectors centred on the principal phases, so the
+    /// first and last quarters of the interval keep the principal names.
+    pub fn index_between_principal(last_index: u8, progress: f64) -> u8 {
+        let offset = if progress < 0.25 {
+            0
+        } else if progress < 0.75 {
+            1
+        } else {
+            2
+        };
+        (last_index + offset) % Self::PHASE_COUNT
+    }
+
+    /// Illuminated fraction as a whole percentage
+    pub fn illumination_percent(&self) -> u8 {
+        let percent = (self.illuminated_fraction.clamp(0.0, 1.0) * 100.0).round();
+        (0..=100).find(|&p| f64::from(p) == percent).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_chart_validation() {
+        let chart = BirthChart {
+            birth_time: 2448058.0,
+            latitude: 48.85,
+            longitude: 2.35,
+            system: AstrologicalSystem::Western,
+        };
+        assert!(chart.validate().is_ok());
+
+        let invalid = BirthChart {
+            latitude: 91.0,
+            ..chart.clone()
+        };
+        assert!(matches!(
+            invalid.validate(),
+            Err(crate::DomainError::InvalidBirthData(_))
+        ));
+        let invalid = BirthChart {
+            birth_time: f64::NAN,
+            ..chart
+        };
+        assert!(invalid.validate().is_err());
+    }
+    #[test]
+    fn test_moon_phase_illumination_percent() {
+        let phase = MoonPhase {
+            age_days: 11.2,
+            phase_index: 3,
+            illuminated_fraction: 0.8672,
+            waxing: true,
+            next_phase_jd: 2460700.0,
+            next_phase_index: 4,
+        };
+        assert_eq!(phase.illumination_percent(), 87);
+        let full = MoonPhase {
+            illuminated_fraction: 1.2,
+            ..phase
+        };
+        assert_eq!(full.illumination_percent(), 100);
+    }
+
+    #[test]
+    fn test_moon_phase_between_principal_phases() {
+        assert_eq!(MoonPhase::index_between_principal(0, 0.1), 0);
+        assert_eq!(MoonPhase::index_between_principal(0, 0.5), 1);
+        assert_eq!(MoonPhase::index_between_principal(4, 0.9), 6);
+        assert_eq!(MoonPhase::index_between_principal(6, 0.8), 0);
+
+        let full_moon = SpiritualEvent::new(
+            SpiritualEvent::FULL_MOON.to_string(),
+            "Full Moon".to_string(),
+            time::OffsetDateTime::UNIX_EPOCH,
+        );
+        assert_eq!(full_moon.moon_phase_index(), Some(4));
+        let eclipse = SpiritualEvent {
+            event_type: "solar_eclipse".to_string(),
+            ..full_moon
+        };
+        assert_eq!(eclipse.moon_phase_index(), None);
+    }
 }
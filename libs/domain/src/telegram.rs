@@ -81,6 +81,18 @@ impl LinkingToken {
     pub fn to_string(self) -> String {
         self.0.to_string()
     }
+
+    /// Token carried by a bot deep link, received as `/start <token>`
+    ///
+    /// Accepts the `/start@BotName <token>` form used in group chats.
+    pub fn from_start_command(text: &str) -> Option<Self> {
+        let (command, payload) = text.trim().split_once(char::is_whitespace)?;
+        let command = command.split('@').next()?;
+        if command != "/start" {
+            return None;
+        }
+        Uuid::parse_str(payload.trim()).ok().map(Self)
+    }
 }
 
 /// Subscription status for premium spiritual features
@@ -169,6 +181,59 @@ impl TelegramProfile {
     }
 }
 
+/// Telegram authentication proof sent by the web client
+///
+/// Either the fields of a Login Widget callback or the raw `initData` query
+/// string of a Telegram WebApp. Nothing in it is trusted until its
+/// HMAC-SHA256 signature has been checked against the bot token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum TelegramAuthPayload {
+    /// Login Widget fields (`id`, `first_name`, ..., `auth_date`, `hash`)
+    LoginWidget(serde_json::Map<String, serde_json::Value>),
+    /// `Telegram.WebApp.initData` as received, URL-encoded
+    WebAppInitData(String),
+}
+
+/// Telegram identity taken from a verified authentication payload
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelegramLogin {
+    /// Telegram account ID
+    pub user_id: TelegramUserId,
+    /// First name from the Telegram profile
+    pub first_name: String,
+    /// Last name, if set
+    pub last_name: Option<String>,
+    /// Telegram @username, if set
+    pub username: Option<String>,
+    /// IETF language tag of the Telegram client (WebApp only)
+    pub language_code: Option<String>,
+    /// When Telegram signed the payload
+    pub auth_date: time::OffsetDateTime,
+}
+
+impl TelegramLogin {
+    /// Tolerated clock skew for `auth_date` in the future
+    pub const MAX_CLOCK_SKEW: time::Duration = time::Duration::seconds(60);
+
+    /// Reject payloads signed more than `max_age` ago (replay protection)
+    pub fn ensure_fresh(
+        &self,
+        now: time::OffsetDateTime,
+        max_age: time::Duration,
+    ) -> Result<(), DomainError> {
+        if self.auth_date > now + Self::MAX_CLOCK_SKEW {
+            return Err(DomainError::InvalidTelegramAuth(
+                "auth_date is in the future".to_string(),
+            ));
+        }
+        if now - self.auth_date > max_age {
+            return Err(DomainError::TelegramAuthExpired);
+        }
+        Ok(())
+    }
+}
+
 /// Telegram authentication session with linking state
 ///
 /// Tracks the complete authentication flow from web deep linking
@@ -261,6 +326,46 @@ mod tests {
         assert!(!SubscriptionStatus::VerificationFailed.has_premium_access());
     }
 
+    #[test]
+    fn telegram_login_freshness() {
+        let now = time::OffsetDateTime::now_utc();
+        let login = TelegramLogin {
+            user_id: TelegramUserId(123456789),
+            first_name: "Ada".to_string(),
+            last_name: None,
+            username: None,
+            language_code: Some("ru".to_string()),
+            auth_date: now - time::Duration::minutes(10),
+        };
+        let max_age = time::Duration::hours(1);
+        assert!(login.ensure_fresh(now, max_age).is_ok());
+        assert!(matches!(
+            login.ensure_fresh(now + time::Duration::hours(2), max_age),
+            Err(DomainError::TelegramAuthExpired)
+        ));
+        assert!(matches!(
+            login.ensure_fresh(now - time::Duration::hours(1), max_age),
+            Err(DomainError::InvalidTelegramAuth(_))
+        ));
+    }
+
+    #[test]
+    fn linking_token_from_start_command() {
+        let user_token = crate::auth::LinkingToken::new(crate::UserId::new());
+        let link = user_token.deep_link("@stars_bot");
+        assert!(link.starts_with("https://t.me/stars_bot?start="));
+
+        let payload = link.split("start=").nth(1).unwrap_or_default();
+        let parsed = LinkingToken::from_start_command(&format!("/start {}", payload));
+        assert_eq!(parsed.map(LinkingToken::uuid), Some(user_token.token));
+        let parsed = LinkingToken::from_start_command(&format!("/start@stars_bot {}", payload));
+        assert_eq!(parsed.map(LinkingToken::uuid), Some(user_token.token));
+
+        assert!(LinkingToken::from_start_command("/start").is_none());
+        assert!(LinkingToken::from_start_command("/start not-a-token").is_none());
+        assert!(LinkingToken::from_start_command(&format!("/link {}", payload)).is_none());
+    }
+
     #[test]
     fn auth_session_flow() {
         let token = LinkingToken::generate();
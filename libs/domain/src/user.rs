@@ -1,27 +1,59 @@
 //! User management domain types
 
+use crate::notifications::NotificationPreferences;
 use crate::telegram::TelegramUserId;
+use crate::{DomainError, DomainResult};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
 use uuid::Uuid;
 
+/// Profile language of new users
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// Profile time zone of new users
+pub const DEFAULT_TIMEZONE: &str = "UTC";
+
+/// Largest spiritual preferences document, in bytes of JSON
+const MAX_SPIRITUAL_PREFERENCES_LEN: usize = 16 * 1024;
+
 /// Unique identifier for users in the system
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct UserId(pub Uuid);
 
 impl UserId {
+    /// Generate a new random user ID
     pub fn new() -> Self {
         Self(Uuid::new_v4())
     }
 
+    /// Create from UUID
     pub fn from_uuid(uuid: Uuid) -> Self {
         Self(uuid)
     }
 
-    pub fn uuid(self) -> Uuid {
+    /// Parse a user ID from a string
+    ///
+    /// # Errors
+    /// Returns `DomainError::InvalidUserId` if the string is not a valid UUID
+    pub fn parse(s: &str) -> DomainResult<Self> {
+        Uuid::parse_str(s)
+            .map(Self)
+            .map_err(|_parse_error| DomainError::InvalidUserId(s.to_string()))
+    }
+
+    /// Get the underlying UUID
+    pub fn as_uuid(&self) -> Uuid {
         self.0
     }
 }
 
+impl fmt::Display for UserId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 impl Default for UserId {
     fn default() -> Self {
         Self::new()
@@ -38,6 +70,246 @@ pub struct User {
     pub created_at: time::OffsetDateTime,
     pub updated_at: time::OffsetDateTime,
     pub is_active: bool,
+    /// Language code: ISO 639-1, optionally with a region (`en`, `pt-BR`)
+    pub language: String,
+    /// IANA time zone name
+    pub timezone: String,
+    /// Free-form JSON object; `notification_preferences` holds the
+    /// notification categories
+    pub spiritual_preferences: Value,
+}
+
+impl User {
+    /// Apply a profile patch; the user is unchanged when validation fails
+    ///
+    /// # Errors
+    /// Returns `DomainError::ValidationFailed` naming the first invalid field
+    pub fn apply_profile_patch(
+        &mut self,
+        patch: &ProfilePatch,
+        now: time::OffsetDateTime,
+    ) -> DomainResult<()> {
+        let mut updated = self.clone();
+        if let Some(username) = &patch.username {
+            validate_username(username)?;
+            updated.username = username.clone();
+        }
+        if let Some(email) = &patch.email {
+            if let Some(email) = email {
+                validate_email(email)?;
+            }
+            updated.email = email.clone();
+        }
+        if let Some(language) = &patch.language {
+            let language = language.as_deref().unwrap_or(DEFAULT_LANGUAGE);
+            validate_language(language)?;
+            updated.language = language.to_string();
+        }
+        if let Some(timezone) = &patch.timezone {
+            let timezone = timezone.as_deref().unwrap_or(DEFAULT_TIMEZONE);
+            validate_timezone(timezone)?;
+            updated.timezone = timezone.to_string();
+        }
+        if let Some(preferences) = &patch.spiritual_preferences {
+            merge_patch(&mut updated.spiritual_preferences, preferences);
+            if !updated.spiritual_preferences.is_object() {
+                updated.spiritual_preferences = Value::Object(Map::new());
+            }
+            validate_spiritual_preferences(&updated.spiritual_preferences)?;
+        }
+        updated.updated_at = now;
+        *self = updated;
+        Ok(())
+    }
+}
+
+/// Partial profile update with JSON merge patch semantics (RFC 7396)
+///
+/// `None` leaves a field unchanged. `Some(None)` is a `null` member: it
+/// clears the email and resets the language and time zone to their defaults.
+/// Spiritual preferences are merged member by member, `null` removing a key.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProfilePatch {
+    /// New username; cannot be removed
+    pub username: Option<String>,
+    /// New email, or `Some(None)` to remove it
+    pub email: Option<Option<String>>,
+    /// New language, or `Some(None)` for `DEFAULT_LANGUAGE`
+    pub language: Option<Option<String>>,
+    /// New time zone, or `Some(None)` for `DEFAULT_TIMEZONE`
+    pub timezone: Option<Option<String>>,
+    /// Merge patch of the spiritual preferences
+    pub spiritual_preferences: Option<Value>,
+}
+
+impl ProfilePatch {
+    /// Members a profile patch may contain
+    pub const FIELDS: [&'static str; 5] = [
+        "username",
+        "email",
+        "language",
+        "timezone",
+        "spiritual_preferences",
+    ];
+
+    /// Read a merge patch document
+    ///
+    /// # Errors
+    /// Returns `DomainError::ValidationFailed` for a non-object document,
+    /// unknown or read-only members and members of the wrong type
+    pub fn from_json(document: &Value) -> DomainResult<Self> {
+        let Value::Object(members) = document else {
+            return Err(DomainError::validation(
+                "profile",
+                "merge patch must be a JSON object",
+            ));
+        };
+        let mut patch = Self::default();
+        for (name, value) in members {
+            match name.as_str() {
+                "username" => {
+                    patch.username = Some(
+                        optional_string(name, value)?
+                            .ok_or_else(|| DomainError::validation(name, "cannot be removed"))?,
+                    );
+                }
+                "email" => patch.email = Some(optional_string(name, value)?),
+                "language" => patch.language = Some(optional_string(name, value)?),
+                "timezone" => patch.timezone = Some(optional_string(name, value)?),
+                "spiritual_preferences" => patch.spiritual_preferences = Some(value.clone()),
+                _ => return Err(DomainError::validation(name, "field cannot be changed")),
+            }
+        }
+        Ok(patch)
+    }
+
+    /// Members present in the patch, in `FIELDS` order
+    pub fn fields(&self) -> Vec<&'static str> {
+        let present = [
+            self.username.is_some(),
+            self.email.is_some(),
+            self.language.is_some(),
+            self.timezone.is_some(),
+            self.spiritual_preferences.is_some(),
+        ];
+        Self::FIELDS
+            .into_iter()
+            .zip(present)
+            .filter_map(|(field, present)| present.then_some(field))
+            .collect()
+    }
+}
+
+/// Apply an RFC 7396 merge patch to `target`
+pub fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(members) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    if let Value::Object(target) = target {
+        for (name, value) in members {
+            if value.is_null() {
+                target.remove(name);
+            } else {
+                merge_patch(target.entry(name.clone()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+fn optional_string(field: &str, value: &Value) -> DomainResult<Option<String>> {
+    match value {
+        Value::Null => Ok(None),
+        Value::String(value) => Ok(Some(value.clone())),
+        _ => Err(DomainError::validation(field, "must be a string or null")),
+    }
+}
+
+/// 3-50 ASCII letters, digits or underscores (Telegram usernames fit)
+fn validate_username(username: &str) -> DomainResult<()> {
+    if !(3..=50).contains(&username.len()) {
+        return Err(DomainError::validation(
+            "username",
+            "must be 3 to 50 characters long",
+        ));
+    }
+    if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return Err(DomainError::validation(
+            "username",
+            "may contain only letters, digits and underscores",
+        ));
+    }
+    Ok(())
+}
+
+fn validate_email(email: &str) -> DomainResult<()> {
+    let valid = email.len() <= 255
+        && !email.contains(char::is_whitespace)
+        && email.split_once('@').is_some_and(|(local, domain)| {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain
+                    .split_once('.')
+                    .is_some_and(|(name, tld)| !name.is_empty() && !tld.is_empty())
+        });
+    if valid {
+        Ok(())
+    } else {
+        Err(DomainError::validation("email", "invalid email address"))
+    }
+}
+
+/// `xx` or `xx-YY`
+fn validate_language(language: &str) -> DomainResult<()> {
+    let (primary, region) = language.split_once('-').unwrap_or((language, ""));
+    let valid = primary.len() == 2
+        && primary.chars().all(|c| c.is_ascii_lowercase())
+        && (region.is_empty() || language.len() == 5)
+        && region.chars().all(|c| c.is_ascii_uppercase());
+    if valid {
+        Ok(())
+    } else {
+        Err(DomainError::validation(
+            "language",
+            "expected an ISO 639-1 code such as `en` or `pt-BR`",
+        ))
+    }
+}
+
+fn validate_timezone(timezone: &str) -> DomainResult<()> {
+    if timezone.len() <= 50 && jiff::tz::TimeZone::get(timezone).is_ok() {
+        Ok(())
+    } else {
+        Err(DomainError::validation(
+            "timezone",
+            "unknown IANA time zone",
+        ))
+    }
+}
+
+fn validate_spiritual_preferences(preferences: &Value) -> DomainResult<()> {
+    if preferences.to_string().len() > MAX_SPIRITUAL_PREFERENCES_LEN {
+        return Err(DomainError::validation(
+            "spiritual_preferences",
+            "document is too large",
+        ));
+    }
+    // Read by the notification scheduler
+    if let Some(notifications) = preferences.get("notification_preferences") {
+        serde_json::from_value::<NotificationPreferences>(notifications.clone()).map_err(|_| {
+            DomainError::validation(
+                "spiritual_preferences.notification_preferences",
+                "expected boolean notification categories",
+            )
+        })?;
+    }
+    Ok(())
 }
 
 /// User role in the system
@@ -57,3 +329,124 @@ impl UserRole {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn user() -> User {
+        User {
+            id: UserId::new(),
+            username: "stargazer".to_string(),
+            email: Some("star@example.com".to_string()),
+            telegram_user_id: None,
+            created_at: time::OffsetDateTime::UNIX_EPOCH,
+            updated_at: time::OffsetDateTime::UNIX_EPOCH,
+            is_active: true,
+            language: DEFAULT_LANGUAGE.to_string(),
+            timezone: DEFAULT_TIMEZONE.to_string(),
+            spiritual_preferences: json!({ "system": "western", "interests": ["moon"] }),
+        }
+    }
+
+    fn parse_patch(document: Value) -> ProfilePatch {
+        let patch = ProfilePatch::from_json(&document);
+        assert!(patch.is_ok(), "{:?}", patch);
+        patch.unwrap_or_default()
+    }
+
+    #[test]
+    fn test_profile_patch_merge_semantics() {
+        let mut user = user();
+        let now = time::OffsetDateTime::now_utc();
+        let patch = parse_patch(json!({
+            "email": null,
+            "language": "pt-BR",
+            "timezone": "Asia/Yerevan",
+            "spiritual_preferences": {
+                "interests": null,
+                "notification_preferences": { "full_moon_alerts": true }
+            }
+        }));
+        assert_eq!(
+            patch.fields(),
+            ["email", "language", "timezone", "spiritual_preferences"]
+        );
+
+        assert!(user.apply_profile_patch(&patch, now).is_ok());
+        assert_eq!(user.username, "stargazer");
+        assert_eq!(user.email, None);
+        assert_eq!(user.language, "pt-BR");
+        assert_eq!(user.timezone, "Asia/Yerevan");
+        assert_eq!(
+            user.spiritual_preferences,
+            json!({
+                "system": "western",
+                "notification_preferences": { "full_moon_alerts": true }
+            })
+        );
+        assert_eq!(user.updated_at, now);
+
+        // `null` resets to the defaults
+        let reset = parse_patch(
+            json!({ "language": null, "timezone": null, "spiritual_preferences": null }),
+        );
+        assert!(user.apply_profile_patch(&reset, now).is_ok());
+        assert_eq!(user.language, DEFAULT_LANGUAGE);
+        assert_eq!(user.timezone, DEFAULT_TIMEZONE);
+        assert_eq!(user.spiritual_preferences, json!({}));
+    }
+
+    #[test]
+    fn test_profile_patch_validation() {
+        let field_of = |result: DomainResult<()>| match result {
+            Err(DomainError::ValidationFailed { field, .. }) => field,
+            other => format!("{:?}", other),
+        };
+        let now = time::OffsetDateTime::now_utc();
+        let cases = [
+            (json!({ "username": "no spaces" }), "username"),
+            (json!({ "username": "ab" }), "username"),
+            (json!({ "email": "not-an-email" }), "email"),
+            (json!({ "language": "english" }), "language"),
+            (json!({ "timezone": "Mars/Olympus_Mons" }), "timezone"),
+            (
+                json!({ "spiritual_preferences": { "notification_preferences": { "full_moon_alerts": "yes" } } }),
+                "spiritual_preferences.notification_preferences",
+            ),
+        ];
+        for (document, field) in cases {
+            let mut user = user();
+            let before = user.clone();
+            assert_eq!(
+                field_of(user.apply_profile_patch(&parse_patch(document), now)),
+                field
+            );
+            assert_eq!(user.username, before.username);
+            assert_eq!(user.spiritual_preferences, before.spiritual_preferences);
+        }
+
+        let parse_field =
+            |document: Value| field_of(ProfilePatch::from_json(&document).map(|_| ()));
+        assert_eq!(parse_field(json!({ "username": null })), "username");
+        assert_eq!(parse_field(json!({ "email": 42 })), "email");
+        assert_eq!(parse_field(json!({ "is_active": false })), "is_active");
+        assert_eq!(parse_field(json!(["username"])), "profile");
+    }
+
+    #[test]
+    fn test_merge_patch_rfc7396_examples() {
+        let mut target = json!({ "a": "b", "c": { "d": "e", "f": "g" } });
+        merge_patch(&mut target, &json!({ "a": "z", "c": { "f": null } }));
+        assert_eq!(target, json!({ "a": "z", "c": { "d": "e" } }));
+
+        let mut target = json!({ "a": [{ "b": "c" }] });
+        merge_patch(&mut target, &json!({ "a": [1] }));
+        assert_eq!(target, json!({ "a": [1] }));
+
+        let mut target = json!(["a", "b"]);
+        merge_patch(&mut target, &json!({ "e": null, "a": 1 }));
+        assert_eq!(target, json!({ "a": 1 }));
+    }
+}
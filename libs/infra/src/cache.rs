@@ -19,6 +19,13 @@ impl RedisCacheService {
     pub fn new(conn: MultiplexedConnection) -> Self {
         Self { conn }
     }
+
+    /// Connect to the Redis server at `url`
+    pub async fn connect(url: &str) -> Result<Self, InfraError> {
+        let client = redis::Client::open(url)?;
+        let conn = client.get_multiplexed_async_connection().await?;
+        Ok(Self::new(conn))
+    }
 }
 
 #[async_trait]
@@ -174,7 +181,7 @@ impl TelegramCacheService {
         user_id: &starscalendars_domain::UserId,
     ) -> PortResult<()> {
         let key = format!("telegram:linking:{}", token);
-        let value = user_id.uuid().to_string();
+        let value = user_id.to_string();
 
         // Cache for 10 minutes (same as token expiry)
         self.cache.set(&key, &value, Duration::from_secs(600)).await
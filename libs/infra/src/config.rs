@@ -14,6 +14,8 @@ pub struct AppConfig {
     pub redis: RedisConfig,
     pub telegram: TelegramConfig,
     pub jwt: JwtConfig,
+    #[serde(default)]
+    pub notifications: NotificationConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +39,20 @@ pub struct RedisConfig {
 pub struct TelegramConfig {
     pub bot_token: String,
     pub channel_username: String,
+    /// Maximum age of Login Widget / WebApp `auth_date` in seconds
+    #[serde(default = "default_auth_max_age_secs")]
+    pub auth_max_age_secs: u64,
+    /// Bot username for `t.me/<bot>?start=<token>` deep links
+    #[serde(default)]
+    pub bot_username: String,
+    /// Secret expected in `X-Telegram-Bot-Api-Secret-Token`; when unset the
+    /// backend long-polls `getUpdates` instead of accepting webhook calls
+    #[serde(default)]
+    pub webhook_secret: Option<String>,
+}
+
+fn default_auth_max_age_secs() -> u64 {
+    crate::DEFAULT_TELEGRAM_AUTH_MAX_AGE_SECS
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,6 +60,36 @@ pub struct JwtConfig {
     pub secret: String,
     pub access_token_ttl: u64,
     pub refresh_token_ttl: u64,
+    /// Send refresh tokens as `Secure; HttpOnly` cookies instead of in the body
+    #[serde(default)]
+    pub refresh_token_cookie: bool,
+}
+
+/// Scheduled event notifications
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationConfig {
+    pub enabled: bool,
+    /// Days of events precomputed and scheduled ahead
+    pub horizon_days: u32,
+    /// Local hour at which notifications of the day's events are sent
+    pub delivery_hour: u8,
+    /// Seconds between dispatcher runs
+    pub interval_secs: u64,
+    /// Telegram broadcast limit
+    pub messages_per_second: u32,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            horizon_days: 14,
+            delivery_hour: 9,
+            interval_secs: 60,
+            messages_per_second: 30,
+        }
+    }
 }
 
 impl AppConfig {
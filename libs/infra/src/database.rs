@@ -68,14 +68,17 @@ impl DatabaseService {
         sqlx::query!("SELECT 1 as health_check")
             .fetch_one(&self.pool)
             .await
-            .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+            .map_err(InfraError::Database)?;
 
         Ok(())
     }
 
     /// Get connection pool statistics
     pub fn pool_stats(&self) -> (u32, u32) {
-        (self.pool.size(), self.pool.num_idle() as u32)
+        (
+            self.pool.size(),
+            u32::try_from(self.pool.num_idle()).unwrap_or(u32::MAX),
+        )
     }
 }
 
@@ -96,107 +99,97 @@ impl UserRepository for PostgresUserRepository {
     async fn create_user(&self, user: User) -> PortResult<()> {
         sqlx::query!(
             r#"
-            INSERT INTO users (id, username, email, telegram_user_id, created_at, updated_at, is_active)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            INSERT INTO users (id, username, email, telegram_user_id, created_at, updated_at, is_active,
+                               language, timezone, spiritual_preferences)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             "#,
             user.id.as_uuid(),
             user.username,
             user.email,
-            user.telegram_user_id.map(|id| id.as_i64()),
+            user.telegram_user_id.map(|id| id.value()),
             user.created_at,
             user.updated_at,
-            user.is_active
+            user.is_active,
+            user.language,
+            user.timezone,
+            &user.spiritual_preferences
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+        .map_err(|e| user_write_error(e, &user))?;
 
         Ok(())
     }
 
     async fn get_user_by_id(&self, id: &UserId) -> PortResult<Option<User>> {
-        let row = sqlx::query!(
-            "SELECT id, username, email, telegram_user_id, created_at, updated_at, is_active FROM users WHERE id = $1",
+        let row = sqlx::query_as!(
+            UserRow,
+            r#"SELECT id, username, email, telegram_user_id, created_at AS "created_at!",
+                      updated_at AS "updated_at!", is_active AS "is_active!", language,
+                      timezone, spiritual_preferences
+               FROM users WHERE id = $1"#,
             id.as_uuid()
         )
         .fetch_optional(&self.pool)
         .await
         .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
 
-        match row {
-            Some(row) => {
-                let telegram_user_id = row
-                    .telegram_user_id
-                    .map(|id| TelegramUserId::new(id))
-                    .transpose()
-                    .map_err(|e| {
-                        DomainError::OperationFailed(format!("Invalid telegram user ID: {}", e))
-                    })?;
-
-                Ok(Some(User {
-                    id: UserId::from_uuid(row.id),
-                    username: row.username,
-                    email: row.email,
-                    telegram_user_id,
-                    created_at: row.created_at,
-                    updated_at: row.updated_at,
-                    is_active: row.is_active,
-                }))
-            }
-            None => Ok(None),
-        }
+        row.map(UserRow::into_user).transpose()
     }
 
     async fn get_user_by_username(&self, username: &str) -> PortResult<Option<User>> {
-        let row = sqlx::query!(
-            "SELECT id, username, email, telegram_user_id, created_at, updated_at, is_active FROM users WHERE username = $1",
+        let row = sqlx::query_as!(
+            UserRow,
+            r#"SELECT id, username, email, telegram_user_id, created_at AS "created_at!",
+                      updated_at AS "updated_at!", is_active AS "is_active!", language,
+                      timezone, spiritual_preferences
+               FROM users WHERE username = $1"#,
             username
         )
         .fetch_optional(&self.pool)
         .await
         .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
 
-        match row {
-            Some(row) => {
-                let telegram_user_id = row
-                    .telegram_user_id
-                    .map(|id| TelegramUserId::new(id))
-                    .transpose()
-                    .map_err(|e| {
-                        DomainError::OperationFailed(format!("Invalid telegram user ID: {}", e))
-                    })?;
-
-                Ok(Some(User {
-                    id: UserId::from_uuid(row.id),
-                    username: row.username,
-                    email: row.email,
-                    telegram_user_id,
-                    created_at: row.created_at,
-                    updated_at: row.updated_at,
-                    is_active: row.is_active,
-                }))
-            }
-            None => Ok(None),
-        }
+        row.map(UserRow::into_user).transpose()
+    }
+
+    async fn get_user_by_telegram_id(&self, telegram_user_id: i64) -> PortResult<Option<User>> {
+        let row = sqlx::query_as!(
+            UserRow,
+            r#"SELECT id, username, email, telegram_user_id, created_at AS "created_at!",
+                      updated_at AS "updated_at!", is_active AS "is_active!", language,
+                      timezone, spiritual_preferences
+               FROM users WHERE telegram_user_id = $1"#,
+            telegram_user_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+
+        row.map(UserRow::into_user).transpose()
     }
 
     async fn update_user(&self, user: &User) -> PortResult<()> {
         sqlx::query!(
             r#"
             UPDATE users 
-            SET username = $2, email = $3, telegram_user_id = $4, updated_at = $5, is_active = $6
+            SET username = $2, email = $3, telegram_user_id = $4, updated_at = $5, is_active = $6,
+                language = $7, timezone = $8, spiritual_preferences = $9
             WHERE id = $1
             "#,
             user.id.as_uuid(),
             user.username,
             user.email,
-            user.telegram_user_id.map(|id| id.as_i64()),
+            user.telegram_user_id.map(|id| id.value()),
             user.updated_at,
-            user.is_active
+            user.is_active,
+            user.language,
+            user.timezone,
+            &user.spiritual_preferences
         )
         .execute(&self.pool)
         .await
-        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+        .map_err(|e| user_write_error(e, user))?;
 
         Ok(())
     }
@@ -211,6 +204,64 @@ impl UserRepository for PostgresUserRepository {
     }
 }
 
+/// Map a failed insert or update of `user`; unique violations become the
+/// matching domain conflict
+fn user_write_error(e: sqlx::Error, user: &User) -> DomainError {
+    let constraint = e
+        .as_database_error()
+        .filter(|db| db.is_unique_violation())
+        .and_then(|db| db.constraint());
+    match (constraint, user.telegram_user_id) {
+        (Some("users_username_key"), _) => DomainError::UsernameExists(user.username.clone()),
+        (Some("users_email_key"), _) => {
+            DomainError::EmailExists(user.email.clone().unwrap_or_default())
+        }
+        (Some("users_telegram_user_id_key"), Some(telegram_user_id)) => {
+            DomainError::TelegramAlreadyLinked(telegram_user_id.value())
+        }
+        _ => DomainError::OperationFailed(format!("Database error: {}", e)),
+    }
+}
+
+/// Row of `users` as read by the repository getters
+struct UserRow {
+    id: uuid::Uuid,
+    username: String,
+    email: Option<String>,
+    telegram_user_id: Option<i64>,
+    created_at: time::OffsetDateTime,
+    updated_at: time::OffsetDateTime,
+    is_active: bool,
+    language: String,
+    timezone: String,
+    spiritual_preferences: serde_json::Value,
+}
+
+impl UserRow {
+    fn into_user(self) -> PortResult<User> {
+        let telegram_user_id = self
+            .telegram_user_id
+            .map(TelegramUserId::new)
+            .transpose()
+            .map_err(|e| {
+                DomainError::OperationFailed(format!("Invalid telegram user ID: {}", e))
+            })?;
+
+        Ok(User {
+            id: UserId::from_uuid(self.id),
+            username: self.username,
+            email: self.email,
+            telegram_user_id,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            is_active: self.is_active,
+            language: self.language,
+            timezone: self.timezone,
+            spiritual_preferences: self.spiritual_preferences,
+        })
+    }
+}
+
 /// PostgreSQL implementation of TokenRepository
 pub struct PostgresTokenRepository {
     pool: PgPool,
@@ -228,12 +279,13 @@ impl TokenRepository for PostgresTokenRepository {
     async fn store_refresh_token(&self, token: &RefreshToken) -> PortResult<()> {
         sqlx::query!(
             r#"
-            INSERT INTO refresh_tokens (id, user_id, token_hash, created_at, expires_at, is_revoked)
-            VALUES ($1, $2, $3, $4, $5, $6)
+            INSERT INTO refresh_tokens (id, user_id, token_hash, family_id, created_at, expires_at, is_revoked)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             "#,
             token.id,
             token.user_id.as_uuid(),
             token.token_hash,
+            token.family_id,
             token.created_at,
             token.expires_at,
             token.is_revoked
@@ -247,7 +299,9 @@ impl TokenRepository for PostgresTokenRepository {
 
     async fn get_refresh_token(&self, token_hash: &str) -> PortResult<Option<RefreshToken>> {
         let row = sqlx::query!(
-            "SELECT id, user_id, token_hash, created_at, expires_at, is_revoked FROM refresh_tokens WHERE token_hash = $1",
+            r#"SELECT id, user_id, token_hash, family_id, created_at AS "created_at!", expires_at,
+                      is_revoked AS "is_revoked!"
+               FROM refresh_tokens WHERE token_hash = $1"#,
             token_hash
         )
         .fetch_optional(&self.pool)
@@ -257,8 +311,9 @@ impl TokenRepository for PostgresTokenRepository {
         match row {
             Some(row) => Ok(Some(RefreshToken {
                 id: row.id,
-                user_id: starscalendars_domain::auth::UserId::from_uuid(row.user_id),
+                user_id: starscalendars_domain::UserId::from_uuid(row.user_id),
                 token_hash: row.token_hash,
+                family_id: row.family_id,
                 created_at: row.created_at,
                 expires_at: row.expires_at,
                 is_revoked: row.is_revoked,
@@ -267,16 +322,29 @@ impl TokenRepository for PostgresTokenRepository {
         }
     }
 
-    async fn revoke_refresh_token(&self, token_hash: &str) -> PortResult<()> {
-        sqlx::query!(
-            "UPDATE refresh_tokens SET is_revoked = true WHERE token_hash = $1",
+    async fn revoke_refresh_token(&self, token_hash: &str) -> PortResult<bool> {
+        // Conditional update: of two concurrent rotations only one wins
+        let result = sqlx::query!(
+            "UPDATE refresh_tokens SET is_revoked = true WHERE token_hash = $1 AND is_revoked = false",
             token_hash
         )
         .execute(&self.pool)
         .await
         .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
 
-        Ok(())
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn revoke_refresh_token_family(&self, family_id: &uuid::Uuid) -> PortResult<u64> {
+        let result = sqlx::query!(
+            "UPDATE refresh_tokens SET is_revoked = true WHERE family_id = $1 AND is_revoked = false",
+            family_id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected())
     }
 
     async fn store_linking_token(&self, token: &LinkingToken) -> PortResult<()> {
@@ -300,7 +368,8 @@ impl TokenRepository for PostgresTokenRepository {
 
     async fn get_linking_token(&self, token: &uuid::Uuid) -> PortResult<Option<LinkingToken>> {
         let row = sqlx::query!(
-            "SELECT token, user_id, created_at, expires_at, is_used FROM linking_tokens WHERE token = $1",
+            r#"SELECT token, user_id, created_at AS "created_at!", expires_at, is_used AS "is_used!"
+               FROM linking_tokens WHERE token = $1"#,
             token
         )
         .fetch_optional(&self.pool)
@@ -310,7 +379,7 @@ impl TokenRepository for PostgresTokenRepository {
         match row {
             Some(row) => Ok(Some(LinkingToken {
                 token: row.token,
-                user_id: starscalendars_domain::auth::UserId::from_uuid(row.user_id),
+                user_id: starscalendars_domain::UserId::from_uuid(row.user_id),
                 created_at: row.created_at,
                 expires_at: row.expires_at,
                 is_used: row.is_used,
@@ -319,11 +388,353 @@ impl TokenRepository for PostgresTokenRepository {
         }
     }
 
-    async fn mark_linking_token_used(&self, token: &uuid::Uuid) -> PortResult<()> {
-        sqlx::query!(
-            "UPDATE linking_tokens SET is_used = true WHERE token = $1",
+    async fn consume_linking_token(&self, token: &uuid::Uuid) -> PortResult<Option<LinkingToken>> {
+        // A single conditional UPDATE, so two concurrent /start commands cannot both consume it
+        let row = sqlx::query!(
+            r#"
+            UPDATE linking_tokens SET is_used = true
+            WHERE token = $1 AND is_used = false AND expires_at > now()
+            RETURNING token, user_id, created_at AS "created_at!", expires_at, is_used AS "is_used!"
+            "#,
             token
         )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+
+        Ok(row.map(|row| LinkingToken {
+            token: row.token,
+            user_id: starscalendars_domain::UserId::from_uuid(row.user_id),
+            created_at: row.created_at,
+            expires_at: row.expires_at,
+            is_used: row.is_used,
+        }))
+    }
+}
+
+/// PostgreSQL repository of precomputed spiritual events
+pub struct PostgresSpiritualEventRepository {
+    pool: PgPool,
+}
+
+impl PostgresSpiritualEventRepository {
+    /// Create new PostgreSQL spiritual event repository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// `spiritual_events` row; JSONB is read as text (no sqlx `json` feature)
+struct SpiritualEventRow {
+    id: uuid::Uuid,
+    event_type: String,
+    title: String,
+    description: Option<String>,
+    astronomical_data: Option<String>,
+    quantum_resonance: Option<f64>,
+    occurs_at: time::OffsetDateTime,
+}
+
+impl SpiritualEventRow {
+    fn into_event(self) -> PortResult<spiritual::SpiritualEvent> {
+        let astronomical_data = match self.astronomical_data {
+            Some(json) => serde_json::from_str(&json).map_err(|e| {
+                DomainError::SerializationError(format!("Invalid astronomical data: {}", e))
+            })?,
+            None => serde_json::Value::Null,
+        };
+
+        Ok(spiritual::SpiritualEvent {
+            id: self.id,
+            event_type: self.event_type,
+            title: self.title,
+            description: self.description,
+            occurs_at: self.occurs_at,
+            astronomical_data,
+            quantum_resonance: self.quantum_resonance,
+        })
+    }
+}
+
+#[async_trait]
+impl SpiritualEventRepository for PostgresSpiritualEventRepository {
+    async fn events_between(
+        &self,
+        from: time::OffsetDateTime,
+        to: time::OffsetDateTime,
+    ) -> PortResult<Vec<spiritual::SpiritualEvent>> {
+        let rows = sqlx::query_as!(
+            SpiritualEventRow,
+            r#"
+            SELECT id, event_type, title, description,
+                   astronomical_data::text AS "astronomical_data?", quantum_resonance, occurs_at
+            FROM spiritual_events
+            WHERE occurs_at >= $1 AND occurs_at < $2
+            ORDER BY occurs_at
+            "#,
+            from,
+            to
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+
+        rows.into_iter()
+            .map(SpiritualEventRow::into_event)
+            .collect()
+    }
+
+    async fn next_event(
+        &self,
+        after: time::OffsetDateTime,
+        event_types: &[&str],
+    ) -> PortResult<Option<spiritual::SpiritualEvent>> {
+        let event_types: Vec<String> = event_types.iter().map(|t| t.to_string()).collect();
+        let row = sqlx::query_as!(
+            SpiritualEventRow,
+            r#"
+            SELECT id, event_type, title, description,
+                   astronomical_data::text AS "astronomical_data?", quantum_resonance, occurs_at
+            FROM spiritual_events
+            WHERE occurs_at > $1 AND (cardinality($2::text[]) = 0 OR event_type = ANY($2))
+            ORDER BY occurs_at
+            LIMIT 1
+            "#,
+            after,
+            &event_types
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+
+        row.map(SpiritualEventRow::into_event).transpose()
+    }
+
+    async fn previous_event(
+        &self,
+        at: time::OffsetDateTime,
+        event_types: &[&str],
+    ) -> PortResult<Option<spiritual::SpiritualEvent>> {
+        let event_types: Vec<String> = event_types.iter().map(|t| t.to_string()).collect();
+        let row = sqlx::query_as!(
+            SpiritualEventRow,
+            r#"
+            SELECT id, event_type, title, description,
+                   astronomical_data::text AS "astronomical_data?", quantum_resonance, occurs_at
+            FROM spiritual_events
+            WHERE occurs_at <= $1 AND (cardinality($2::text[]) = 0 OR event_type = ANY($2))
+            ORDER BY occurs_at DESC
+            LIMIT 1
+            "#,
+            at,
+            &event_types
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+
+        row.map(SpiritualEventRow::into_event).transpose()
+    }
+
+    // `as` below is the sqlx bind override for nullable array elements, not a cast
+    #[allow(clippy::as_conversions)]
+    async fn store_events(&self, events: &[spiritual::SpiritualEvent]) -> PortResult<u64> {
+        let ids: Vec<uuid::Uuid> = events.iter().map(|e| e.id).collect();
+        let event_types: Vec<String> = events.iter().map(|e| e.event_type.clone()).collect();
+        let titles: Vec<String> = events.iter().map(|e| e.title.clone()).collect();
+        let descriptions: Vec<Option<String>> =
+            events.iter().map(|e| e.description.clone()).collect();
+        let astronomical_data: Vec<Option<String>> = events
+            .iter()
+            .map(|e| (!e.astronomical_data.is_null()).then(|| e.astronomical_data.to_string()))
+            .collect();
+        let resonances: Vec<Option<f64>> = events.iter().map(|e| e.quantum_resonance).collect();
+        let occurs_at: Vec<time::OffsetDateTime> = events.iter().map(|e| e.occurs_at).collect();
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO spiritual_events (id, event_type, title, description, astronomical_data, quantum_resonance, occurs_at)
+            SELECT id, event_type, title, description, astronomical_data::jsonb, quantum_resonance, occurs_at
+            FROM UNNEST($1::uuid[], $2::text[], $3::text[], $4::text[], $5::text[], $6::float8[], $7::timestamptz[])
+                AS e(id, event_type, title, description, astronomical_data, quantum_resonance, occurs_at)
+            ON CONFLICT (event_type, occurs_at) DO NOTHING
+            "#,
+            &ids,
+            &event_types,
+            &titles,
+            &descriptions as &[Option<String>],
+            &astronomical_data as &[Option<String>],
+            &resonances as &[Option<f64>],
+            &occurs_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+/// PostgreSQL repository of scheduled notification deliveries
+pub struct PostgresNotificationRepository {
+    pool: PgPool,
+}
+
+impl PostgresNotificationRepository {
+    /// Create new PostgreSQL notification repository
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for PostgresNotificationRepository {
+    async fn recipients(&self) -> PortResult<Vec<notifications::NotificationRecipient>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, telegram_user_id AS "telegram_user_id!",
+                   COALESCE(language, 'en') AS "language!", COALESCE(timezone, 'UTC') AS "timezone!",
+                   (spiritual_preferences -> 'notification_preferences')::text AS "notification_preferences?"
+            FROM users
+            WHERE is_active = true AND telegram_user_id IS NOT NULL
+              AND spiritual_preferences ? 'notification_preferences'
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                // Malformed preferences disable notifications rather than the whole run
+                let preferences: notifications::NotificationPreferences =
+                    serde_json::from_str(row.notification_preferences.as_deref()?).ok()?;
+                preferences
+                    .any()
+                    .then(|| notifications::NotificationRecipient {
+                        user_id: starscalendars_domain::UserId::from_uuid(row.id),
+                        telegram_user_id: row.telegram_user_id,
+                        language: row.language,
+                        timezone: row.timezone,
+                        preferences,
+                    })
+            })
+            .collect())
+    }
+
+    async fn schedule_deliveries(
+        &self,
+        deliveries: &[notifications::NotificationDelivery],
+    ) -> PortResult<u64> {
+        let keys: Vec<String> = deliveries
+            .iter()
+            .map(|d| d.idempotency_key.clone())
+            .collect();
+        let user_ids: Vec<uuid::Uuid> = deliveries.iter().map(|d| d.user_id.as_uuid()).collect();
+        let event_ids: Vec<uuid::Uuid> = deliveries.iter().map(|d| d.event_id).collect();
+        let chat_ids: Vec<i64> = deliveries.iter().map(|d| d.chat_id).collect();
+        let messages: Vec<String> = deliveries.iter().map(|d| d.message.clone()).collect();
+        let deliver_at: Vec<time::OffsetDateTime> =
+            deliveries.iter().map(|d| d.deliver_at).collect();
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO notification_deliveries (idempotency_key, user_id, event_id, chat_id, message, deliver_at)
+            SELECT * FROM UNNEST($1::text[], $2::uuid[], $3::uuid[], $4::int8[], $5::text[], $6::timestamptz[])
+            ON CONFLICT (idempotency_key) DO NOTHING
+            "#,
+            &keys,
+            &user_ids,
+            &event_ids,
+            &chat_ids,
+            &messages,
+            &deliver_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: time::OffsetDateTime,
+        limit: u32,
+    ) -> PortResult<Vec<notifications::NotificationDelivery>> {
+        // SKIP LOCKED lets several backend instances dispatch side by side
+        let rows = sqlx::query!(
+            r#"
+            UPDATE notification_deliveries SET status = 'sending'
+            WHERE idempotency_key IN (
+                SELECT idempotency_key FROM notification_deliveries
+                WHERE status = 'pending' AND deliver_at <= $1
+                ORDER BY deliver_at
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING idempotency_key, user_id, event_id, chat_id, message, deliver_at, attempts
+            "#,
+            now,
+            i64::from(limit)
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+
+        let mut deliveries: Vec<notifications::NotificationDelivery> = rows
+            .into_iter()
+            .map(|row| notifications::NotificationDelivery {
+                idempotency_key: row.idempotency_key,
+                user_id: starscalendars_domain::UserId::from_uuid(row.user_id),
+                event_id: row.event_id,
+                chat_id: row.chat_id,
+                message: row.message,
+                deliver_at: row.deliver_at,
+                status: notifications::DeliveryStatus::Sending,
+                attempts: u32::try_from(row.attempts).unwrap_or(0),
+            })
+            .collect();
+        deliveries.sort_by_key(|d| d.deliver_at);
+        Ok(deliveries)
+    }
+
+    async fn mark_delivery_sent(&self, idempotency_key: &str) -> PortResult<()> {
+        sqlx::query!(
+            "UPDATE notification_deliveries SET status = 'sent', sent_at = NOW() WHERE idempotency_key = $1",
+            idempotency_key
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn record_delivery_failure(
+        &self,
+        idempotency_key: &str,
+        attempts: u32,
+        retry_at: Option<time::OffsetDateTime>,
+        error: &str,
+    ) -> PortResult<()> {
+        let status = match retry_at {
+            Some(_) => notifications::DeliveryStatus::Pending,
+            None => notifications::DeliveryStatus::Failed,
+        };
+        sqlx::query!(
+            r#"
+            UPDATE notification_deliveries
+            SET status = $2, attempts = $3, deliver_at = COALESCE($4, deliver_at), last_error = $5
+            WHERE idempotency_key = $1
+            "#,
+            idempotency_key,
+            status.as_str(),
+            i32::try_from(attempts).unwrap_or(i32::MAX),
+            retry_at,
+            error
+        )
         .execute(&self.pool)
         .await
         .map_err(|e| DomainError::OperationFailed(format!("Database error: {}", e)))?;
@@ -0,0 +1,31 @@
+//! Domain event publishing
+//!
+//! There is no message broker yet: events are written to the structured
+//! log, where the log pipeline picks them up.
+
+use starscalendars_domain::{DomainError, DomainEvent, Event, EventPublisher};
+use tracing::info;
+
+/// Publishes events as `info` log records with the serialized event
+pub struct TracingEventPublisher;
+
+impl EventPublisher for TracingEventPublisher {
+    type Error = DomainError;
+
+    fn publish(&self, event: Event) -> Result<(), Self::Error> {
+        let payload = serde_json::to_string(&event).map_err(|e| {
+            DomainError::OperationFailed(format!("Event serialization failed: {}", e))
+        })?;
+        info!(
+            event_type = event.event_type(),
+            event_id = %event.event_id().0,
+            payload = %payload,
+            "Domain event"
+        );
+        Ok(())
+    }
+
+    fn publish_batch(&self, events: Vec<Event>) -> Result<(), Self::Error> {
+        events.into_iter().try_for_each(|event| self.publish(event))
+    }
+}
@@ -4,6 +4,7 @@
 
 use async_trait::async_trait;
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use sha2::{Digest, Sha256};
 // Use only domain types for Clean Architecture
 use crate::InfraError;
 use starscalendars_domain::*;
@@ -114,6 +115,18 @@ impl JwtService for JwtServiceImpl {
         // Create a cryptographically secure random token
         Ok(Uuid::new_v4().to_string())
     }
+
+    fn hash_refresh_token(&self, token: &str) -> String {
+        refresh_token_hash(token)
+    }
+}
+
+/// Hex SHA-256 of a refresh token
+///
+/// Tokens are 122-bit random values, so a fast unsalted digest is enough
+/// and keeps the lookup by hash possible.
+pub fn refresh_token_hash(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
 }
 
 /// Mock JWT service for testing
@@ -164,13 +177,18 @@ impl JwtService for MockJwtService {
         match &self.mock_claims {
             Some(claims) => Ok(claims.clone()),
             None => {
-                let user_id = starscalendars_domain::auth::UserId::new();
+                let user_id = starscalendars_domain::UserId::new();
                 Ok(JwtClaims::new(&user_id, None, false, &[]))
             }
         }
     }
 
     async fn create_refresh_token(&self) -> PortResult<String> {
-        Ok("mock_refresh_token".to_string())
+        // Unique, so that a rotated token never replaces its predecessor
+        Ok(format!("mock_refresh_token_{}", uuid::Uuid::new_v4()))
+    }
+
+    fn hash_refresh_token(&self, token: &str) -> String {
+        refresh_token_hash(token)
     }
 }
@@ -5,8 +5,10 @@
 
 pub mod cache;
 pub mod database;
+pub mod events;
 pub mod jwt;
 pub mod telegram;
+pub mod telegram_auth;
 // ❌ REMOVED: astronomical services violate WASM-only architecture per tz.md
 pub mod config;
 pub mod mocks;
@@ -14,8 +16,10 @@ pub mod mocks;
 // Re-export implementations
 pub use cache::*;
 pub use database::*;
+pub use events::*;
 pub use jwt::*;
 pub use telegram::*;
+pub use telegram_auth::*;
 // ❌ REMOVED: astronomical services - WASM-only calculations per tz.md
 pub use config::*;
 pub use mocks::*;
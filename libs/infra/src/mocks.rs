@@ -22,7 +22,7 @@ impl MockUserRepository {
     }
 
     pub fn add_user(&self, user: User) {
-        self.users.insert(user.id.clone(), user);
+        self.users.insert(user.id, user);
     }
 }
 
@@ -35,7 +35,7 @@ impl Default for MockUserRepository {
 #[async_trait]
 impl UserRepository for MockUserRepository {
     async fn create_user(&self, user: User) -> PortResult<()> {
-        self.users.insert(user.id.clone(), user);
+        self.users.insert(user.id, user);
         Ok(())
     }
 
@@ -51,8 +51,16 @@ impl UserRepository for MockUserRepository {
             .map(|entry| entry.clone()))
     }
 
+    async fn get_user_by_telegram_id(&self, telegram_user_id: i64) -> PortResult<Option<User>> {
+        Ok(self
+            .users
+            .iter()
+            .find(|entry| entry.telegram_user_id.map(|id| id.value()) == Some(telegram_user_id))
+            .map(|entry| entry.clone()))
+    }
+
     async fn update_user(&self, user: &User) -> PortResult<()> {
-        self.users.insert(user.id.clone(), user.clone());
+        self.users.insert(user.id, user.clone());
         Ok(())
     }
 
@@ -98,11 +106,22 @@ impl TokenRepository for MockTokenRepository {
             .map(|token| token.clone()))
     }
 
-    async fn revoke_refresh_token(&self, token_hash: &str) -> PortResult<()> {
-        if let Some(mut token) = self.refresh_tokens.get_mut(token_hash) {
-            token.is_revoked = true;
+    async fn revoke_refresh_token(&self, token_hash: &str) -> PortResult<bool> {
+        Ok(match self.refresh_tokens.get_mut(token_hash) {
+            Some(mut token) => !std::mem::replace(&mut token.is_revoked, true),
+            None => false,
+        })
+    }
+
+    async fn revoke_refresh_token_family(&self, family_id: &uuid::Uuid) -> PortResult<u64> {
+        let mut revoked = 0;
+        for mut token in self.refresh_tokens.iter_mut() {
+            if token.family_id == *family_id && !token.is_revoked {
+                token.is_revoked = true;
+                revoked += 1;
+            }
         }
-        Ok(())
+        Ok(revoked)
     }
 
     async fn store_linking_token(&self, token: &LinkingToken) -> PortResult<()> {
@@ -114,9 +133,264 @@ impl TokenRepository for MockTokenRepository {
         Ok(self.linking_tokens.get(token).map(|token| token.clone()))
     }
 
-    async fn mark_linking_token_used(&self, token: &uuid::Uuid) -> PortResult<()> {
-        if let Some(mut linking_token) = self.linking_tokens.get_mut(token) {
-            linking_token.is_used = true;
+    async fn consume_linking_token(&self, token: &uuid::Uuid) -> PortResult<Option<LinkingToken>> {
+        // The entry guard holds the shard lock, so check and update cannot interleave
+        let Some(mut linking_token) = self.linking_tokens.get_mut(token) else {
+            return Ok(None);
+        };
+        if !linking_token.is_valid() {
+            return Ok(None);
+        }
+        linking_token.mark_used();
+        Ok(Some(linking_token.clone()))
+    }
+}
+
+/// Mock spiritual event repository for testing
+pub struct MockSpiritualEventRepository {
+    events: std::sync::RwLock<Vec<spiritual::SpiritualEvent>>,
+}
+
+impl MockSpiritualEventRepository {
+    pub fn new() -> Self {
+        Self {
+            events: std::sync::RwLock::new(Vec::with_capacity(100)),
+        }
+    }
+
+    pub fn add_event(&self, event: spiritual::SpiritualEvent) {
+        if let Ok(mut events) = self.events.write() {
+            events.push(event);
+            events.sort_by_key(|event| event.occurs_at);
+        }
+    }
+
+    /// Events sorted by time, matching `event_types` (all when empty)
+    fn matching(&self, event_types: &[&str]) -> Vec<spiritual::SpiritualEvent> {
+        self.events
+            .read()
+            .map(|events| {
+                events
+                    .iter()
+                    .filter(|event| {
+                        event_types.is_empty() || event_types.contains(&event.event_type.as_str())
+                    })
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Default for MockSpiritualEventRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SpiritualEventRepository for MockSpiritualEventRepository {
+    async fn events_between(
+        &self,
+        from: time::OffsetDateTime,
+        to: time::OffsetDateTime,
+    ) -> PortResult<Vec<spiritual::SpiritualEvent>> {
+        Ok(self
+            .matching(&[])
+            .into_iter()
+            .filter(|event| event.occurs_at >= from && event.occurs_at < to)
+            .collect())
+    }
+
+    async fn next_event(
+        &self,
+        after: time::OffsetDateTime,
+        event_types: &[&str],
+    ) -> PortResult<Option<spiritual::SpiritualEvent>> {
+        Ok(self
+            .matching(event_types)
+            .into_iter()
+            .find(|event| event.occurs_at > after))
+    }
+
+    async fn previous_event(
+        &self,
+        at: time::OffsetDateTime,
+        event_types: &[&str],
+    ) -> PortResult<Option<spiritual::SpiritualEvent>> {
+        Ok(self
+            .matching(event_types)
+            .into_iter()
+            .rev()
+            .find(|event| event.occurs_at <= at))
+    }
+
+    async fn store_events(&self, events: &[spiritual::SpiritualEvent]) -> PortResult<u64> {
+        let mut inserted = 0;
+        for event in events {
+            let known = self
+                .matching(&[event.event_type.as_str()])
+                .iter()
+                .any(|known| known.occurs_at == event.occurs_at);
+            if !known {
+                self.add_event(event.clone());
+                inserted += 1;
+            }
+        }
+        Ok(inserted)
+    }
+}
+
+/// Mock event publisher recording published events
+pub struct MockEventPublisher {
+    events: std::sync::Mutex<Vec<Event>>,
+}
+
+impl MockEventPublisher {
+    pub fn new() -> Self {
+        Self {
+            events: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Events published so far, oldest first
+    pub fn published(&self) -> Vec<Event> {
+        self.events
+            .lock()
+            .map(|events| events.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl Default for MockEventPublisher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventPublisher for MockEventPublisher {
+    type Error = DomainError;
+
+    fn publish(&self, event: Event) -> Result<(), Self::Error> {
+        self.publish_batch(vec![event])
+    }
+
+    fn publish_batch(&self, mut events: Vec<Event>) -> Result<(), Self::Error> {
+        self.events
+            .lock()
+            .map(|mut published| published.append(&mut events))
+            .map_err(|_| DomainError::OperationFailed("Event log poisoned".to_string()))
+    }
+}
+
+/// Mock notification repository for testing
+pub struct MockNotificationRepository {
+    recipients: std::sync::RwLock<Vec<notifications::NotificationRecipient>>,
+    deliveries: dashmap::DashMap<String, notifications::NotificationDelivery>,
+}
+
+impl MockNotificationRepository {
+    pub fn new() -> Self {
+        Self {
+            recipients: std::sync::RwLock::new(Vec::new()),
+            deliveries: dashmap::DashMap::with_capacity(100),
+        }
+    }
+
+    pub fn add_recipient(&self, recipient: notifications::NotificationRecipient) {
+        if let Ok(mut recipients) = self.recipients.write() {
+            recipients.push(recipient);
+        }
+    }
+
+    /// Stored deliveries, earliest first
+    pub fn deliveries(&self) -> Vec<notifications::NotificationDelivery> {
+        let mut deliveries: Vec<_> = self
+            .deliveries
+            .iter()
+            .map(|entry| entry.value().clone())
+            .collect();
+        deliveries.sort_by_key(|d| d.deliver_at);
+        deliveries
+    }
+}
+
+impl Default for MockNotificationRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for MockNotificationRepository {
+    async fn recipients(&self) -> PortResult<Vec<notifications::NotificationRecipient>> {
+        Ok(self
+            .recipients
+            .read()
+            .map(|recipients| recipients.clone())
+            .unwrap_or_default())
+    }
+
+    async fn schedule_deliveries(
+        &self,
+        deliveries: &[notifications::NotificationDelivery],
+    ) -> PortResult<u64> {
+        let mut inserted = 0;
+        for delivery in deliveries {
+            if !self.deliveries.contains_key(&delivery.idempotency_key) {
+                self.deliveries
+                    .insert(delivery.idempotency_key.clone(), delivery.clone());
+                inserted += 1;
+            }
+        }
+        Ok(inserted)
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        now: time::OffsetDateTime,
+        limit: u32,
+    ) -> PortResult<Vec<notifications::NotificationDelivery>> {
+        let due: Vec<_> = self
+            .deliveries()
+            .into_iter()
+            .filter(|d| d.status == notifications::DeliveryStatus::Pending && d.deliver_at <= now)
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .collect();
+
+        Ok(due
+            .into_iter()
+            .filter_map(|delivery| {
+                let mut stored = self.deliveries.get_mut(&delivery.idempotency_key)?;
+                stored.status = notifications::DeliveryStatus::Sending;
+                Some(stored.clone())
+            })
+            .collect())
+    }
+
+    async fn mark_delivery_sent(&self, idempotency_key: &str) -> PortResult<()> {
+        if let Some(mut delivery) = self.deliveries.get_mut(idempotency_key) {
+            delivery.status = notifications::DeliveryStatus::Sent;
+        }
+        Ok(())
+    }
+
+    async fn record_delivery_failure(
+        &self,
+        idempotency_key: &str,
+        attempts: u32,
+        retry_at: Option<time::OffsetDateTime>,
+        _error: &str,
+    ) -> PortResult<()> {
+        if let Some(mut delivery) = self.deliveries.get_mut(idempotency_key) {
+            delivery.attempts = attempts;
+            match retry_at {
+                Some(retry_at) => {
+                    delivery.status = notifications::DeliveryStatus::Pending;
+                    delivery.deliver_at = retry_at;
+                }
+                None => delivery.status = notifications::DeliveryStatus::Failed,
+            }
         }
         Ok(())
     }
@@ -160,6 +160,87 @@ impl TelegramApiService {
             .result
             .ok_or_else(|| InfraError::TelegramApi("No bot info in response".to_string()))
     }
+
+    /// Long-poll messages with `update_id >= offset`, waiting up to `timeout_secs`
+    ///
+    /// Telegram refuses `getUpdates` while a webhook is set.
+    pub async fn get_updates(
+        &self,
+        offset: i64,
+        timeout_secs: u64,
+    ) -> Result<Vec<BotUpdate>, InfraError> {
+        #[derive(Serialize)]
+        struct GetUpdatesRequest {
+            offset: i64,
+            timeout: u64,
+            allowed_updates: [&'static str; 1],
+        }
+
+        #[derive(Deserialize)]
+        struct GetUpdatesResponse {
+            ok: bool,
+            result: Option<Vec<BotUpdate>>,
+            description: Option<String>,
+        }
+
+        let request = GetUpdatesRequest {
+            offset,
+            timeout: timeout_secs,
+            allowed_updates: ["message"],
+        };
+
+        let response = self
+            .client
+            .post(&self.api_url("getUpdates"))
+            .timeout(Duration::from_secs(timeout_secs + 10))
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| InfraError::Http(e))?;
+
+        let updates_response: GetUpdatesResponse =
+            response.json().await.map_err(|e| InfraError::Http(e))?;
+
+        if !updates_response.ok {
+            let error_msg = updates_response
+                .description
+                .unwrap_or_else(|| "Failed to get updates".to_string());
+            return Err(InfraError::TelegramApi(error_msg));
+        }
+
+        Ok(updates_response.result.unwrap_or_default())
+    }
+}
+
+/// Incoming Bot API update, as delivered by `getUpdates` or a webhook
+///
+/// Only the fields the backend reacts to are kept.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BotUpdate {
+    pub update_id: i64,
+    pub message: Option<BotMessage>,
+}
+
+/// Message of a [`BotUpdate`]
+#[derive(Debug, Clone, Deserialize)]
+pub struct BotMessage {
+    pub chat: BotChat,
+    pub from: Option<BotUser>,
+    pub text: Option<String>,
+}
+
+/// Chat a [`BotMessage`] was sent in
+#[derive(Debug, Clone, Deserialize)]
+pub struct BotChat {
+    pub id: i64,
+}
+
+/// Sender of a [`BotMessage`]
+#[derive(Debug, Clone, Deserialize)]
+pub struct BotUser {
+    pub id: i64,
+    pub is_bot: bool,
+    pub language_code: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
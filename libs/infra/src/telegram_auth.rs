@@ -0,0 +1,235 @@
+//! Telegram Login Widget and WebApp authentication
+//!
+//! Both flows sign the received fields with HMAC-SHA256 over a
+//! data-check-string: `key=value` pairs sorted by key and joined by `\n`,
+//! without `hash`. Only the key differs: SHA-256 of the bot token for the
+//! Login Widget, HMAC-SHA256 of the bot token keyed with `WebAppData` for
+//! WebApp `initData`.
+
+use crate::InfraError;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use starscalendars_domain::telegram::TelegramUserId;
+use starscalendars_domain::*;
+use std::collections::BTreeMap;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Default lifetime of a signed payload (24 hours)
+pub const DEFAULT_TELEGRAM_AUTH_MAX_AGE_SECS: u64 = 86_400;
+
+/// Verifier for Telegram authentication payloads signed with the bot token
+pub struct TelegramAuthVerifierImpl {
+    login_widget_key: [u8; 32],
+    web_app_key: [u8; 32],
+    max_age: time::Duration,
+}
+
+/// `user` field of WebApp `initData`
+#[derive(serde::Deserialize)]
+struct WebAppUser {
+    id: i64,
+    first_name: String,
+    last_name: Option<String>,
+    username: Option<String>,
+    language_code: Option<String>,
+}
+
+impl TelegramAuthVerifierImpl {
+    /// Create verifier for `bot_token`, accepting payloads up to `max_age` old
+    pub fn new(bot_token: &str, max_age: time::Duration) -> Result<Self, InfraError> {
+        let mut mac = HmacSha256::new_from_slice(b"WebAppData")
+            .map_err(|e| InfraError::Configuration(format!("HMAC key error: {}", e)))?;
+        mac.update(bot_token.as_bytes());
+
+        Ok(Self {
+            login_widget_key: Sha256::digest(bot_token.as_bytes()).into(),
+            web_app_key: mac.finalize().into_bytes().into(),
+            max_age,
+        })
+    }
+
+    /// Verify `payload` as of `now`
+    pub fn verify_at(
+        &self,
+        payload: &TelegramAuthPayload,
+        now: time::OffsetDateTime,
+    ) -> PortResult<TelegramLogin> {
+        let login = match payload {
+            TelegramAuthPayload::LoginWidget(fields) => self.verify_login_widget(fields)?,
+            TelegramAuthPayload::WebAppInitData(init_data) => self.verify_web_app(init_data)?,
+        };
+        login.ensure_fresh(now, self.max_age)?;
+        Ok(login)
+    }
+
+    fn verify_login_widget(
+        &self,
+        fields: &serde_json::Map<String, serde_json::Value>,
+    ) -> PortResult<TelegramLogin> {
+        let mut values = BTreeMap::new();
+        for (key, value) in fields {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Number(n) => n.to_string(),
+                serde_json::Value::Bool(b) => b.to_string(),
+                _ => return Err(invalid(&format!("unexpected value for {}", key))),
+            };
+            values.insert(key.clone(), value);
+        }
+        check_signature(&self.login_widget_key, &values)?;
+
+        let id = required(&values, "id")?
+            .parse::<i64>()
+            .map_err(|_| invalid("malformed id"))?;
+        Ok(TelegramLogin {
+            user_id: TelegramUserId::new(id)?,
+            first_name: required(&values, "first_name")?.to_string(),
+            last_name: values.get("last_name").cloned(),
+            username: values.get("username").cloned(),
+            language_code: None,
+            auth_date: auth_date(&values)?,
+        })
+    }
+
+    fn verify_web_app(&self, init_data: &str) -> PortResult<TelegramLogin> {
+        let mut values = BTreeMap::new();
+        for (key, value) in form_urlencoded::parse(init_data.as_bytes()).into_owned() {
+            if values.insert(key, value).is_some() {
+                return Err(invalid("duplicate field"));
+            }
+        }
+        check_signature(&self.web_app_key, &values)?;
+
+        let user: WebAppUser = serde_json::from_str(required(&values, "user")?)
+            .map_err(|e| invalid(&format!("malformed user: {}", e)))?;
+        Ok(TelegramLogin {
+            user_id: TelegramUserId::new(user.id)?,
+            first_name: user.first_name,
+            last_name: user.last_name,
+            username: user.username,
+            language_code: user.language_code,
+            auth_date: auth_date(&values)?,
+        })
+    }
+}
+
+impl TelegramAuthVerifier for TelegramAuthVerifierImpl {
+    fn verify(&self, payload: &TelegramAuthPayload) -> PortResult<TelegramLogin> {
+        self.verify_at(payload, time::OffsetDateTime::now_utc())
+    }
+}
+
+fn invalid(reason: &str) -> DomainError {
+    DomainError::InvalidTelegramAuth(reason.to_string())
+}
+
+fn required<'a>(values: &'a BTreeMap<String, String>, key: &str) -> PortResult<&'a str> {
+    values
+        .get(key)
+        .map(String::as_str)
+        .ok_or_else(|| invalid(&format!("missing {}", key)))
+}
+
+fn auth_date(values: &BTreeMap<String, String>) -> PortResult<time::OffsetDateTime> {
+    required(values, "auth_date")?
+        .parse::<i64>()
+        .ok()
+        .and_then(|seconds| time::OffsetDateTime::from_unix_timestamp(seconds).ok())
+        .ok_or_else(|| invalid("malformed auth_date"))
+}
+
+/// Constant-time check of `hash` against the data-check-string of `values`
+fn check_signature(key: &[u8], values: &BTreeMap<String, String>) -> PortResult<()> {
+    let hash = hex::decode(required(values, "hash")?).map_err(|_| invalid("malformed hash"))?;
+    let data_check_string = values
+        .iter()
+        .filter(|(key, _)| key.as_str() != "hash")
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut mac = HmacSha256::new_from_slice(key)
+        .map_err(|e| DomainError::InternalError(format!("HMAC key error: {}", e)))?;
+    mac.update(data_check_string.as_bytes());
+    mac.verify_slice(&hash)
+        .map_err(|_| invalid("signature mismatch"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOT_TOKEN: &str = "123456:TEST-TOKEN";
+
+    /// Signed with `BOT_TOKEN` at 2025-10-09 08:53:20 UTC
+    const AUTH_DATE: i64 = 1_760_000_000;
+
+    const INIT_DATA: &str = "query_id=AAH&user=%7B%22id%22%3A123456789%2C%22first_name%22%3A%22Ada%22%2C%22last_name%22%3A%22Lovelace%22%2C%22username%22%3A%22ada%22%2C%22language_code%22%3A%22ru%22%7D&auth_date=1760000000&hash=be208d1ad658cd693fcd3f98f94503af21d5fbf5c04e820de7fbc333bd37cb6d";
+
+    fn verifier() -> Option<TelegramAuthVerifierImpl> {
+        TelegramAuthVerifierImpl::new(BOT_TOKEN, time::Duration::hours(1)).ok()
+    }
+
+    fn at(offset_secs: i64) -> time::OffsetDateTime {
+        time::OffsetDateTime::from_unix_timestamp(AUTH_DATE + offset_secs)
+            .unwrap_or(time::OffsetDateTime::UNIX_EPOCH)
+    }
+
+    fn login_widget(hash: &str) -> TelegramAuthPayload {
+        let fields = serde_json::json!({
+            "id": 123456789,
+            "first_name": "Ada",
+            "username": "ada",
+            "auth_date": AUTH_DATE,
+            "hash": hash,
+        });
+        match fields {
+            serde_json::Value::Object(map) => TelegramAuthPayload::LoginWidget(map),
+            _ => TelegramAuthPayload::WebAppInitData(String::new()),
+        }
+    }
+
+    #[test]
+    fn test_login_widget_signature() {
+        let Some(verifier) = verifier() else {
+            panic!("Failed to create Telegram auth verifier");
+        };
+        let payload =
+            login_widget("4f1183a9cb582184b2cbd130b3b2cc8ca5335e2e3140fd508c4969db75b82ab2");
+        let login = verifier.verify_at(&payload, at(300));
+        assert!(
+            matches!(&login, Ok(l) if l.user_id.value() == 123456789 && l.username.as_deref() == Some("ada"))
+        );
+
+        let forged =
+            login_widget("5f1183a9cb582184b2cbd130b3b2cc8ca5335e2e3140fd508c4969db75b82ab2");
+        assert!(matches!(
+            verifier.verify_at(&forged, at(300)),
+            Err(DomainError::InvalidTelegramAuth(_))
+        ));
+        assert!(matches!(
+            verifier.verify_at(&payload, at(7200)),
+            Err(DomainError::TelegramAuthExpired)
+        ));
+    }
+
+    #[test]
+    fn test_web_app_init_data_signature() {
+        let Some(verifier) = verifier() else {
+            panic!("Failed to create Telegram auth verifier");
+        };
+        let payload = TelegramAuthPayload::WebAppInitData(INIT_DATA.to_string());
+        let login = verifier.verify_at(&payload, at(60));
+        assert!(
+            matches!(&login, Ok(l) if l.language_code.as_deref() == Some("ru") && l.last_name.as_deref() == Some("Lovelace"))
+        );
+
+        // Same data signed for another bot
+        let other = TelegramAuthVerifierImpl::new("654321:OTHER", time::Duration::hours(1));
+        assert!(matches!(other, Ok(v) if v.verify_at(&payload, at(60)).is_err()));
+
+        let tampered = TelegramAuthPayload::WebAppInitData(INIT_DATA.replace("Ada", "Eve"));
+        assert!(verifier.verify_at(&tampered, at(60)).is_err());
+    }
+}
@@ -0,0 +1,11 @@
+-- Refresh token rotation with reuse detection
+-- Tokens are stored as SHA-256 digests and grouped into rotation families:
+-- presenting a revoked token revokes every token of its family.
+
+-- Rows written before hashing hold plaintext tokens; drop them (users sign in again)
+DELETE FROM refresh_tokens;
+
+ALTER TABLE refresh_tokens
+    ADD COLUMN family_id UUID NOT NULL DEFAULT uuid_generate_v4();
+
+CREATE INDEX idx_refresh_tokens_family_id ON refresh_tokens(family_id);
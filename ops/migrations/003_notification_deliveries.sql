@@ -0,0 +1,24 @@
+-- Scheduled Telegram notifications of precomputed events
+-- Events are precomputed repeatedly over a rolling window, so an event is
+-- identified by its type and instant. Each (event, user) notification is a
+-- delivery row keyed by an idempotency key; a row is claimed ('sending')
+-- before its message is sent and is never claimed twice.
+
+CREATE UNIQUE INDEX idx_spiritual_events_type_occurs_at ON spiritual_events(event_type, occurs_at);
+
+CREATE TABLE notification_deliveries (
+    idempotency_key VARCHAR(100) PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    event_id UUID NOT NULL REFERENCES spiritual_events(id) ON DELETE CASCADE,
+    chat_id BIGINT NOT NULL,
+    message TEXT NOT NULL,
+    deliver_at TIMESTAMPTZ NOT NULL,
+    status VARCHAR(10) NOT NULL DEFAULT 'pending', -- pending, sending, sent, failed
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    sent_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+CREATE INDEX idx_notification_deliveries_due ON notification_deliveries(deliver_at) WHERE status = 'pending';
+CREATE INDEX idx_notification_deliveries_user_id ON notification_deliveries(user_id);
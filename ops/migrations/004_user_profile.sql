@@ -0,0 +1,16 @@
+-- Editable user profiles
+-- Accounts created by Telegram sign-in have no email, and a profile edit may
+-- remove it. Language, time zone and spiritual preferences always hold a
+-- value so that clearing them means "back to the default".
+
+ALTER TABLE users ALTER COLUMN email DROP NOT NULL;
+
+UPDATE users
+SET language = COALESCE(language, 'en'),
+    timezone = COALESCE(timezone, 'UTC'),
+    spiritual_preferences = COALESCE(spiritual_preferences, '{}');
+
+ALTER TABLE users
+    ALTER COLUMN language SET NOT NULL,
+    ALTER COLUMN timezone SET NOT NULL,
+    ALTER COLUMN spiritual_preferences SET NOT NULL;
@@ -0,0 +1,108 @@
+#!/usr/bin/env python3
+"""Generate the embedded star table from the Yale Bright Star Catalogue
+
+Reads the fixed-width BSC5 catalog (CDS V/50, file `catalog`, or the
+Harvard `bsc5.dat`) and writes the `star!` rows included by
+libs/astro-core/src/stars.rs, ordered by right ascension: every star with
+a J2000 position down to the limiting magnitude, or with `--named` only
+the stars the app labels (the embedded table).
+
+Usage: scripts/gen-bright-stars.py [--named] bsc5.dat > libs/astro-core/src/bright_stars.rs
+"""
+
+import sys
+
+LIMITING_MAGNITUDE = 6.0
+
+# IAU proper names of the stars the app labels
+PROPER_NAMES = {
+    15: "Alpheratz", 21: "Caph", 39: "Algenib", 168: "Schedar", 188: "Diphda",
+    264: "Navi", 337: "Mirach", 424: "Polaris", 472: "Achernar", 603: "Almach",
+    617: "Hamal", 911: "Menkar", 936: "Algol", 1017: "Mirfak", 1165: "Alcyone",
+    1457: "Aldebaran", 1708: "Capella", 1713: "Rigel", 1790: "Bellatrix",
+    1791: "Elnath", 1852: "Mintaka", 1879: "Meissa", 1899: "Hatysa",
+    1903: "Alnilam", 1948: "Alnitak", 2004: "Saiph", 2061: "Betelgeuse",
+    2088: "Menkalinan", 2294: "Mirzam", 2326: "Canopus", 2421: "Alhena",
+    2491: "Sirius", 2618: "Adhara", 2693: "Wezen", 2891: "Castor",
+    2943: "Procyon", 2990: "Pollux", 3307: "Avior", 3685: "Miaplacidus",
+    3748: "Alphard", 3982: "Regulus", 4295: "Merak", 4301: "Dubhe",
+    4534: "Denebola", 4554: "Phecda", 4660: "Megrez", 4730: "Acrux",
+    4763: "Gacrux", 4853: "Mimosa", 4905: "Alioth", 5054: "Mizar",
+    5056: "Spica", 5191: "Alkaid", 5267: "Hadar", 5340: "Arcturus",
+    5459: "Rigil Kentaurus", 5506: "Izar", 5531: "Zubenelgenubi",
+    5563: "Kochab", 5793: "Alphecca", 5953: "Dschubba", 6134: "Antares",
+    6217: "Atria", 6527: "Shaula", 6553: "Sargas", 6556: "Rasalhague",
+    6705: "Eltanin", 6879: "Kaus Australis", 7001: "Vega", 7121: "Nunki",
+    7417: "Albireo", 7557: "Altair", 7790: "Peacock", 7796: "Sadr",
+    7924: "Deneb", 8308: "Enif", 8425: "Alnair", 8728: "Fomalhaut",
+    8775: "Scheat", 8781: "Markab",
+}
+
+
+def field(line, start, end):
+    """Bytes start … end (1-based, inclusive) of a catalog line, stripped"""
+    return line[start - 1:end].strip()
+
+
+def parse(line):
+    """Catalog row, or None for entries without a position or magnitude"""
+    ra_h = field(line, 76, 77)
+    vmag = field(line, 103, 107)
+    if not ra_h or not vmag:
+        return None
+    hr = int(field(line, 1, 4))
+    name = PROPER_NAMES.get(hr) or " ".join(field(line, 5, 14).split()) or f"HR {hr}"
+    pm_ra = field(line, 149, 154) or "0"
+    pm_dec = field(line, 155, 160) or "0"
+    return {
+        "hr": hr,
+        "name": name,
+        "ra": (int(ra_h), int(field(line, 78, 79)), float(field(line, 80, 83))),
+        "dec": (
+            -1.0 if field(line, 84, 84) == "-" else 1.0,
+            int(field(line, 85, 86)),
+            int(field(line, 87, 88)),
+            int(field(line, 89, 90)),
+        ),
+        # arcsec/yr → mas/yr
+        "pm": (float(pm_ra) * 1000.0, float(pm_dec) * 1000.0),
+        "vmag": float(vmag),
+    }
+
+
+def row(star):
+    rh, rm, rs = star["ra"]
+    sign, dd, dm, ds = star["dec"]
+    pm_ra, pm_dec = star["pm"]
+    name = star["name"].replace("\\", "\\\\").replace('"', '\\"')
+    return (
+        f'    star!({star["hr"]}, "{name}", {rh}.0, {rm}.0, {rs:.3f}, {sign}, '
+        f"{dd}.0, {dm}.0, {ds:.2f}, {pm_ra:.2f}, {pm_dec:.2f}, {star['vmag']:.2f}),"
+    )
+
+
+def main():
+    args = sys.argv[1:]
+    named = "--named" in args
+    if named:
+        args.remove("--named")
+    if len(args) != 1:
+        sys.exit(__doc__)
+    with open(args[0], encoding="latin-1") as catalog:
+        stars = [s for s in map(parse, catalog) if s and s["vmag"] <= LIMITING_MAGNITUDE]
+    if named:
+        stars = [s for s in stars if s["hr"] in PROPER_NAMES]
+    stars.sort(key=lambda s: (s["ra"], s["hr"]))
+
+    faintest = max(s["vmag"] for s in stars)
+    kind = "named stars" if named else "stars"
+    print("// Generated by scripts/gen-bright-stars.py from the Yale Bright Star")
+    print(f"// Catalogue (BSC5): {len(stars)} {kind} to V ≤ {faintest:.2f}. Do not edit.")
+    print("[")
+    for star in stars:
+        print(row(star))
+    print("]")
+
+
+if __name__ == "__main__":
+    main()
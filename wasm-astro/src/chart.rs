@@ -0,0 +1,81 @@
+//! Astrological chart for an instant and place
+//!
+//! Binding over `starscalendars_astro_core::chart`: angles, house cusps and
+//! the apparent longitudes and speeds of the Sun, Moon and planets in one
+//! buffer. Used for natal charts and for the return charts of `returns`.
+
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::JulianDay;
+use starscalendars_astro_core::chart::{CHART_LEN, cast_chart};
+use starscalendars_astro_core::houses::HouseSystem;
+use starscalendars_astro_core::zodiac::Zodiac;
+
+thread_local! {
+    static CHART_BUFFER: RefCell<[f64; CHART_LEN]> = const { RefCell::new([0.0; CHART_LEN]) };
+}
+
+/// Validate chart inputs and decode the codes
+pub(crate) fn chart_options(
+    jd_utc: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    house_system: u32,
+    zodiac: u32,
+) -> Option<(HouseSystem, Zodiac)> {
+    if JulianDay::new(jd_utc).is_err() || !lat_rad.is_finite() || !lon_east_rad.is_finite() {
+        return None;
+    }
+    Some((
+        HouseSystem::from_code(house_system)?,
+        Zodiac::from_code(zodiac)?,
+    ))
+}
+
+/// Copy a chart into the shared chart buffer
+pub(crate) fn write_chart(chart: Option<[f64; CHART_LEN]>) -> *const f64 {
+    match chart {
+        Some(chart) => CHART_BUFFER.with(|buffer| {
+            let mut buf = buffer.borrow_mut();
+            *buf = chart;
+            buf.as_ptr()
+        }),
+        None => std::ptr::null(),
+    }
+}
+
+/// **PURE ASTRO-RUST**: Chart for an instant and place
+///
+/// Uses astro::lunar::geocent_ecl_pos(), VSOP87 and astro::pluto for the
+/// bodies and the apparent sidereal time for the angles. `house_system`:
+/// 0 Placidus, 1 whole sign, 2 equal; `zodiac`: 0 tropical, 1 sidereal
+/// (Lahiri). Layout [35 f64], see `cast_chart`. Returns null on invalid
+/// input or when Placidus is undefined at the latitude.
+#[wasm_bindgen]
+pub fn get_chart(
+    jd_utc: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    house_system: u32,
+    zodiac: u32,
+) -> *const f64 {
+    let Some((system, zodiac)) = chart_options(jd_utc, lat_rad, lon_east_rad, house_system, zodiac)
+    else {
+        return std::ptr::null();
+    };
+    write_chart(cast_chart(jd_utc, lat_rad, lon_east_rad, system, zodiac))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chart_codes() {
+        let (lat, lon) = (0.9, 0.0);
+        assert!(get_chart(2460681.0, lat, lon, 3, 0).is_null());
+        assert!(get_chart(-1.0, lat, lon, 0, 0).is_null());
+        assert!(!get_chart(2460681.0, lat, lon, 0, 1).is_null());
+    }
+}
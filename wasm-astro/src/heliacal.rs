@@ -0,0 +1,102 @@
+//! Heliacal rising and setting (first and last visibility)
+//!
+//! Binding over `starscalendars_astro_core::heliacal`: validates the
+//! arguments and returns NaN where the core search finds no event.
+
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::JulianDay;
+use starscalendars_astro_core::heliacal::{HeliacalBody, HeliacalEvent, next_heliacal_event};
+use starscalendars_astro_core::stars::star_by_hr;
+
+/// Validate wasm arguments and run the search
+fn next_heliacal_event_checked(
+    body: Option<HeliacalBody>,
+    event: u32,
+    jd_utc_start: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    arcus_visionis_rad: f64,
+) -> f64 {
+    if JulianDay::new(jd_utc_start).is_err() || !lat_rad.is_finite() || !lon_east_rad.is_finite() {
+        return f64::NAN;
+    }
+    let (Some(body), Some(event)) = (body, HeliacalEvent::from_code(event)) else {
+        return f64::NAN;
+    };
+    let arcus_visionis_override =
+        (arcus_visionis_rad.is_finite() && arcus_visionis_rad > 0.0).then_some(arcus_visionis_rad);
+    next_heliacal_event(
+        &body,
+        event,
+        jd_utc_start,
+        lat_rad,
+        lon_east_rad,
+        arcus_visionis_override,
+    )
+    .unwrap_or(f64::NAN)
+}
+
+/// Next heliacal event of a catalog star (by HR number)
+///
+/// Event: 0 heliacal rising, 1 heliacal setting, 2 first evening,
+/// 3 last morning visibility. Pass NaN as `arcus_visionis_rad` for the
+/// magnitude-based default. Returns the JD UTC of the twilight moment of
+/// visibility, or NaN if none within ~800 days.
+/// Heavy: use off-frame (idle) only and cache the result.
+#[wasm_bindgen]
+pub fn next_star_heliacal_event(
+    hr: u16,
+    event: u32,
+    jd_utc_start: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    arcus_visionis_rad: f64,
+) -> f64 {
+    next_heliacal_event_checked(
+        star_by_hr(hr).map(HeliacalBody::Star),
+        event,
+        jd_utc_start,
+        lat_rad,
+        lon_east_rad,
+        arcus_visionis_rad,
+    )
+}
+
+/// Next heliacal event of a naked-eye planet (0 Mercury, 1 Venus, 3 Mars,
+/// 4 Jupiter, 5 Saturn)
+///
+/// Same events and result as `next_star_heliacal_event`; the default arcus
+/// visionis follows the planet's magnitude on each day.
+/// Heavy: use off-frame (idle) only and cache the result.
+#[wasm_bindgen]
+pub fn next_planet_heliacal_event(
+    planet_index: usize,
+    event: u32,
+    jd_utc_start: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    arcus_visionis_rad: f64,
+) -> f64 {
+    next_heliacal_event_checked(
+        HeliacalBody::planet(planet_index),
+        event,
+        jd_utc_start,
+        lat_rad,
+        lon_east_rad,
+        arcus_visionis_rad,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_validation() {
+        assert!(next_star_heliacal_event(9999, 0, 2460676.5, 0.5, 0.5, f64::NAN).is_nan());
+        assert!(next_star_heliacal_event(2491, 7, 2460676.5, 0.5, 0.5, f64::NAN).is_nan());
+        assert!(next_planet_heliacal_event(2, 0, 2460676.5, 0.5, 0.5, f64::NAN).is_nan());
+        assert!(next_star_heliacal_event(2491, 0, f64::NAN, 0.5, 0.5, f64::NAN).is_nan());
+    }
+}
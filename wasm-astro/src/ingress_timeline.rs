@@ -0,0 +1,152 @@
+//! Sign ingress and house transit timeline of the Sun and planets
+//!
+//! Binding over `starscalendars_astro_core::ingress_timeline`. Rows are
+//! `(jd_utc, body, from, to, direction)` with direction +1 direct and -1
+//! retrograde; this module bounds the query range and the row count (a
+//! timeline that does not fit is rejected, never cut short).
+
+use std::cell::{Cell, RefCell};
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::JulianDay;
+use starscalendars_astro_core::houses::{HouseSystem, chart_angles, house_cusps};
+use starscalendars_astro_core::ingress_timeline::{
+    TIMELINE_STRIDE, TimelineKind, ingress_timeline, timeline_rows,
+};
+use starscalendars_astro_core::timescales::utc_to_tt_jd;
+use starscalendars_astro_core::zodiac::Zodiac;
+
+/// Maximum timeline rows per query
+const MAX_TIMELINE_ROWS: usize = 256;
+
+/// Maximum length of a query range, in days
+const MAX_RANGE_DAYS: f64 = 366.0; // @allow-wasm-const
+
+thread_local! {
+    static TIMELINE_BUFFER: RefCell<[f64; MAX_TIMELINE_ROWS * TIMELINE_STRIDE]> =
+        const { RefCell::new([0.0; MAX_TIMELINE_ROWS * TIMELINE_STRIDE]) };
+    static TIMELINE_COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Solve a timeline and fill the shared buffer
+fn write_timeline(jd_utc_start: f64, jd_utc_end: f64, kind: TimelineKind) -> *const f64 {
+    ingress_timeline(kind, utc_to_tt_jd(jd_utc_start), utc_to_tt_jd(jd_utc_end))
+        .map_or(std::ptr::null(), |found| write_rows(&timeline_rows(kind, &found)))
+}
+
+/// Copy rows into the shared buffer; null when they do not all fit
+fn write_rows(rows: &[[f64; TIMELINE_STRIDE]]) -> *const f64 {
+    if rows.len() > MAX_TIMELINE_ROWS {
+        return std::ptr::null();
+    }
+    TIMELINE_BUFFER.with(|buffer| {
+        let mut buf = buffer.borrow_mut();
+        let (slots, _) = buf.as_chunks_mut::<TIMELINE_STRIDE>();
+        for (slot, row) in slots.iter_mut().zip(rows) {
+            *slot = *row;
+        }
+        TIMELINE_COUNT.with(|count| count.set(rows.len()));
+        buf.as_ptr()
+    })
+}
+
+/// Whether a JD UTC range is valid for a timeline query
+fn valid_range(jd_utc_start: f64, jd_utc_end: f64) -> bool {
+    JulianDay::new(jd_utc_start).is_ok()
+        && JulianDay::new(jd_utc_end).is_ok()
+        && jd_utc_end > jd_utc_start
+        && jd_utc_end - jd_utc_start <= MAX_RANGE_DAYS
+}
+
+/// **PURE ASTRO-RUST**: Sign ingresses of the Sun and planets (Mercury … Pluto)
+///
+/// Uses VSOP87 (astro::planet) and astro::pluto::heliocent_pos() for apparent
+/// longitudes. `zodiac`: 0 tropical, 1 sidereal (Lahiri). Range up to 366
+/// days. Layout [get_ingress_timeline_count() × 5 f64]: JD UTC, body code
+/// (0 Sun, 2 Mercury … 9 Pluto), from sign, to sign (0 Aries … 11 Pisces),
+/// direction (+1 direct, -1 retrograde). Returns null on invalid input or
+/// when the timeline exceeds 256 rows (query a shorter range).
+/// Heavy: use off-frame (idle) only and cache the result.
+#[wasm_bindgen]
+pub fn get_sign_ingress_timeline(jd_utc_start: f64, jd_utc_end: f64, zodiac: u32) -> *const f64 {
+    TIMELINE_COUNT.with(|count| count.set(0));
+    match Zodiac::from_code(zodiac) {
+        Some(zodiac) if valid_range(jd_utc_start, jd_utc_end) => {
+            write_timeline(jd_utc_start, jd_utc_end, TimelineKind::Signs(zodiac))
+        }
+        _ => std::ptr::null(),
+    }
+}
+
+/// **PURE ASTRO-RUST**: Transits of the Sun and planets through natal houses
+///
+/// Natal cusps from the birth JD UTC and place; `house_system`: 0 Placidus,
+/// 1 whole sign, 2 equal. Same layout as `get_sign_ingress_timeline` with
+/// houses numbered 1 … 12 (tropical longitudes of date). Returns null on
+/// invalid input, when Placidus is undefined at the birth latitude or when
+/// the timeline exceeds 256 rows.
+/// Heavy: use off-frame (idle) only and cache the result.
+#[wasm_bindgen]
+pub fn get_house_transit_timeline(
+    jd_utc_start: f64,
+    jd_utc_end: f64,
+    natal_jd_utc: f64,
+    natal_lat_rad: f64,
+    natal_lon_east_rad: f64,
+    house_system: u32,
+) -> *const f64 {
+    TIMELINE_COUNT.with(|count| count.set(0));
+    let Some(system) = HouseSystem::from_code(house_system) else {
+        return std::ptr::null();
+    };
+    if !valid_range(jd_utc_start, jd_utc_end)
+        || JulianDay::new(natal_jd_utc).is_err()
+        || !natal_lat_rad.is_finite()
+        || !natal_lon_east_rad.is_finite()
+    {
+        return std::ptr::null();
+    }
+    let angles = chart_angles(natal_jd_utc, natal_lat_rad, natal_lon_east_rad);
+    match house_cusps(&angles, system) {
+        Some(cusps) => write_timeline(jd_utc_start, jd_utc_end, TimelineKind::Houses(cusps)),
+        None => std::ptr::null(),
+    }
+}
+
+/// Number of rows written by the last timeline call
+#[wasm_bindgen]
+pub fn get_ingress_timeline_count() -> usize {
+    TIMELINE_COUNT.with(Cell::get)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2025-01-01 00:00 TT
+    const JAN_2025: f64 = 2460676.5;
+
+    #[test]
+    fn test_export_validation() {
+        assert!(!get_sign_ingress_timeline(JAN_2025, JAN_2025 + 31.0, 1).is_null());
+        assert!(get_ingress_timeline_count() >= 1);
+        assert!(get_sign_ingress_timeline(JAN_2025, JAN_2025 + 400.0, 0).is_null());
+        assert_eq!(get_ingress_timeline_count(), 0);
+        assert!(get_sign_ingress_timeline(JAN_2025, JAN_2025 + 31.0, 5).is_null());
+        assert!(
+            get_house_transit_timeline(JAN_2025, JAN_2025 + 31.0, 2448058.0, 1.4, 0.0, 0).is_null()
+        );
+        assert!(
+            !get_house_transit_timeline(JAN_2025, JAN_2025 + 31.0, 2448058.0, 1.4, 0.0, 1)
+                .is_null()
+        );
+    }
+
+    #[test]
+    fn test_overflow_is_rejected() {
+        let rows = vec![[JAN_2025, 0.0, 0.0, 1.0, 1.0]; MAX_TIMELINE_ROWS + 1];
+        assert!(write_rows(&rows).is_null());
+        assert!(!write_rows(&rows[..MAX_TIMELINE_ROWS]).is_null());
+        assert_eq!(get_ingress_timeline_count(), MAX_TIMELINE_ROWS);
+    }
+}
@@ -3,6 +3,10 @@
 //! Pure astro-rust library wrapper with ZERO custom calculations.
 //! Follows O(1) горячий путь principle with exactly one compute_state(t) call per frame.
 //!
+//! All computation lives in `starscalendars-astro-core` (safe native API):
+//! the exports here and in the feature modules only validate arguments and
+//! copy results into the thread-local buffers.
+//!
 //! ## Performance Requirements
 //! - Exactly one `compute_state(julian_day)` call per frame
 //! - Zero-copy data transfer via Float64Array view
@@ -23,36 +27,10 @@
 use std::cell::RefCell;
 use wasm_bindgen::prelude::*;
 
-// WASM-only types - astronomical calculations are forbidden in backend per tz.md
-#[derive(Debug, Clone, Copy)]
-struct JulianDay(f64);
-
-impl JulianDay {
-    pub fn new(jd: f64) -> Result<Self, &'static str> {
-        if jd < 0.0 || !jd.is_finite() {
-            Err("Invalid Julian Day")
-        } else {
-            Ok(Self(jd))
-        }
-    }
-
-    pub fn as_f64(&self) -> f64 {
-        self.0
-    }
-}
-
-#[derive(Debug, Clone, Copy)]
-struct Cartesian {
-    pub x: f64,
-    pub y: f64,
-    pub z: f64,
-}
-
-impl Cartesian {
-    pub fn new(x: f64, y: f64, z: f64) -> Self {
-        Self { x, y, z }
-    }
-}
+// Ephemeris core shared with native callers; this crate only adds the JS bindings
+use starscalendars_astro_core::bodies::ecliptic_to_equatorial;
+use starscalendars_astro_core::state::{planet_geocentric_state, planet_heliocentric_state};
+use starscalendars_astro_core::{JulianDay, ecliptic_to_cartesian};
 
 #[derive(Debug, Clone, Copy)]
 enum CelestialBody {
@@ -99,102 +77,37 @@ macro_rules! console_log {
     ($($t:tt)*) => (log(&format_args!($($t)*).to_string()))
 }
 
-// ===== Reusable time scale utilities (UTC↔TT) with leap seconds support =====
+// ===== Time scale utilities (UTC↔TT) with leap seconds support =====
 mod timescales {
-    use std::cell::RefCell;
     use wasm_bindgen::prelude::*;
 
-    thread_local! {
-        static TAI_MINUS_UTC_OVERRIDE: RefCell<Option<f64>> = const { RefCell::new(None) };
-    }
-
-    const LEAP_SECONDS: &[(i32, u8, f64)] = &[
-        (1972, 1, 10.0),
-        (1972, 7, 11.0),
-        (1973, 1, 12.0),
-        (1974, 1, 13.0),
-        (1975, 1, 14.0),
-        (1976, 1, 15.0),
-        (1977, 1, 16.0),
-        (1978, 1, 17.0),
-        (1979, 1, 18.0),
-        (1980, 1, 19.0),
-        (1981, 7, 20.0),
-        (1982, 7, 21.0),
-        (1983, 7, 22.0),
-        (1985, 7, 23.0),
-        (1988, 1, 24.0),
-        (1990, 1, 25.0),
-        (1991, 1, 26.0),
-        (1992, 7, 27.0),
-        (1993, 7, 28.0),
-        (1994, 7, 29.0),
-        (1996, 1, 30.0),
-        (1997, 7, 31.0),
-        (1999, 1, 32.0),
-        (2006, 1, 33.0),
-        (2009, 1, 34.0),
-        (2013, 1, 35.0),
-        (2015, 7, 36.0),
-        (2017, 1, 37.0),
-    ];
-
-    fn tai_minus_utc_seconds_for_year_month(year: i32, month: u8) -> f64 {
-        let override_val = TAI_MINUS_UTC_OVERRIDE.with(|c| *c.borrow());
-        if let Some(v) = override_val {
-            return v;
-        }
-        // Saturate to last known (37 s since 2017-01) for future years until updated
-        let mut val = if year < 1972 { 0.0 } else { 10.0 };
-        let mut i = 0usize;
-        while i < LEAP_SECONDS.len() {
-            let (y, m, v) = LEAP_SECONDS[i];
-            if year > y || (year == y && month >= m) {
-                val = v;
-            }
-            i += 1;
-        }
-        val
-    }
-
-    pub fn utc_to_tt_jd(jd_utc: f64) -> f64 {
-        let (y, m, _d) = match astro::time::date_frm_julian_day(jd_utc) {
-            Ok((yy, mm, dd)) => (yy as i32, mm as u8, dd),
-            Err(_) => return f64::NAN,
-        };
-        let tai_utc = tai_minus_utc_seconds_for_year_month(y, m);
-        let tt_minus_utc = tai_utc + 32.184;
-        jd_utc + tt_minus_utc / 86400.0
-    }
-
-    pub fn tt_to_utc_jd(jd_tt: f64) -> f64 {
-        let (y, m, _d) = match astro::time::date_frm_julian_day(jd_tt) {
-            Ok((yy, mm, dd)) => (yy as i32, mm as u8, dd),
-            Err(_) => return f64::NAN,
-        };
-        let tai_utc = tai_minus_utc_seconds_for_year_month(y, m);
-        let tt_minus_utc = tai_utc + 32.184;
-        jd_tt - tt_minus_utc / 86400.0
-    }
-
     #[wasm_bindgen]
     pub fn set_tai_minus_utc_override(seconds: f64) {
-        TAI_MINUS_UTC_OVERRIDE.with(|c| {
-            *c.borrow_mut() = if seconds.is_finite() && seconds >= 0.0 {
-                Some(seconds)
-            } else {
-                None
-            };
-        });
+        starscalendars_astro_core::timescales::set_tai_minus_utc_override(seconds);
     }
 
     #[wasm_bindgen]
     pub fn clear_tai_minus_utc_override() {
-        TAI_MINUS_UTC_OVERRIDE.with(|c| {
-            *c.borrow_mut() = None;
-        });
-    }
-}
+        starscalendars_astro_core::timescales::clear_tai_minus_utc_override();
+    }
+}
+
+// ===== Feature modules (thin wasm exports over astro-core) =====
+mod chart;
+mod heliacal;
+mod ingress_timeline;
+mod lunar_physical;
+mod meteors;
+mod moon_phase;
+mod orion;
+mod physical;
+mod planetary_hours;
+mod returns;
+mod satellites;
+mod small_body;
+mod stars;
+mod synastry;
+mod void_of_course;
 
 /// Thread-local buffer for ephemeris data (O(1) горячий путь requirement)
 ///
@@ -216,59 +129,29 @@ pub fn init() {
 // Legacy compute_all API removed. Use compute_state(julian_day).
 
 /// Compute main state in a single call (future-extensible):
-/// Layout [11 f64]:
+/// Layout [17 f64]:
 /// - Sun(0..2): zeros by design (Sun fixed at scene origin; skip per-frame solar math)
 /// - Moon(3..5): geocentric ecliptic Cartesian (x,y,z) in AU
 /// - Earth(6..8): heliocentric ecliptic Cartesian (x,y,z) in AU
 /// - Zenith(9..10): Solar zenith [lon_east_rad, lat_rad]
+/// - Moon velocity(11..13): geocentric ecliptic (vx,vy,vz) in AU/day
+/// - Earth velocity(14..16): heliocentric ecliptic (vx,vy,vz) in AU/day
+///
+/// Slots 0..10 keep their original meaning, so an 11-wide view stays valid.
 #[wasm_bindgen]
 pub fn compute_state(julian_day: f64) -> *const f64 {
+    use starscalendars_astro_core::state::STATE_LEN;
     thread_local! {
-        static STATE_BUFFER: RefCell<[f64; 11]> = const { RefCell::new([0.0; 11]) };
+        static STATE_BUFFER: RefCell<[f64; STATE_LEN]> = const { RefCell::new([0.0; STATE_LEN]) };
     }
 
+    let Some(state) = starscalendars_astro_core::compute_state(julian_day) else {
+        console_log!("❌ Invalid Julian Day: {}", julian_day);
+        return std::ptr::null();
+    };
     STATE_BUFFER.with(|buffer| {
         let mut out = buffer.borrow_mut();
-
-        // Validate JD
-        let jd = match JulianDay::new(julian_day) {
-            Ok(jd) => jd.as_f64(),
-            Err(_) => {
-                console_log!("❌ Invalid Julian Day: {}", julian_day);
-                return std::ptr::null();
-            }
-        };
-
-        // Nutation for precision
-        let (nut_long, _nut_oblq) = astro::nutation::nutation(jd);
-
-        // Sun position/derived values are not used in hot path; keep zeros to minimize per-frame work
-        out[0] = 0.0; // reserved
-        out[1] = 0.0; // reserved
-        out[2] = 0.0; // reserved
-
-        // Moon geocentric
-        let (moon_ecl, moon_dist_km) = astro::lunar::geocent_ecl_pos(jd);
-        let moon_dist_au = moon_dist_km / 149597870.7;
-        let moon_corrected_long = moon_ecl.long + nut_long;
-        let moon_pos = ecliptic_to_cartesian(moon_corrected_long, moon_ecl.lat, moon_dist_au);
-        out[3] = moon_pos.x;
-        out[4] = moon_pos.y;
-        out[5] = moon_pos.z;
-
-        // Earth heliocentric
-        let (earth_long, earth_lat, earth_r) =
-            astro::planet::heliocent_coords(&astro::planet::Planet::Earth, jd);
-        let earth_pos = ecliptic_to_cartesian(earth_long, earth_lat, earth_r);
-        out[6] = earth_pos.x;
-        out[7] = earth_pos.y;
-        out[8] = earth_pos.z;
-
-        // Solar zenith in radians (lon E+, lat N+)
-        let (zenith_lon_east_rad, zenith_lat_rad) = solar_zenith_position_rad_internal(jd);
-        out[9] = zenith_lon_east_rad;
-        out[10] = zenith_lat_rad;
-
+        *out = state.to_array();
         out.as_ptr()
     })
 }
@@ -277,91 +160,16 @@ pub fn compute_state(julian_day: f64) -> *const f64 {
 /// Returns JD in UTC of the event. Heavy: use off-frame (idle) only.
 #[wasm_bindgen]
 pub fn next_winter_solstice_from(jd_utc_start: f64) -> f64 {
-    // Validate
-    let jd_utc = match JulianDay::new(jd_utc_start) {
-        Ok(jd) => jd.as_f64(),
-        Err(_) => return f64::NAN,
-    };
-
-    use crate::timescales::{tt_to_utc_jd, utc_to_tt_jd};
-
-    // Helpers for angular math
-    #[inline]
-    fn wrap_two_pi(x: f64) -> f64 {
-        astro::angle::limit_to_two_PI(x)
-    }
-    #[inline]
-    fn principal_angle(x: f64) -> f64 {
-        let w = wrap_two_pi(x);
-        if w > std::f64::consts::PI {
-            w - 2.0 * std::f64::consts::PI
-        } else {
-            w
-        }
-    }
-
-    // Apparent solar ecliptic longitude λ_app (TT)
-    let solar_lambda_app_tt = |jd_tt: f64| -> f64 {
-        // Geometric geocentric ecliptic coords (mean equinox of date)
-        let (sun_ecl, sun_dist_au) = astro::sun::geocent_ecl_pos(jd_tt);
-        // FK5 correction
-        let (long_fk5, lat_fk5) = astro::sun::ecl_coords_to_FK5(jd_tt, sun_ecl.long, sun_ecl.lat);
-        // Annual aberration (radians)
-        let ab_long = astro::aberr::sol_aberr(sun_dist_au);
-        // Nutation in longitude
-        let (nut_long, _nut_oblq) = astro::nutation::nutation(jd_tt);
-        wrap_two_pi(long_fk5 + ab_long + nut_long)
-    };
-
-    // Start from TT corresponding to start UTC
-    let jd_tt0 = utc_to_tt_jd(jd_utc);
-    if !jd_tt0.is_finite() {
-        return f64::NAN;
-    }
-
-    // Target longitude: 270° (winter solstice)
-    let target = 270.0_f64.to_radians();
-    let lambda0 = solar_lambda_app_tt(jd_tt0);
-    // Mean solar rate ~0.98564736°/day = 0.017202124 rad/day
-    const SOLAR_MEAN_RATE: f64 = 0.017202124; // @allow-numeric-param
-    // Forward phase to next target
-    let delta_forward = wrap_two_pi(target - lambda0);
-    let mut t = jd_tt0 + delta_forward / SOLAR_MEAN_RATE;
-
-    // Newton with numeric derivative (robust, few iterations)
-    let mut iter = 0u32;
-    const H: f64 = 1.0e-3; // ~86.4 s @allow-numeric-param
-    const MAX_ITERS: u32 = 16; // @allow-numeric-param
-    const MAX_STEP: f64 = 2.0; // days @allow-numeric-param
-    loop {
-        if !t.is_finite() || iter >= MAX_ITERS {
-            break;
-        }
-        let lam = solar_lambda_app_tt(t);
-        let f = principal_angle(lam - target);
-        if f.abs() < 1.0e-10 {
-            break;
-        }
-        let lam_p = solar_lambda_app_tt(t + H);
-        let lam_m = solar_lambda_app_tt(t - H);
-        let dfdt = principal_angle(lam_p - lam_m) / (2.0 * H);
-        if dfdt.abs() < 1.0e-12 {
-            break;
-        }
-        let mut step = f / dfdt;
-        if step > MAX_STEP {
-            step = MAX_STEP;
-        }
-        if step < -MAX_STEP {
-            step = -MAX_STEP;
-        }
-        t -= step;
-        iter += 1;
-    }
-    let jd_tt_min = t;
+    starscalendars_astro_core::next_winter_solstice_from(jd_utc_start).unwrap_or(f64::NAN)
+}
 
-    // Convert TT -> UTC using ΔT at event date
-    tt_to_utc_jd(jd_tt_min)
+/// Find the next instant the Sun's apparent ecliptic longitude reaches `target_lon_rad`
+/// (equinox and ecliptic of date) starting from given UTC JD.
+/// Returns JD in UTC of the event, NaN on invalid input. Heavy: use off-frame (idle) only.
+#[wasm_bindgen]
+pub fn next_solar_longitude_from(jd_utc_start: f64, target_lon_rad: f64) -> f64 {
+    starscalendars_astro_core::next_solar_longitude_from(jd_utc_start, target_lon_rad)
+        .unwrap_or(f64::NAN)
 }
 
 // Removed legacy helpers get_body_count/get_coordinate_count (no longer used by frontend)
@@ -473,19 +281,6 @@ fn calculate_all_positions_pure_astro_rust(
     Ok(())
 }
 
-/// Convert ecliptic spherical coordinates to Cartesian
-///
-/// High-performance coordinate transformation without allocations.
-/// Input coordinates are in RADIANS (as returned by astro-rust API).
-#[inline]
-fn ecliptic_to_cartesian(longitude_rad: f64, latitude_rad: f64, radius_au: f64) -> Cartesian {
-    let cos_lat = latitude_rad.cos();
-    let x = radius_au * cos_lat * longitude_rad.cos();
-    let y = radius_au * cos_lat * longitude_rad.sin();
-    let z = radius_au * latitude_rad.sin();
-    Cartesian::new(x, y, z)
-}
-
 // ========== ADDITIONAL ASTRO-RUST API COVERAGE ==========
 // Complete coverage of astro-rust library functions for full astronomical calculations
 
@@ -619,6 +414,66 @@ pub fn get_pluto_position(julian_day: f64) -> *const f64 {
     })
 }
 
+/// **PURE ASTRO-RUST**: Get planet heliocentric state (position + velocity)
+///
+/// Layout [6 f64]: [x, y, z, vx, vy, vz] — ecliptic Cartesian of date, AU and
+/// AU/day (Pluto precessed from J2000).
+/// Velocity by symmetric differencing of VSOP87 positions.
+/// Planet indices as in `get_planet_position`, plus 8=Pluto.
+#[wasm_bindgen]
+pub fn get_planet_state(planet_index: usize, julian_day: f64) -> *const f64 {
+    thread_local! {
+        static PLANET_STATE_BUFFER: RefCell<[f64; 6]> = const { RefCell::new([0.0; 6]) };
+    }
+
+    PLANET_STATE_BUFFER.with(|buffer| {
+        let mut buf = buffer.borrow_mut();
+
+        match planet_heliocentric_state(planet_index, julian_day) {
+            Some(state) => {
+                *buf = state;
+                buf.as_ptr()
+            }
+            None => {
+                console_log!("❌ Invalid planet index: {}", planet_index);
+                std::ptr::null()
+            }
+        }
+    })
+}
+
+/// **PURE ASTRO-RUST**: Get planet geocentric state with optional light-time correction
+///
+/// Layout [7 f64]: [x, y, z, vx, vy, vz, light_time_days] — geocentric ecliptic
+/// Cartesian of date in AU and AU/day. With `apply_light_time` the planet is placed where
+/// it was when the observed light left it (τ = Δ / c); otherwise τ is reported as 0.
+/// Range rate for Doppler is `dot(r, v) / |r|` on the JS side.
+#[wasm_bindgen]
+pub fn get_planet_geocentric_state(
+    planet_index: usize,
+    julian_day: f64,
+    apply_light_time: bool,
+) -> *const f64 {
+    thread_local! {
+        static GEO_STATE_BUFFER: RefCell<[f64; 7]> = const { RefCell::new([0.0; 7]) };
+    }
+
+    GEO_STATE_BUFFER.with(|buffer| {
+        let mut buf = buffer.borrow_mut();
+
+        match planet_geocentric_state(planet_index, julian_day, apply_light_time) {
+            Some(state) => {
+                *buf = state;
+                buf.as_ptr()
+            }
+            None => {
+                console_log!("❌ Invalid planet index: {}", planet_index);
+                std::ptr::null()
+            }
+        }
+    })
+}
+
 /// **PURE ASTRO-RUST**: Get nutation corrections
 ///
 /// Returns nutation in longitude and obliquity using astro::nutation::nutation()
@@ -895,58 +750,7 @@ pub fn get_moon_horizontal_parallax(distance_km: f64) -> f64 {
     astro::lunar::eq_hz_parllx(distance_km)
 }
 
-/// **НАИТОЧНЕЙШИЙ РАСЧЕТ ЗЕНИТА СОЛНЦА**: Calculate exact solar zenith position on Earth's surface
-///
-/// Calculates the precise geographic coordinates where the Sun is directly overhead (zenith)
-/// at the given Julian Day. Uses ONLY pure astro-rust functions for maximum precision.
-///
-/// **Algorithm using pure astro-rust:**
-/// 1. Get Sun's geocentric ecliptic position (VSOP87 theory)
-/// 2. Apply nutation corrections for sub-arcsecond accuracy
-/// 3. Convert to equatorial coordinates (right ascension, declination)
-/// 4. Calculate mean sidereal time at Greenwich
-/// 5. Apply nutation to get apparent sidereal time
-/// 6. Solar zenith longitude = apparent_sidereal_time - right_ascension
-/// 7. Solar zenith latitude = declination
-///
-/// **Returns**: [longitude_degrees, latitude_degrees] where longitude is West-positive
-/// **Performance**: High-precision calculations with nutation/precession corrections
-// calculate_solar_zenith_position removed; zenith is included in compute_state.
-
-/// Internal helper: compute solar zenith position in radians (lon E-positive, lat N-positive)
-#[inline]
-fn solar_zenith_position_rad_internal(julian_day: f64) -> (f64, f64) {
-    // Geocentric solar ecliptic position with high-precision corrections
-    let (sun_ecl, sun_dist_au) = astro::sun::geocent_ecl_pos(julian_day);
-    // FK5 conversion (longitude/latitude)
-    let (sun_long_fk5, sun_lat_fk5) =
-        astro::sun::ecl_coords_to_FK5(julian_day, sun_ecl.long, sun_ecl.lat);
-    // Annual aberration in ecliptic longitude
-    let ab_long = astro::aberr::sol_aberr(sun_dist_au);
-    // Nutation and true obliquity
-    let (nut_long, nut_oblq) = astro::nutation::nutation(julian_day);
-    let mean_oblq = astro::ecliptic::mn_oblq_IAU(julian_day);
-    let true_oblq = mean_oblq + nut_oblq;
-    let sun_corrected_long = sun_long_fk5 + ab_long + nut_long;
-    // Equatorial coordinates (apparent)
-    let sun_right_ascension =
-        astro::coords::asc_frm_ecl(sun_corrected_long, sun_lat_fk5, true_oblq);
-    let sun_declination = astro::coords::dec_frm_ecl(sun_corrected_long, sun_lat_fk5, true_oblq);
-    // Sidereal time (apparent)
-    let mean_sidereal_time = astro::time::mn_sidr(julian_day);
-    let apparent_sidereal_time = astro::time::apprnt_sidr(mean_sidereal_time, nut_long, true_oblq);
-    // Zenith longitude east-positive
-    let mut zenith_longitude_rad = apparent_sidereal_time - sun_right_ascension;
-    let two_pi_limited = astro::angle::limit_to_two_PI(zenith_longitude_rad);
-    zenith_longitude_rad = if two_pi_limited > std::f64::consts::PI {
-        two_pi_limited - 2.0 * std::f64::consts::PI
-    } else {
-        two_pi_limited
-    };
-    (zenith_longitude_rad, sun_declination)
-}
-
-// calculate_solar_zenith_position_rad removed; zenith is included in compute_state.
+// Solar zenith: `starscalendars_astro_core::zenith`, included in compute_state.
 
 /// **PURE ASTRO-RUST**: Get mean sidereal time at Greenwich
 ///
@@ -986,7 +790,7 @@ pub fn convert_ecliptic_to_equatorial(
     EQUATORIAL_BUFFER.with(|buffer| {
         let mut buf = buffer.borrow_mut();
         let (right_ascension, declination) =
-            ecliptic_to_equatorial_internal(ecl_long_rad, ecl_lat_rad, julian_day, apply_nutation);
+            ecliptic_to_equatorial(ecl_long_rad, ecl_lat_rad, julian_day, apply_nutation);
         buf[0] = right_ascension;
         buf[1] = declination;
         buf.as_ptr()
@@ -995,26 +799,6 @@ pub fn convert_ecliptic_to_equatorial(
 
 // solar_zenith_position_deg_internal removed; not part of the minimal API.
 
-/// Internal helper: ecliptic to equatorial conversion with optional nutation
-#[inline]
-fn ecliptic_to_equatorial_internal(
-    ecl_long_rad: f64,
-    ecl_lat_rad: f64,
-    julian_day: f64,
-    apply_nutation: bool,
-) -> (f64, f64) {
-    let obliquity = if apply_nutation {
-        let mean_oblq = astro::ecliptic::mn_oblq_IAU(julian_day);
-        let (_nut_long, nut_oblq) = astro::nutation::nutation(julian_day);
-        mean_oblq + nut_oblq
-    } else {
-        astro::ecliptic::mn_oblq_IAU(julian_day)
-    };
-    let right_ascension = astro::coords::asc_frm_ecl(ecl_long_rad, ecl_lat_rad, obliquity);
-    let declination = astro::coords::dec_frm_ecl(ecl_long_rad, ecl_lat_rad, obliquity);
-    (right_ascension, declination)
-}
-
 /// Get version information (contract check string)
 #[wasm_bindgen]
 pub fn get_version() -> String {
@@ -1039,140 +823,53 @@ pub fn debug_get_buffer() -> String {
 pub fn get_function_count() -> usize {
     // Count of pure astro-rust functions exposed:
     // compute_state, get_sun_position, get_moon_position, get_planet_position (8 planets),
-    // get_pluto_position, get_nutation, get_mean_obliquity, julian_day_to_century,
+    // get_pluto_position, get_planet_state, get_planet_geocentric_state,
+    // get_nutation, get_mean_obliquity, julian_day_to_century,
     // get_orbital_elements, get_lunar_illumination_fraction, get_lunar_ascending_node,
     // get_lunar_perigee, apply_precession_ecliptic, apply_precession_equatorial,
     // get_planetary_apparent_magnitude_muller, get_planetary_apparent_magnitude_84,
     // get_planetary_semidiameter, get_sun_semidiameter, get_moon_semidiameter,
     // get_moon_horizontal_parallax,
-    // get_mean_sidereal_time, get_apparent_sidereal_time, convert_ecliptic_to_equatorial
-    25
+    // get_mean_sidereal_time, get_apparent_sidereal_time, convert_ecliptic_to_equatorial,
+    // get_jupiter_system, get_saturn_system, get_lunar_physical_ephemeris,
+    // get_physical_ephemeris, get_comet_state, get_asteroid_state,
+    // get_star_count, get_star_apparent_places, get_orion_belt_places,
+    // next_orion_alignment_from, set_orion_alignment_config, reset_orion_alignment_config,
+    // next_star_heliacal_event, next_planet_heliacal_event, next_solar_longitude_from,
+    // get_meteor_shower_count, get_meteor_shower, get_meteor_night_profile,
+    // get_planetary_hours, get_moon_sign_ingresses, get_moon_sign_ingress_count,
+    // get_sign_ingress_timeline, get_house_transit_timeline, get_ingress_timeline_count,
+    // get_chart, get_solar_return, get_lunar_return, set_synastry_aspect,
+    // reset_synastry_config, get_synastry, get_composite_chart, get_moon_phase
+    59
 }
 
 // ===================== QUANTUM TIME (NT) SUPPORT =====================
-// 1:1 перенос семантики из фронта (без строк). Возвращаем числа для форматирования на JS.
-
-#[derive(Clone, Copy)]
-struct QuantumEntry {
-    u_ms: f64, // Unix ms
-    d: i32,    // day in year [0..365)
-    y: i32,    // year index starting at 0
-}
-
-thread_local! {
-    static QUANTUM_TABLE: RefCell<Vec<QuantumEntry>> = RefCell::new(Vec::with_capacity(32000));
-}
-
-const QT_CONST_NT_MS: f64 = 1344643200000.0; // constNT @allow-wasm-const
-const QT_CONST_D_MS: f64 = 86459178.082191780821918_f64; // constD @allow-wasm-const
-const QT_CONST_D_EXTRA_MS: f64 = 43229589.41095890410959_f64; // constDExtra @allow-wasm-const
-const QT_MAX_TIME_MS: f64 = 4090089600000.0; // maxTime @allow-wasm-const
-const QT_SPECIAL_YEAR: i32 = 11; // specialYear @allow-wasm-const
-const QT_SPECIAL_DAY: i32 = 121; // specialDay @allow-wasm-const
-
-fn init_quantum_table_if_needed() {
-    QUANTUM_TABLE.with(|tbl| {
-        let mut v = tbl.borrow_mut();
-        if !v.is_empty() {
-            return;
-        }
-        let mut u = QT_CONST_NT_MS;
-        let mut d: i32 = 0;
-        let mut y: i32 = 0;
-        while u < QT_MAX_TIME_MS {
-            v.push(QuantumEntry { u_ms: u, d, y });
-            if y == QT_SPECIAL_YEAR && d == QT_SPECIAL_DAY {
-                u += QT_CONST_D_EXTRA_MS;
-                d += 1;
-                v.push(QuantumEntry { u_ms: u, d, y });
-                u += QT_CONST_D_EXTRA_MS;
-                d += 1;
-                v.push(QuantumEntry { u_ms: u, d, y });
-            } else {
-                u += QT_CONST_D_MS;
-                d += 1;
-            }
-            if d == 365 {
-                d = 0;
-                y += 1;
-            }
-        }
-    });
-}
-
-#[inline]
-fn floor_div(a: f64, b: f64) -> f64 {
-    (a / b).floor()
-}
-
-fn adjust_ms_like_js(epoch_ms: f64, tz_offset_min: f64) -> f64 {
-    // JS: setHours(24 - (getTimezoneOffset()/60 + 4)); minutes=seconds=ms=0
-    // Воспроизводим локальную нормализацию суток и переносим к UTC.
-    let local_ms = epoch_ms - tz_offset_min * 60_000.0;
-    let day_ms = 86_400_000.0;
-    let hour_ms = 3_600_000.0;
-    let tz_hours = tz_offset_min / 60.0;
-    let target_h_real = 24.0 - (tz_hours + 4.0);
-    let target_h_floor = target_h_real.floor();
-    // Нормализуем часы и сдвиг дня
-    let h_norm = ((target_h_floor % 24.0) + 24.0) % 24.0;
-    let day_offset = ((target_h_floor - h_norm) / 24.0).floor();
-    let local_day_idx = floor_div(local_ms, day_ms);
-    let adjusted_local_ms = (local_day_idx + day_offset) * day_ms + h_norm * hour_ms;
-    // Обратно в UTC
-    adjusted_local_ms + tz_offset_min * 60_000.0
-}
-
-fn binary_search_qt(table: &[QuantumEntry], target_u_ms: f64) -> Option<QuantumEntry> {
-    let mut left: isize = 0;
-    let mut right: isize = table.len() as isize - 1;
-    let mut best: Option<QuantumEntry> = None;
-    while left <= right {
-        let mid = left + ((right - left) / 2);
-        let e = table[mid as usize];
-        if e.u_ms <= target_u_ms {
-            best = Some(e);
-            left = mid + 1;
-        } else {
-            right = mid - 1;
-        }
-    }
-    best
-}
 
 /// Compute Quantum Time components [d_in_decade, decade_index, year_index]
 /// Input: epoch_ms (Unix ms), timezone_offset_minutes (like Date.getTimezoneOffset())
 #[wasm_bindgen]
 pub fn get_quantum_time_components(epoch_ms: f64, timezone_offset_minutes: f64) -> *const f64 {
     thread_local! { static QT_OUT: RefCell<[f64; 3]> = const { RefCell::new([0.0; 3]) }; }
+    let Some(qt) = starscalendars_astro_core::quantum_time(epoch_ms, timezone_offset_minutes)
+    else {
+        return std::ptr::null();
+    };
     QT_OUT.with(|buf| {
         let mut out = buf.borrow_mut();
-        if !epoch_ms.is_finite() || !timezone_offset_minutes.is_finite() {
-            return std::ptr::null();
-        }
-        init_quantum_table_if_needed();
-        let adjusted_ms = adjust_ms_like_js(epoch_ms, timezone_offset_minutes);
-        let res = QUANTUM_TABLE.with(|tbl| {
-            let v = tbl.borrow();
-            binary_search_qt(&v, adjusted_ms)
-        });
-        match res {
-            Some(e) => {
-                let dp = (e.d / 10) as f64;
-                let d_in_decade = (e.d - (e.d / 10) * 10) as f64;
-                out[0] = d_in_decade;
-                out[1] = dp;
-                out[2] = e.y as f64;
-                out.as_ptr()
-            }
-            None => std::ptr::null(),
-        }
+        *out = [
+            f64::from(qt.day_in_decade),
+            f64::from(qt.decade),
+            f64::from(qt.year),
+        ];
+        out.as_ptr()
     })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use starscalendars_astro_core::state::{moon_geocentric_cartesian, symmetric_velocity};
 
     #[test]
     fn test_julian_day_calculation() {
@@ -1206,6 +903,18 @@ mod tests {
         assert!(!result.is_null());
     }
 
+    #[test]
+    fn test_moon_geocentric_velocity_magnitude() {
+        // Moon orbital speed ≈ 1.02 km/s ≈ 0.00059 AU/day
+        let v = symmetric_velocity(moon_geocentric_cartesian, 2451545.0);
+        let speed = (v.x * v.x + v.y * v.y + v.z * v.z).sqrt();
+        assert!(
+            speed > 0.00050 && speed < 0.00068,
+            "Moon speed out of range: {}",
+            speed
+        );
+    }
+
     #[test]
     fn test_pure_astro_rust_pluto_position() {
         let result = get_pluto_position(2451545.0);
@@ -1249,23 +958,6 @@ mod tests {
         assert!(!result.is_null());
     }
 
-    #[test]
-    fn test_solar_zenith_position_calculation() {
-        let (lon_east_rad, lat_rad) = solar_zenith_position_rad_internal(2451545.0);
-        // Longitude should be within [-π, π]
-        assert!(
-            lon_east_rad >= -std::f64::consts::PI && lon_east_rad <= std::f64::consts::PI,
-            "Solar zenith longitude out of bounds: {}",
-            lon_east_rad
-        );
-        // Latitude should be within [-π/2, π/2]
-        assert!(
-            lat_rad.abs() <= std::f64::consts::FRAC_PI_2,
-            "Solar zenith latitude out of bounds: {}",
-            lat_rad
-        );
-    }
-
     #[test]
     fn test_mean_and_apparent_sidereal_time() {
         let mean_st = get_mean_sidereal_time(2451545.0);
@@ -1291,23 +983,4 @@ mod tests {
             difference
         ); // Should be small correction
     }
-
-    #[test]
-    fn test_ecliptic_to_equatorial_conversion() {
-        // Test conversion of ecliptic coordinates to equatorial (internal helper avoids unsafe)
-        let (right_ascension, declination) =
-            ecliptic_to_equatorial_internal(0.0, 0.0, 2451545.0, false);
-        // Right ascension should be in [0, 2π] range
-        assert!(
-            right_ascension >= 0.0 && right_ascension <= 2.0 * std::f64::consts::PI,
-            "Right ascension out of range: {}",
-            right_ascension
-        );
-        // Declination should be in [-π/2, π/2] range
-        assert!(
-            declination.abs() <= 1.5708,
-            "Declination out of range: {}",
-            declination
-        );
-    }
 }
@@ -0,0 +1,49 @@
+//! Physical ephemeris of the Moon
+//!
+//! Binding over `starscalendars_astro_core::lunar_physical`: librations,
+//! the axis and bright-limb position angles and the topocentric corrections.
+
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::JulianDay;
+use starscalendars_astro_core::lunar_physical::{LUNAR_PHYSICAL_LEN, lunar_physical_ephemeris};
+
+/// **PURE ASTRO-RUST**: Lunar physical ephemeris (libration, axis, bright limb)
+///
+/// Uses astro::lunar::optical_libr(), physical_libr(), pos_angl_of_axis_of_rot(),
+/// bright_limb() and topocent_libr_by_diff_corrections().
+/// Layout [7 f64], see `lunar_physical_ephemeris`. Pass NaN for the
+/// observer coordinates to skip the topocentric corrections.
+#[wasm_bindgen]
+pub fn get_lunar_physical_ephemeris(
+    julian_day: f64,
+    observer_lat_rad: f64,
+    observer_lon_east_rad: f64,
+) -> *const f64 {
+    thread_local! {
+        static LUNAR_PHYSICAL_BUFFER: RefCell<[f64; LUNAR_PHYSICAL_LEN]> =
+            const { RefCell::new([0.0; LUNAR_PHYSICAL_LEN]) };
+    }
+
+    if JulianDay::new(julian_day).is_err() {
+        return std::ptr::null();
+    }
+
+    LUNAR_PHYSICAL_BUFFER.with(|buffer| {
+        let mut buf = buffer.borrow_mut();
+        *buf = lunar_physical_ephemeris(julian_day, observer_lat_rad, observer_lon_east_rad);
+        buf.as_ptr()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_validation() {
+        assert!(!get_lunar_physical_ephemeris(2448724.5, f64::NAN, f64::NAN).is_null());
+        assert!(get_lunar_physical_ephemeris(f64::NAN, 0.9, 0.5).is_null());
+    }
+}
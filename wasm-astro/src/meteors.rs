@@ -0,0 +1,130 @@
+//! Meteor shower calendar
+//!
+//! Binding over `starscalendars_astro_core::meteors`: peak and observing
+//! conditions of the embedded IMO showers for a year and an observer.
+
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::meteors::{
+    METEOR_SHOWER_COUNT, METEOR_SHOWER_LEN, METEOR_SHOWERS, NIGHT_PROFILE_SAMPLES,
+    NIGHT_PROFILE_STRIDE, ZHR_LIMITING_MAGNITUDE, meteor_night_profile, meteor_shower,
+};
+
+/// Validate the observer and fall back to a dark sky for the limiting magnitude
+fn observer_limiting_magnitude(
+    lat_rad: f64,
+    lon_east_rad: f64,
+    limiting_magnitude: f64,
+) -> Option<f64> {
+    if !lat_rad.is_finite() || !lon_east_rad.is_finite() {
+        return None;
+    }
+    Some(if limiting_magnitude.is_finite() {
+        limiting_magnitude
+    } else {
+        ZHR_LIMITING_MAGNITUDE
+    })
+}
+
+/// Number of showers in the embedded IMO list
+#[wasm_bindgen]
+pub fn get_meteor_shower_count() -> usize {
+    METEOR_SHOWER_COUNT
+}
+
+/// Meteor shower peak and observing conditions for a year and observer
+///
+/// Uses astro::sun::geocent_ecl_pos(), astro::precess::precess_ecl_coords(),
+/// astro::precess::precess_eq_coords() and astro::lunar::illum_frac_frm_ecl_coords().
+/// `limiting_magnitude`: the observer's dark-sky limit, NaN for 6.5.
+/// Layout [14 f64], see `meteor_shower`. Returns null for an invalid
+/// index or input. Heavy: use off-frame (idle) only and cache the result.
+#[wasm_bindgen]
+pub fn get_meteor_shower(
+    index: usize,
+    year: i32,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    limiting_magnitude: f64,
+) -> *const f64 {
+    thread_local! {
+        static METEOR_SHOWER_BUFFER: RefCell<[f64; METEOR_SHOWER_LEN]> =
+            const { RefCell::new([0.0; METEOR_SHOWER_LEN]) };
+    }
+
+    let result = METEOR_SHOWERS.get(index).zip(observer_limiting_magnitude(
+        lat_rad,
+        lon_east_rad,
+        limiting_magnitude,
+    ));
+    match result.and_then(|(shower, lm)| meteor_shower(shower, year, lat_rad, lon_east_rad, lm)) {
+        Some(summary) => METEOR_SHOWER_BUFFER.with(|buffer| {
+            let mut buf = buffer.borrow_mut();
+            *buf = summary;
+            buf.as_ptr()
+        }),
+        None => std::ptr::null(),
+    }
+}
+
+/// Hourly radiant/Sun/Moon altitudes and observable rate through the peak night
+///
+/// Layout [25 × 5 f64], see `meteor_night_profile`. Returns null for
+/// an invalid index or input. Heavy: use off-frame (idle) only.
+#[wasm_bindgen]
+pub fn get_meteor_night_profile(
+    index: usize,
+    year: i32,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    limiting_magnitude: f64,
+) -> *const f64 {
+    thread_local! {
+        static NIGHT_PROFILE_BUFFER: RefCell<[f64; NIGHT_PROFILE_SAMPLES * NIGHT_PROFILE_STRIDE]> =
+            const { RefCell::new([0.0; NIGHT_PROFILE_SAMPLES * NIGHT_PROFILE_STRIDE]) };
+    }
+
+    let result = METEOR_SHOWERS.get(index).zip(observer_limiting_magnitude(
+        lat_rad,
+        lon_east_rad,
+        limiting_magnitude,
+    ));
+    let profile = result.and_then(|(shower, lm)| {
+        let peak = shower.peak_in_year(year)?;
+        meteor_night_profile(shower, peak, lat_rad, lon_east_rad, lm)
+    });
+    match profile {
+        Some(profile) => NIGHT_PROFILE_BUFFER.with(|buffer| {
+            let mut buf = buffer.borrow_mut();
+            *buf = profile;
+            buf.as_ptr()
+        }),
+        None => std::ptr::null(),
+    }
+}
+
+/// Meteor shower code and name by index (debug only)
+///
+/// **DO NOT USE IN PRODUCTION** - violates no-string-passing rule
+#[wasm_bindgen]
+#[cfg(debug_assertions)]
+pub fn debug_get_meteor_shower_name(index: usize) -> String {
+    METEOR_SHOWERS
+        .get(index)
+        .map(|shower| format!("{} {}", shower.code, shower.name))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_validation() {
+        assert_eq!(get_meteor_shower_count(), METEOR_SHOWER_COUNT);
+        assert!(get_meteor_shower(METEOR_SHOWER_COUNT, 2025, 0.8, 0.0, f64::NAN).is_null());
+        assert!(get_meteor_shower(0, 2025, f64::NAN, 0.0, f64::NAN).is_null());
+        assert!(!get_meteor_night_profile(0, 2025, 0.8, 0.0, f64::NAN).is_null());
+    }
+}
@@ -0,0 +1,71 @@
+//! Moon phase, lunar age and the next principal phase
+//!
+//! Binding of `starscalendars_astro_core::moon_phase`, which documents the
+//! phase sectors and the lunar age.
+
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::moon_phase::moon_phase;
+
+/// Moon phase buffer length
+pub(crate) const MOON_PHASE_LEN: usize = 6;
+
+thread_local! {
+    static MOON_PHASE_BUFFER: RefCell<[f64; MOON_PHASE_LEN]> =
+        const { RefCell::new([0.0; MOON_PHASE_LEN]) };
+}
+
+/// **PURE ASTRO-RUST**: Moon phase and lunar age
+///
+/// Uses astro::lunar::geocent_ecl_pos(), astro::sun and
+/// astro::lunar::illum_frac_frm_ecl_coords().
+/// Layout [6 f64]:
+/// - 0: lunar age (days since the last new moon)
+/// - 1: phase index 0..7 (0 New Moon, 1 Waxing Crescent … 7 Waning Crescent)
+/// - 2: illuminated fraction 0..1
+/// - 3: 1 waxing, 0 waning
+/// - 4: JD UTC of the next principal phase
+/// - 5: its phase index (0 new, 2 first quarter, 4 full, 6 last quarter)
+///
+/// Returns null on invalid input.
+#[wasm_bindgen]
+pub fn get_moon_phase(jd_utc: f64) -> *const f64 {
+    let Some(phase) = moon_phase(jd_utc) else {
+        return std::ptr::null();
+    };
+    MOON_PHASE_BUFFER.with(|buffer| {
+        let mut buf = buffer.borrow_mut();
+        *buf = [
+            phase.age_days,
+            f64::from(phase.phase_index),
+            phase.illuminated_fraction,
+            if phase.waxing { 1.0 } else { 0.0 },
+            phase.next_phase_jd,
+            f64::from(phase.next_phase_index),
+        ];
+        buf.as_ptr()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moon_phase_buffer() {
+        assert!(get_moon_phase(f64::NAN).is_null());
+        // 2025-02-09 17:00 UTC, waxing gibbous
+        let jd_utc = 2460716.225;
+        let ptr = get_moon_phase(jd_utc);
+        assert!(!ptr.is_null());
+        let buf = MOON_PHASE_BUFFER.with(|buffer| *buffer.borrow());
+        let phase = moon_phase(jd_utc);
+        assert!(phase.is_some());
+        let Some(phase) = phase else { return };
+        assert_eq!(buf[0], phase.age_days);
+        assert_eq!(buf[1], 3.0);
+        assert_eq!(buf[3], 1.0);
+        assert_eq!(buf[4], phase.next_phase_jd);
+    }
+}
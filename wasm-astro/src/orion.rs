@@ -0,0 +1,83 @@
+//! Orion–Sun alignment event solver (Tatev)
+//!
+//! Binding over `starscalendars_astro_core::orion`; this module keeps the
+//! per-thread alignment configuration.
+
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::JulianDay;
+use starscalendars_astro_core::orion::{AlignmentConfig, AlignmentMode, next_orion_alignment};
+
+thread_local! {
+    static ALIGNMENT_CONFIG: RefCell<AlignmentConfig> =
+        const { RefCell::new(AlignmentConfig::DEFAULT) };
+}
+
+/// Configure the alignment used by `next_orion_alignment_from`
+///
+/// `mode`: 0 = position angle of the Sun from the belt direction,
+/// 1 = Alnilam azimuth minus Sun azimuth. Angles in radians. Returns false
+/// (and keeps the previous configuration) for invalid input.
+#[wasm_bindgen]
+pub fn set_orion_alignment_config(
+    mode: u32,
+    target_rad: f64,
+    belt_alt_min_rad: f64,
+    belt_alt_max_rad: f64,
+    sun_alt_max_rad: f64,
+) -> bool {
+    let config = AlignmentMode::from_code(mode).and_then(|mode| {
+        AlignmentConfig::new(
+            mode,
+            target_rad,
+            belt_alt_min_rad,
+            belt_alt_max_rad,
+            sun_alt_max_rad,
+        )
+    });
+    let Some(config) = config else {
+        return false;
+    };
+    ALIGNMENT_CONFIG.with(|c| {
+        *c.borrow_mut() = config;
+    });
+    true
+}
+
+/// Restore the default alignment (Sun on the belt line beyond Alnitak)
+#[wasm_bindgen]
+pub fn reset_orion_alignment_config() {
+    ALIGNMENT_CONFIG.with(|c| {
+        *c.borrow_mut() = AlignmentConfig::DEFAULT;
+    });
+}
+
+/// Find the next Orion–Sun alignment for an observer, starting from given UTC JD.
+/// Returns JD in UTC of the event, or NaN if none within two years.
+/// Heavy: use off-frame (idle) only and cache the result.
+#[wasm_bindgen]
+pub fn next_orion_alignment_from(jd_utc_start: f64, lat_rad: f64, lon_east_rad: f64) -> f64 {
+    if JulianDay::new(jd_utc_start).is_err() || !lat_rad.is_finite() || !lon_east_rad.is_finite() {
+        return f64::NAN;
+    }
+    let config = ALIGNMENT_CONFIG.with(|c| *c.borrow());
+    next_orion_alignment(&config, jd_utc_start, lat_rad, lon_east_rad).unwrap_or(f64::NAN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_validation() {
+        assert!(!set_orion_alignment_config(2, 0.0, 0.0, 1.0, 0.0));
+        assert!(!set_orion_alignment_config(0, f64::NAN, 0.0, 1.0, 0.0));
+        assert!(!set_orion_alignment_config(1, 0.0, 1.0, 0.0, 0.0));
+        assert!(set_orion_alignment_config(1, 0.5, 0.0, 1.0, -0.1));
+        reset_orion_alignment_config();
+        let mode = ALIGNMENT_CONFIG.with(|c| c.borrow().mode);
+        assert_eq!(mode, AlignmentMode::PositionAngle);
+        assert!(next_orion_alignment_from(-1.0, 0.7, 0.8).is_nan());
+    }
+}
@@ -0,0 +1,50 @@
+//! Physical ephemerides for disk orientation
+//!
+//! Binding over `starscalendars_astro_core::physical`: orientation of the
+//! Sun, Mars and Jupiter as seen from Earth. The Sun uses body index 9.
+
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::JulianDay;
+use starscalendars_astro_core::physical::{PHYSICAL_EPHEMERIS_LEN, physical_ephemeris};
+
+/// **PURE ASTRO-RUST**: Physical ephemeris of the Sun, Mars or Jupiter
+///
+/// Uses astro::sun::ephemeris(), astro::sun::synodic_rot(),
+/// astro::planet::mars::ephemeris() and astro::planet::jupiter::ephemeris().
+/// Body: 3 = Mars, 4 = Jupiter, 9 = Sun. Layout [6 f64], see
+/// `physical_ephemeris`. Returns null for other bodies.
+#[wasm_bindgen]
+pub fn get_physical_ephemeris(body_index: usize, julian_day: f64) -> *const f64 {
+    thread_local! {
+        static PHYSICAL_BUFFER: RefCell<[f64; PHYSICAL_EPHEMERIS_LEN]> =
+            const { RefCell::new([0.0; PHYSICAL_EPHEMERIS_LEN]) };
+    }
+
+    if JulianDay::new(julian_day).is_err() {
+        return std::ptr::null();
+    }
+
+    match physical_ephemeris(body_index, julian_day) {
+        Some(eph) => PHYSICAL_BUFFER.with(|buffer| {
+            let mut buf = buffer.borrow_mut();
+            *buf = eph;
+            buf.as_ptr()
+        }),
+        None => std::ptr::null(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starscalendars_astro_core::physical::PHYSICAL_BODY_SUN;
+
+    #[test]
+    fn test_export_validation() {
+        assert!(!get_physical_ephemeris(PHYSICAL_BODY_SUN, 2451545.0).is_null());
+        assert!(get_physical_ephemeris(5, 2451545.0).is_null());
+        assert!(get_physical_ephemeris(3, f64::NAN).is_null());
+    }
+}
@@ -0,0 +1,51 @@
+//! Traditional planetary hours and the ruler of the day
+//!
+//! Binding over `starscalendars_astro_core::planetary_hours`. Rulers are
+//! encoded by their Chaldean index: 0 Saturn, 1 Jupiter, 2 Mars, 3 Sun,
+//! 4 Venus, 5 Mercury, 6 Moon.
+
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::JulianDay;
+use starscalendars_astro_core::planetary_hours::{PLANETARY_HOURS_LEN, planetary_hours};
+
+/// Planetary hours and day ruler for an observer
+///
+/// Sunrise/sunset from the apparent Sun (FK5 + aberration + nutation) at
+/// -50′. Rulers by Chaldean index (0 Saturn … 6 Moon). Layout [52 f64], see
+/// `planetary_hours`. Returns null on invalid input or when the Sun
+/// does not rise or set. Off-frame; refresh once per planetary hour.
+#[wasm_bindgen]
+pub fn get_planetary_hours(jd_utc: f64, lat_rad: f64, lon_east_rad: f64) -> *const f64 {
+    thread_local! {
+        static PLANETARY_HOURS_BUFFER: RefCell<[f64; PLANETARY_HOURS_LEN]> =
+            const { RefCell::new([0.0; PLANETARY_HOURS_LEN]) };
+    }
+
+    if JulianDay::new(jd_utc).is_err() || !lat_rad.is_finite() || !lon_east_rad.is_finite() {
+        return std::ptr::null();
+    }
+
+    match planetary_hours(jd_utc, lat_rad, lon_east_rad) {
+        Some(hours) => PLANETARY_HOURS_BUFFER.with(|buffer| {
+            let mut buf = buffer.borrow_mut();
+            *buf = hours;
+            buf.as_ptr()
+        }),
+        None => std::ptr::null(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_validation() {
+        assert!(get_planetary_hours(f64::NAN, 0.9, 0.0).is_null());
+        // Tromsø (69.65°N) at the December solstice
+        assert!(get_planetary_hours(2461031.0, 1.2156, 0.3309).is_null());
+        assert!(!get_planetary_hours(2460681.0, 0.8992, -0.0022).is_null());
+    }
+}
@@ -0,0 +1,103 @@
+//! Solar and lunar returns
+//!
+//! Binding over `starscalendars_astro_core::returns`: the instant the Sun
+//! (Moon) comes back to its natal apparent longitude, with the chart cast
+//! for the place where the native is at the return moment.
+
+use wasm_bindgen::prelude::*;
+
+use crate::chart::{chart_options, write_chart};
+use starscalendars_astro_core::JulianDay;
+use starscalendars_astro_core::returns::{ReturnBody, return_chart};
+
+/// Shared input validation and buffer handling of the return exports
+fn return_chart_export(
+    body: ReturnBody,
+    natal_jd_utc: f64,
+    jd_utc_start: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    house_system: u32,
+    zodiac: u32,
+) -> *const f64 {
+    let Some((system, zodiac)) =
+        chart_options(jd_utc_start, lat_rad, lon_east_rad, house_system, zodiac)
+    else {
+        return std::ptr::null();
+    };
+    if JulianDay::new(natal_jd_utc).is_err() {
+        return std::ptr::null();
+    }
+    write_chart(return_chart(
+        body,
+        natal_jd_utc,
+        jd_utc_start,
+        lat_rad,
+        lon_east_rad,
+        system,
+        zodiac,
+    ))
+}
+
+/// **PURE ASTRO-RUST**: Next solar return and its chart
+///
+/// Sun back at its natal apparent longitude (astro::sun + FK5 + aberration +
+/// nutation), first instant at or after `jd_utc_start`, chart cast at the
+/// given place. Codes as in `get_chart`; layout [35 f64] with the return JD
+/// UTC in slot 0. Returns null on invalid input.
+#[wasm_bindgen]
+pub fn get_solar_return(
+    natal_jd_utc: f64,
+    jd_utc_start: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    house_system: u32,
+    zodiac: u32,
+) -> *const f64 {
+    return_chart_export(
+        ReturnBody::Sun,
+        natal_jd_utc,
+        jd_utc_start,
+        lat_rad,
+        lon_east_rad,
+        house_system,
+        zodiac,
+    )
+}
+
+/// **PURE ASTRO-RUST**: Next lunar return and its chart
+///
+/// Moon back at its natal apparent longitude (astro::lunar::geocent_ecl_pos()
+/// + nutation), first instant at or after `jd_utc_start`. Same codes and
+/// layout as `get_solar_return`.
+#[wasm_bindgen]
+pub fn get_lunar_return(
+    natal_jd_utc: f64,
+    jd_utc_start: f64,
+    lat_rad: f64,
+    lon_east_rad: f64,
+    house_system: u32,
+    zodiac: u32,
+) -> *const f64 {
+    return_chart_export(
+        ReturnBody::Moon,
+        natal_jd_utc,
+        jd_utc_start,
+        lat_rad,
+        lon_east_rad,
+        house_system,
+        zodiac,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_return_exports() {
+        // 1990-06-15 12:00 UTC natal, from 2025-01-01
+        assert!(get_solar_return(f64::NAN, 2460676.5, 0.9, 0.0, 0, 0).is_null());
+        assert!(!get_lunar_return(2448058.0, 2460676.5, 0.9, 0.0, 1, 1).is_null());
+    }
+}
@@ -0,0 +1,72 @@
+//! Planetary satellite systems for the planet "zoom" mode
+//!
+//! Binding over `starscalendars_astro_core::satellites`: the Galilean moons,
+//! the eight major moons of Saturn and the ring and pole geometry, in units
+//! of the parent planet's equatorial radius.
+
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::JulianDay;
+use starscalendars_astro_core::satellites::{
+    JUPITER_SYSTEM_LEN, SATURN_SYSTEM_LEN, jupiter_system, saturn_system,
+};
+
+/// **PURE ASTRO-RUST**: Galilean moons and Jupiter's physical ephemeris
+///
+/// Uses astro::planet::jupiter::moon::apprnt_rect_coords() and
+/// astro::planet::jupiter::ephemeris(). Layout [13 f64], see `jupiter_system`.
+/// Heavy: use off-frame or only while the Jupiter zoom view is active.
+#[wasm_bindgen]
+pub fn get_jupiter_system(julian_day: f64) -> *const f64 {
+    thread_local! {
+        static JUPITER_BUFFER: RefCell<[f64; JUPITER_SYSTEM_LEN]> =
+            const { RefCell::new([0.0; JUPITER_SYSTEM_LEN]) };
+    }
+
+    if JulianDay::new(julian_day).is_err() {
+        return std::ptr::null();
+    }
+
+    JUPITER_BUFFER.with(|buffer| {
+        let mut buf = buffer.borrow_mut();
+        *buf = jupiter_system(julian_day);
+        buf.as_ptr()
+    })
+}
+
+/// **PURE ASTRO-RUST**: Saturn's major moons and ring geometry
+///
+/// Uses astro::planet::saturn::moon::apprnt_rect_coords() and
+/// astro::planet::saturn::ring::elements(). Layout [30 f64], see `saturn_system`.
+/// Heavy: use off-frame or only while the Saturn zoom view is active.
+#[wasm_bindgen]
+pub fn get_saturn_system(julian_day: f64) -> *const f64 {
+    thread_local! {
+        static SATURN_BUFFER: RefCell<[f64; SATURN_SYSTEM_LEN]> =
+            const { RefCell::new([0.0; SATURN_SYSTEM_LEN]) };
+    }
+
+    if JulianDay::new(julian_day).is_err() {
+        return std::ptr::null();
+    }
+
+    SATURN_BUFFER.with(|buffer| {
+        let mut buf = buffer.borrow_mut();
+        *buf = saturn_system(julian_day);
+        buf.as_ptr()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_validation() {
+        assert!(!get_jupiter_system(2451545.0).is_null());
+        assert!(get_jupiter_system(f64::NAN).is_null());
+        assert!(!get_saturn_system(2451545.0).is_null());
+        assert!(get_saturn_system(-1.0).is_null());
+    }
+}
@@ -0,0 +1,134 @@
+//! Comets and asteroids from osculating orbital elements
+//!
+//! Binding over `starscalendars_astro_core::small_body`: two-body propagation
+//! of MPC-style element sets into heliocentric and apparent geocentric
+//! coordinates plus an H/G magnitude.
+
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::JulianDay;
+use starscalendars_astro_core::small_body::{
+    OrbitalElements, SMALL_BODY_STATE_LEN, small_body_state,
+};
+
+/// Write a small-body state into the shared buffer, null on failure
+fn write_small_body_state(
+    elements: Option<OrbitalElements>,
+    julian_day: f64,
+    abs_mag: f64,
+    slope: f64,
+) -> *const f64 {
+    thread_local! {
+        static SMALL_BODY_BUFFER: RefCell<[f64; SMALL_BODY_STATE_LEN]> =
+            const { RefCell::new([0.0; SMALL_BODY_STATE_LEN]) };
+    }
+
+    if JulianDay::new(julian_day).is_err() {
+        return std::ptr::null();
+    }
+
+    match elements.and_then(|el| small_body_state(&el, julian_day, abs_mag, slope)) {
+        Some(state) => SMALL_BODY_BUFFER.with(|buffer| {
+            let mut buf = buffer.borrow_mut();
+            *buf = state;
+            buf.as_ptr()
+        }),
+        None => std::ptr::null(),
+    }
+}
+
+/// **PURE ASTRO-RUST**: Comet state from perihelion elements (q, e, T)
+///
+/// Uses astro::orbit::{elliptic, parabolic, near_parabolic} and
+/// astro::planet::heliocent_coords_frm_orb_elements(). Angles in radians
+/// (J2000 ecliptic), times in JDE. Layout [10 f64], see
+/// `small_body_state`. Returns null for invalid elements or when the
+/// near-parabolic solver does not converge.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn get_comet_state(
+    julian_day: f64,
+    perihelion_jd: f64,
+    perihelion_dist_au: f64,
+    eccentricity: f64,
+    inclination_rad: f64,
+    ascending_node_rad: f64,
+    arg_perihelion_rad: f64,
+    abs_mag_h: f64,
+    slope_g: f64,
+) -> *const f64 {
+    let elements = OrbitalElements::from_perihelion(
+        perihelion_dist_au,
+        eccentricity,
+        inclination_rad,
+        ascending_node_rad,
+        arg_perihelion_rad,
+        perihelion_jd,
+    );
+    write_small_body_state(elements, julian_day, abs_mag_h, slope_g)
+}
+
+/// **PURE ASTRO-RUST**: Asteroid state from mean-anomaly elements (a, e, M at epoch)
+///
+/// Uses astro::orbit::elliptic::{mn_motion, ecc_anom} and
+/// astro::planet::heliocent_coords_frm_orb_elements(). Angles in radians
+/// (J2000 ecliptic), times in JDE. Layout [10 f64], see
+/// `small_body_state`. Returns null for non-elliptic or invalid elements.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn get_asteroid_state(
+    julian_day: f64,
+    epoch_jd: f64,
+    semimajor_axis_au: f64,
+    eccentricity: f64,
+    inclination_rad: f64,
+    ascending_node_rad: f64,
+    arg_perihelion_rad: f64,
+    mean_anomaly_rad: f64,
+    abs_mag_h: f64,
+    slope_g: f64,
+) -> *const f64 {
+    let elements = OrbitalElements::from_mean_anomaly(
+        epoch_jd,
+        semimajor_axis_au,
+        eccentricity,
+        inclination_rad,
+        ascending_node_rad,
+        arg_perihelion_rad,
+        mean_anomaly_rad,
+    );
+    write_small_body_state(elements, julian_day, abs_mag_h, slope_g)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_validation() {
+        // Comet Encke, Meeus Example 33.b
+        let encke = |jd: f64| {
+            get_comet_state(
+                jd,
+                2448193.04502,
+                0.33085,
+                0.8502196,
+                0.2085,
+                5.8425,
+                3.2504,
+                10.0,
+                0.15,
+            )
+        };
+        assert!(!encke(2448170.5).is_null());
+        assert!(encke(f64::NAN).is_null());
+        // Hyperbolic elements are not valid asteroid elements
+        assert!(
+            get_asteroid_state(
+                2460000.5, 2460000.5, 2.7, 1.2, 0.1, 0.2, 0.3, 0.4, 3.3, 0.12
+            )
+            .is_null()
+        );
+    }
+}
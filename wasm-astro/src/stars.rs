@@ -0,0 +1,96 @@
+//! Embedded bright-star catalog and apparent places
+//!
+//! Binding over `starscalendars_astro_core::stars`: batched apparent places
+//! of the catalog and of the Orion belt in thread-local buffers.
+
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::JulianDay;
+#[cfg(debug_assertions)]
+use starscalendars_astro_core::stars::BRIGHT_STARS;
+use starscalendars_astro_core::stars::{
+    ALNILAM_HR, ALNITAK_HR, MINTAKA_HR, STAR_COUNT, STAR_STRIDE, star_apparent_place,
+    star_apparent_places, star_by_hr,
+};
+
+/// Number of stars in the embedded catalog
+#[wasm_bindgen]
+pub fn get_star_count() -> usize {
+    STAR_COUNT
+}
+
+/// **PURE ASTRO-RUST**: Apparent places of the embedded bright-star catalog
+///
+/// Uses astro::precess::precess_eq_coords(), astro::nutation::nutation_in_eq_coords()
+/// and astro::aberr::stell_aberr_in_eq_coords(). Layout [get_star_count() × 4 f64],
+/// see `star_apparent_places`. Off-frame: recompute about once per minute.
+#[wasm_bindgen]
+pub fn get_star_apparent_places(julian_day: f64) -> *const f64 {
+    thread_local! {
+        static STAR_BUFFER: RefCell<[[f64; STAR_STRIDE]; STAR_COUNT]> =
+            const { RefCell::new([[0.0; STAR_STRIDE]; STAR_COUNT]) };
+    }
+
+    if JulianDay::new(julian_day).is_err() {
+        return std::ptr::null();
+    }
+
+    STAR_BUFFER.with(|buffer| {
+        let mut buf = buffer.borrow_mut();
+        star_apparent_places(julian_day, buf.as_mut_slice());
+        buf.as_flattened().as_ptr()
+    })
+}
+
+/// **PURE ASTRO-RUST**: Apparent places of the Orion belt stars
+///
+/// Same pipeline as `get_star_apparent_places`. Layout [6 f64]:
+/// [α, δ] of Alnitak, Alnilam, Mintaka (rad).
+#[wasm_bindgen]
+pub fn get_orion_belt_places(julian_day: f64) -> *const f64 {
+    thread_local! {
+        static BELT_BUFFER: RefCell<[f64; 6]> = const { RefCell::new([0.0; 6]) };
+    }
+
+    if JulianDay::new(julian_day).is_err() {
+        return std::ptr::null();
+    }
+
+    BELT_BUFFER.with(|buffer| {
+        let mut buf = buffer.borrow_mut();
+        for (i, hr) in [ALNITAK_HR, ALNILAM_HR, MINTAKA_HR].into_iter().enumerate() {
+            let (ra, dec) = star_by_hr(hr)
+                .map(|star| star_apparent_place(star, julian_day))
+                .unwrap_or((f64::NAN, f64::NAN));
+            buf[2 * i] = ra;
+            buf[2 * i + 1] = dec;
+        }
+        buf.as_ptr()
+    })
+}
+
+/// Catalog star name by index (debug only, names never cross the hot path)
+///
+/// **DO NOT USE IN PRODUCTION** - violates no-string-passing rule
+#[wasm_bindgen]
+#[cfg(debug_assertions)]
+pub fn debug_get_star_name(index: usize) -> String {
+    BRIGHT_STARS
+        .get(index)
+        .map(|star| star.name.to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_validation() {
+        assert_eq!(get_star_count(), STAR_COUNT);
+        assert!(!get_star_apparent_places(2460000.5).is_null());
+        assert!(get_star_apparent_places(f64::NAN).is_null());
+        assert!(!get_orion_belt_places(2460000.5).is_null());
+    }
+}
@@ -0,0 +1,180 @@
+//! Synastry and composite charts of two natal charts
+//!
+//! Binding over `starscalendars_astro_core::synastry`, which scores the
+//! cross-aspects and builds midpoint or Davison composites. This module keeps
+//! the per-thread aspect configuration and the result buffers.
+
+use std::cell::{Cell, RefCell};
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::JulianDay;
+use starscalendars_astro_core::houses::HouseSystem;
+use starscalendars_astro_core::synastry::{
+    CompositeMethod, SYNASTRY_POINTS, SynastryConfig, composite_chart, synastry,
+};
+use starscalendars_astro_core::zodiac::Zodiac;
+use starscalendars_domain::spiritual::{AstrologicalSystem, BirthChart};
+
+use crate::chart::write_chart;
+
+/// f64 values per cross-aspect row
+const SYNASTRY_STRIDE: usize = 5;
+
+/// Synastry buffer length: total score, row count, then the rows
+const SYNASTRY_LEN: usize = 2 + SYNASTRY_POINTS * SYNASTRY_POINTS * SYNASTRY_STRIDE;
+
+thread_local! {
+    static SYNASTRY_CONFIG: Cell<SynastryConfig> = const { Cell::new(SynastryConfig::DEFAULT) };
+}
+
+/// Birth chart from WASM arguments (radians) and a zodiac code
+fn birth_chart(jd_utc: f64, lat_rad: f64, lon_east_rad: f64, zodiac: u32) -> Option<BirthChart> {
+    let system = match Zodiac::from_code(zodiac)? {
+        Zodiac::Tropical => AstrologicalSystem::Western,
+        Zodiac::Sidereal => AstrologicalSystem::Vedic,
+    };
+    let chart = BirthChart {
+        birth_time: jd_utc,
+        latitude: lat_rad.to_degrees(),
+        longitude: lon_east_rad.to_degrees(),
+        system,
+    };
+    (JulianDay::new(jd_utc).is_ok() && chart.validate().is_ok()).then_some(chart)
+}
+
+/// Set the orb (rad, 0 ≤ orb < 15°) and weight of one aspect for synastry scoring
+///
+/// `aspect`: 0 conjunction, 1 sextile, 2 square, 3 trine, 4 opposition.
+/// Returns false (config unchanged) on invalid input.
+#[wasm_bindgen]
+pub fn set_synastry_aspect(aspect: u32, orb_rad: f64, weight: f64) -> bool {
+    SYNASTRY_CONFIG.with(
+        |config| match config.get().with_aspect(aspect, orb_rad, weight) {
+            Some(updated) => {
+                config.set(updated);
+                true
+            }
+            None => false,
+        },
+    )
+}
+
+/// Restore the default synastry orbs and weights
+#[wasm_bindgen]
+pub fn reset_synastry_config() {
+    SYNASTRY_CONFIG.with(|config| config.set(SynastryConfig::DEFAULT));
+}
+
+/// **PURE ASTRO-RUST**: Synastry cross-aspects between two natal charts
+///
+/// Uses astro::lunar, VSOP87 and astro::pluto for the bodies and the apparent
+/// sidereal time for the angles. Births as JD UTC and radians. Layout
+/// [2 + rows × 5 f64]: total score, row count, then rows of [point A,
+/// point B, aspect code, deviation rad, score]; points 0..9 body codes,
+/// 10 Ascendant, 11 Midheaven. Returns null on invalid input.
+#[wasm_bindgen]
+pub fn get_synastry(
+    jd_utc_a: f64,
+    lat_rad_a: f64,
+    lon_east_rad_a: f64,
+    jd_utc_b: f64,
+    lat_rad_b: f64,
+    lon_east_rad_b: f64,
+) -> *const f64 {
+    thread_local! {
+        static SYNASTRY_BUFFER: RefCell<[f64; SYNASTRY_LEN]> =
+            const { RefCell::new([0.0; SYNASTRY_LEN]) };
+    }
+
+    let (Some(a), Some(b)) = (
+        birth_chart(jd_utc_a, lat_rad_a, lon_east_rad_a, 0),
+        birth_chart(jd_utc_b, lat_rad_b, lon_east_rad_b, 0),
+    ) else {
+        return std::ptr::null();
+    };
+    let config = SYNASTRY_CONFIG.with(Cell::get);
+    let Ok(synastry) = synastry(&a, &b, &config) else {
+        return std::ptr::null();
+    };
+
+    SYNASTRY_BUFFER.with(|buffer| {
+        let mut buf = buffer.borrow_mut();
+        let (header, rest) = buf.split_at_mut(2);
+        let (rows, _) = rest.as_chunks_mut::<SYNASTRY_STRIDE>();
+        let mut count = 0u8;
+        for (row, aspect) in rows.iter_mut().zip(&synastry.aspects) {
+            *row = [
+                f64::from(aspect.point_a),
+                f64::from(aspect.point_b),
+                f64::from(aspect.aspect.code()),
+                aspect.deviation,
+                aspect.score,
+            ];
+            count = count.saturating_add(1);
+        }
+        header[0] = synastry.total_score;
+        header[1] = f64::from(count);
+        buf.as_ptr()
+    })
+}
+
+/// **PURE ASTRO-RUST**: Composite chart of two natal charts
+///
+/// `method`: 0 midpoint, 1 Davison (time and geographic midpoint). Births as
+/// JD UTC and radians; `house_system` and `zodiac` as in `get_chart`. Layout
+/// [35 f64], see `composite_chart`. Returns null on invalid input or when
+/// Placidus is undefined at a latitude.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn get_composite_chart(
+    method: u32,
+    jd_utc_a: f64,
+    lat_rad_a: f64,
+    lon_east_rad_a: f64,
+    jd_utc_b: f64,
+    lat_rad_b: f64,
+    lon_east_rad_b: f64,
+    house_system: u32,
+    zodiac: u32,
+) -> *const f64 {
+    let (Some(method), Some(system)) = (
+        CompositeMethod::from_code(method),
+        HouseSystem::from_code(house_system),
+    ) else {
+        return std::ptr::null();
+    };
+    let (Some(a), Some(b)) = (
+        birth_chart(jd_utc_a, lat_rad_a, lon_east_rad_a, zodiac),
+        birth_chart(jd_utc_b, lat_rad_b, lon_east_rad_b, zodiac),
+    ) else {
+        return std::ptr::null();
+    };
+    write_chart(composite_chart(&a, &b, system, method).ok().flatten())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synastry_config() {
+        assert!(set_synastry_aspect(3, 0.1, 3.0));
+        assert!(!set_synastry_aspect(5, 0.1, 1.0));
+        assert!(!set_synastry_aspect(0, 1.0, 1.0));
+        reset_synastry_config();
+        assert_eq!(SYNASTRY_CONFIG.with(Cell::get), SynastryConfig::DEFAULT);
+    }
+
+    #[test]
+    fn test_synastry_exports() {
+        // Paris 1990-06-15 12:00 UTC, New York 1992-10-04 18:00 UTC
+        assert!(
+            !get_composite_chart(1, 2448058.0, 0.85, 0.04, 2448900.25, 0.71, -1.29, 0, 0).is_null()
+        );
+        assert!(
+            get_composite_chart(2, 2448058.0, 0.85, 0.04, 2448900.25, 0.71, -1.29, 0, 0).is_null()
+        );
+        assert!(!get_synastry(2448058.0, 0.85, 0.04, 2448900.25, 0.71, -1.29).is_null());
+        assert!(get_synastry(f64::NAN, 0.85, 0.04, 2448900.25, 0.71, -1.29).is_null());
+    }
+}
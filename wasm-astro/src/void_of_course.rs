@@ -0,0 +1,132 @@
+//! Moon sign ingresses and void-of-course windows
+//!
+//! Binding over `starscalendars_astro_core::void_of_course`: each Moon sign
+//! stay with the last exact major aspect before the exit, written as rows of
+//! JD UTC times into a bounded buffer.
+
+use std::cell::{Cell, RefCell};
+use wasm_bindgen::prelude::*;
+
+use starscalendars_astro_core::JulianDay;
+use starscalendars_astro_core::timescales::{tt_to_utc_jd, utc_to_tt_jd};
+use starscalendars_astro_core::void_of_course::{MoonSignStay, moon_sign_stays};
+use starscalendars_astro_core::zodiac::Zodiac;
+
+/// f64 values per sign stay in the output buffer
+const MOON_INGRESS_STRIDE: usize = 6;
+
+/// Maximum length of a query range, in days
+const MAX_RANGE_DAYS: f64 = 366.0; // @allow-wasm-const
+
+/// Maximum sign stays in a query range (~13.4 sidereal months × 12)
+const MAX_MOON_INGRESSES: usize = 168;
+
+/// Write stays into the output layout (times converted to JD UTC)
+///
+/// Row [6 f64]: ingress JD UTC, sign (0 Aries … 11 Pisces), exit JD UTC
+/// (= void end), void start JD UTC, aspected body code, aspect code
+/// (0 conjunction … 4 opposition). Without a known last aspect the void start
+/// is the ingress and body/aspect are NaN.
+fn write_stays(stays: &[MoonSignStay], out: &mut [f64]) -> usize {
+    let (rows, _) = out.as_chunks_mut::<MOON_INGRESS_STRIDE>();
+    let mut count = 0;
+    for (row, stay) in rows.iter_mut().zip(stays) {
+        let ingress_utc = tt_to_utc_jd(stay.ingress.jd_tt);
+        *row = match stay.last_aspect {
+            Some(aspect) => [
+                ingress_utc,
+                f64::from(stay.ingress.to),
+                tt_to_utc_jd(stay.exit_jd_tt),
+                tt_to_utc_jd(aspect.jd_tt),
+                f64::from(aspect.body),
+                f64::from(aspect.aspect.code()),
+            ],
+            None => [
+                ingress_utc,
+                f64::from(stay.ingress.to),
+                tt_to_utc_jd(stay.exit_jd_tt),
+                ingress_utc,
+                f64::NAN,
+                f64::NAN,
+            ],
+        };
+        count += 1;
+    }
+    count
+}
+
+thread_local! {
+    static MOON_INGRESS_BUFFER: RefCell<[f64; MAX_MOON_INGRESSES * MOON_INGRESS_STRIDE]> =
+        const { RefCell::new([0.0; MAX_MOON_INGRESSES * MOON_INGRESS_STRIDE]) };
+    static MOON_INGRESS_COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+/// **PURE ASTRO-RUST**: Moon sign ingresses and void-of-course windows
+///
+/// Uses astro::lunar::geocent_ecl_pos() for the Moon and VSOP87/Pluto for the
+/// aspected bodies. `zodiac`: 0 tropical, 1 sidereal (Lahiri). Range up to
+/// 366 days. Layout [get_moon_sign_ingress_count() × 6 f64], see
+/// `write_stays`. Returns null on invalid input.
+/// Heavy: use off-frame (idle) only and cache the result.
+#[wasm_bindgen]
+pub fn get_moon_sign_ingresses(jd_utc_start: f64, jd_utc_end: f64, zodiac: u32) -> *const f64 {
+    MOON_INGRESS_COUNT.with(|count| count.set(0));
+    let Some(zodiac) = Zodiac::from_code(zodiac) else {
+        return std::ptr::null();
+    };
+    if JulianDay::new(jd_utc_start).is_err()
+        || JulianDay::new(jd_utc_end).is_err()
+        || jd_utc_end <= jd_utc_start
+        || jd_utc_end - jd_utc_start > MAX_RANGE_DAYS
+    {
+        return std::ptr::null();
+    }
+
+    let Some(stays) = moon_sign_stays(zodiac, utc_to_tt_jd(jd_utc_start), utc_to_tt_jd(jd_utc_end))
+    else {
+        return std::ptr::null();
+    };
+    MOON_INGRESS_BUFFER.with(|buffer| {
+        let mut buf = buffer.borrow_mut();
+        let written = write_stays(&stays, buf.as_mut_slice());
+        MOON_INGRESS_COUNT.with(|count| count.set(written));
+        buf.as_ptr()
+    })
+}
+
+/// Number of rows written by the last `get_moon_sign_ingresses` call
+#[wasm_bindgen]
+pub fn get_moon_sign_ingress_count() -> usize {
+    MOON_INGRESS_COUNT.with(Cell::get)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2025-01-01 00:00 TT
+    const JAN_2025: f64 = 2460676.5;
+
+    #[test]
+    fn test_export_layout_and_validation() {
+        let ptr = get_moon_sign_ingresses(JAN_2025, JAN_2025 + 30.0, 0);
+        assert!(!ptr.is_null());
+        let count = get_moon_sign_ingress_count();
+        assert!((13..=14).contains(&count));
+        MOON_INGRESS_BUFFER.with(|buffer| {
+            let buf = buffer.borrow();
+            let (rows, _) = buf.as_chunks::<MOON_INGRESS_STRIDE>();
+            for row in rows.iter().take(count) {
+                assert!(row[0] >= JAN_2025 && row[0] < JAN_2025 + 30.0);
+                assert!(row[2] > row[0]);
+                assert!(row[3] <= row[2]);
+                assert!(row[4].is_nan() || (0.0..10.0).contains(&row[4]));
+            }
+        });
+
+        assert!(get_moon_sign_ingresses(JAN_2025, JAN_2025 + 400.0, 0).is_null());
+        assert_eq!(get_moon_sign_ingress_count(), 0);
+        assert!(get_moon_sign_ingresses(JAN_2025, JAN_2025 + 1.0, 2).is_null());
+        assert!(get_moon_sign_ingresses(JAN_2025, JAN_2025 - 1.0, 0).is_null());
+    }
+}